@@ -36,7 +36,6 @@ export const depositCapital = async (inputFile: string) => {
     const lpGovernance = new PublicKey(config.lpGovernance);
     const lpMintPublicKey = new PublicKey(config.lpMint);
     const delegateMintGovernance = new PublicKey(config.delegateMintGovernance);
-    const delegateTokenMint = new PublicKey(config.delegateTokenMint);
 
     const usdcTokenSource = await getOrCreateAssociatedTokenAccount(
         connection,
@@ -61,9 +60,7 @@ export const depositCapital = async (inputFile: string) => {
         usdcMintPublicKey,
         lpTokenAccount,
         lpMintPublicKey,
-        delegateTokenMint,
-        config.amount,
-        usdcMint.decimals
+        config.amount * 10 ** usdcMint.decimals
     )
 
     const tx = new Transaction()
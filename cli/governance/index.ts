@@ -39,7 +39,6 @@ export default (async (program: Command) => {
       "lpGovernance": "HApH8Zr8fqXyoH3xyjaj4PBdgWyCDPiGevu4ijTCGb9E",
       "lpMint": "HzhBrBLD8NQrE1jwHNptvmFmgQ1nddtHTamWy7eqLtTJ",
       "delegateMintGovernance": "UW21kChKwbPjtrYipcGqWXrKkG3XvHchKYgbLdauhCB",
-      "delegateTokenMint": "F2RdujrSfffnTc1Qps8bEtxW25Wm2j1u7d2Ph3ZLQKzt",
       "amount": 1
     }
     `
@@ -12,7 +12,6 @@ export const validateDepositCapitalConfig = (config: any) => {
         isString(config.lpGovernance) &&
         isString(config.lpMint) &&
         isString(config.delegateMintGovernance) &&
-        isString(config.delegateTokenMint) &&
         isNumber(config.amount)
     );
 
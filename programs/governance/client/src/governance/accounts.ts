@@ -354,6 +354,7 @@ export class GovernanceConfig {
   voteTipping: VoteTipping;
   proposalCoolOffTime: number;
   minCouncilTokensToCreateProposal: BN;
+  councilVetoVoteThresholdPercentage?: VoteThresholdPercentage;
 
   constructor(args: {
     voteThresholdPercentage: VoteThresholdPercentage;
@@ -363,6 +364,7 @@ export class GovernanceConfig {
     voteTipping?: VoteTipping;
     proposalCoolOffTime?: number;
     minCouncilTokensToCreateProposal: BN;
+    councilVetoVoteThresholdPercentage?: VoteThresholdPercentage;
   }) {
     this.voteThresholdPercentage = args.voteThresholdPercentage;
     this.minCommunityTokensToCreateProposal =
@@ -373,6 +375,8 @@ export class GovernanceConfig {
     this.proposalCoolOffTime = args.proposalCoolOffTime ?? 0;
     this.minCouncilTokensToCreateProposal =
       args.minCouncilTokensToCreateProposal;
+    this.councilVetoVoteThresholdPercentage =
+      args.councilVetoVoteThresholdPercentage;
   }
 }
 
@@ -64,11 +64,9 @@ export class DepositGoverningTokensArgs {
 export class DepositCapitalArgs {
   instruction: GovernanceInstruction = GovernanceInstruction.DepositCapital;
   amount: BN;
-  decimals: BN;
 
-  constructor(args: { amount: BN, decimals: BN }) {
+  constructor(args: { amount: BN }) {
     this.amount = args.amount;
-    this.decimals = args.decimals;
   }
 }
 
@@ -239,8 +239,7 @@ function createGovernanceSchema(programVersion: number) {
                 kind: 'struct',
                 fields: [
                     ['instruction', 'u8'],
-                    ['amount', 'u64'],
-                    ['decimals', 'u8']
+                    ['amount', 'u64']
                 ]
             },
         ],
@@ -560,7 +559,7 @@ function createGovernanceSchema(programVersion: number) {
                     ['governedAccount', 'pubkey'],
                     ['proposalCount', 'u32'],
                     ['config', GovernanceConfig],
-                    ['reserved', [6]],
+                    ['reserved', [5]],
                     ['votingProposalCount', 'u16'],
                 ],
             },
@@ -587,6 +586,10 @@ function createGovernanceSchema(programVersion: number) {
                     ['voteTipping', 'u8'],
                     ['proposalCoolOffTime', 'u32'],
                     ['minCouncilTokensToCreateProposal', 'u64'],
+                    [
+                        'councilVetoVoteThresholdPercentage',
+                        {kind: 'option', type: VoteThresholdPercentage},
+                    ],
                 ],
             },
         ],
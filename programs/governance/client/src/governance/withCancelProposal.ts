@@ -18,6 +18,7 @@ export const withCancelProposal = (
   proposal: PublicKey,
   proposalOwnerRecord: PublicKey,
   governanceAuthority: PublicKey,
+  councilTokenOwnerRecord?: PublicKey,
 ) => {
   const args = new CancelProposalArgs();
   const data = Buffer.from(serialize(GOVERNANCE_SCHEMA, args));
@@ -57,6 +58,15 @@ export const withCancelProposal = (
     },
   );
 
+  if (councilTokenOwnerRecord) {
+    // Required when a council member cancels a Succeeded Proposal within cool off time
+    keys.push({
+      pubkey: councilTokenOwnerRecord,
+      isWritable: false,
+      isSigner: false,
+    });
+  }
+
   if (programVersion == PROGRAM_VERSION_V1) {
     keys.push({
       pubkey: SYSVAR_CLOCK_PUBKEY,
@@ -22,9 +22,7 @@ import {getGovernanceSchema} from "./serialisation";
  * @param capitalTokenMint spl token mint
  * @param lpTokenAccount treasury stock account
  * @param lpTokenMint treasury stock mint
- * @param delegateTokenMint treasury stock transfer signer
- * @param amount amount of spl token to transfer in
- * @param decimals the decimal of the spl token
+ * @param amount amount of spl token to transfer in, in base units of the mint, priced at the lp per capital token ratio of the realm's capital raise
  */
 export const withDepositCapital = async (
     instructions: TransactionInstruction[],
@@ -38,14 +36,11 @@ export const withDepositCapital = async (
     capitalTokenMint: PublicKey,
     lpTokenAccount: PublicKey,
     lpTokenMint: PublicKey,
-    delegateTokenMint: PublicKey,
     amount: number,
-    decimals: number,
 ) => {
 
     const args = new DepositCapitalArgs({
-        amount: new BN(amount),
-        decimals: new BN(decimals)
+        amount: new BN(amount)
     });
 
     const data = Buffer.from(
@@ -70,6 +65,39 @@ export const withDepositCapital = async (
         ASSOCIATED_TOKEN_PROGRAM_ID
     )
 
+    const [capitalRaise] = await PublicKey.findProgramAddress(
+        [
+            Buffer.from("capital-raise"),
+            realm.toBuffer()
+        ],
+        programId
+    )
+
+    const [realmConfig] = await PublicKey.findProgramAddress(
+        [
+            Buffer.from("realm-config"),
+            realm.toBuffer()
+        ],
+        programId
+    )
+
+    const [capitalContributionRecord] = await PublicKey.findProgramAddress(
+        [
+            Buffer.from("capital-contribution"),
+            realm.toBuffer(),
+            capitalTokenAuthority.toBuffer()
+        ],
+        programId
+    )
+
+    const [frozenSubject] = await PublicKey.findProgramAddress(
+        [
+            Buffer.from("freeze"),
+            capitalTokenAuthority.toBuffer()
+        ],
+        identityVerificationProgramId
+    )
+
     const [identityVerificationRecord] = await PublicKey.findProgramAddress(
         [
             Buffer.from("identity"),
@@ -81,14 +109,14 @@ export const withDepositCapital = async (
 
     const keys = [
         {pubkey: realm, isWritable: false, isSigner: false}, // 0
-        {pubkey: lpGovernance, isWritable: true, isSigner: false}, // 1
+        {pubkey: lpGovernance, isWritable: false, isSigner: false}, // 1
         {pubkey: capitalTokenAuthority, isWritable: true, isSigner: true}, // 2
         {pubkey: capitalTokenAccount, isWritable: true, isSigner: false}, // 3
         {pubkey: capitalTokenHoldingAccount, isWritable: true, isSigner: false}, // 4
         {pubkey: lpTokenAccount, isWritable: true, isSigner: false}, // 5
         {pubkey: lpHoldingAccount, isWritable: true, isSigner: false}, // 6
         {pubkey: lpTokenMint, isWritable: false, isSigner: false}, // 7
-        {pubkey: delegateTokenMint, isWritable: false, isSigner: false}, // 8
+        {pubkey: capitalRaise, isWritable: true, isSigner: false}, // 8
         {pubkey: identityVerificationRecord, isWritable: false, isSigner: false}, // 9
         {pubkey: identityVerificationProgramId, isWritable: false, isSigner: false}, // 10
         {pubkey: TOKEN_PROGRAM_ID, isWritable: false, isSigner: false}, // 11
@@ -96,6 +124,9 @@ export const withDepositCapital = async (
         {pubkey: SYSVAR_RENT_PUBKEY, isWritable: false, isSigner: false}, // 13
         // even though we never use this it has to be added or the lp ata account creation fails
         {pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isWritable: false, isSigner: false}, // 14
+        {pubkey: realmConfig, isWritable: false, isSigner: false}, // 15
+        {pubkey: frozenSubject, isWritable: false, isSigner: false}, // 16
+        {pubkey: capitalContributionRecord, isWritable: true, isSigner: false}, // 17
     ];
 
     instructions.push(
@@ -16,6 +16,7 @@ export const withFlagTransactionError = (
   tokenOwnerRecord: PublicKey,
   governanceAuthority: PublicKey,
   proposalTransaction: PublicKey,
+  governance: PublicKey,
 ) => {
   const args = new FlagTransactionErrorArgs();
   const data = Buffer.from(serialize(GOVERNANCE_SCHEMA, args));
@@ -48,6 +49,13 @@ export const withFlagTransactionError = (
       isSigner: false,
       isWritable: false,
     });
+  } else {
+    // Breaking change: V2 needs the Governance of the Proposal to enforce the cool off time
+    keys.push({
+      pubkey: governance,
+      isSigner: false,
+      isWritable: false,
+    });
   }
 
   instructions.push(
@@ -397,6 +397,182 @@ pub enum GovernanceError {
     /// user identity not known
     #[error("The user trying to interact with the program is not known.")]
     UserIdentityNotKnown,
+
+    /// user identity approval has lapsed
+    #[error("The identity verification of the user has expired.")]
+    UserIdentityVerificationExpired,
+
+    /// Invalid investor requirements
+    #[error("Invalid investor requirements")]
+    InvalidInvestorRequirements,
+
+    /// Investor doesn't hold the investor classes required by the realm
+    #[error("Investor doesn't hold the investor classes required by the realm")]
+    InvestorClassNotEligible,
+
+    /// Investor jurisdiction is excluded by the realm
+    #[error("Investor jurisdiction is excluded by the realm")]
+    InvestorJurisdictionNotEligible,
+
+    /// Invalid frozen subject account
+    #[error("Invalid frozen subject account")]
+    InvalidFrozenSubjectAccount,
+
+    /// user identity is frozen
+    #[error("The user trying to interact with the program is frozen.")]
+    UserIdentityFrozen,
+
+    /// Invalid permissioned list for the realm
+    #[error("Invalid permissioned list for the realm")]
+    InvalidPermissionedList,
+
+    /// Governing token owner is not a member of the realm's permissioned list
+    #[error("Governing token owner is not a member of the realm's permissioned list")]
+    NotPermissionedListMember,
+
+    /// Invalid permissioned list entry account
+    #[error("Invalid permissioned list entry account")]
+    InvalidPermissionedListEntry,
+
+    /// Governing token owner is on the realm's deny list
+    #[error("Governing token owner is on the realm's deny list")]
+    DeniedByPermissionedList,
+
+    /// Realm has no identity verification program configured
+    #[error("Realm has no identity verification program configured")]
+    IdentityVerificationProgramNotConfigured,
+
+    /// Identity verification program isn't the one trusted by the realm
+    #[error("Identity verification program isn't the one trusted by the realm")]
+    InvalidIdentityVerificationProgram,
+
+    /// Identity record address doesn't match the subject and the realm
+    #[error("Identity record address doesn't match the subject and the realm")]
+    InvalidIdentityRecordAddress,
+
+    /// Identity record isn't owned by the trusted identity verification program
+    #[error("Identity record isn't owned by the trusted identity verification program")]
+    InvalidIdentityRecordOwner,
+
+    /// Account isn't an identity record
+    #[error("Account isn't an identity record")]
+    InvalidIdentityRecordDiscriminator,
+
+    /// Invalid identity policy
+    #[error("Invalid identity policy")]
+    InvalidIdentityPolicy,
+
+    /// Invalid capital raise config
+    #[error("Invalid capital raise config")]
+    InvalidCapitalRaiseConfig,
+
+    /// Invalid Realm for capital raise
+    #[error("Invalid Realm for capital raise")]
+    InvalidRealmForCapitalRaise,
+
+    /// Accounts don't match the capital raise
+    #[error("Accounts don't match the capital raise")]
+    InvalidCapitalRaiseAccounts,
+
+    /// Capital raise is not open for deposits
+    #[error("Capital raise is not open for deposits")]
+    CapitalRaiseNotOpen,
+
+    /// Capital amount is outside of the capital raise ticket range
+    #[error("Capital amount is outside of the capital raise ticket range")]
+    CapitalRaiseTicketOutOfRange,
+
+    /// Capital raise max raise exceeded
+    #[error("Capital raise max raise exceeded")]
+    CapitalRaiseMaxRaiseExceeded,
+
+    /// Capital deposit is too small to issue any LP tokens
+    #[error("Capital deposit is too small to issue any LP tokens")]
+    CapitalRaiseDepositTooSmall,
+
+    /// Capital raise math overflow
+    #[error("Capital raise math overflow")]
+    CapitalRaiseMathOverflow,
+
+    /// Invalid capital contribution record
+    #[error("Invalid capital contribution record")]
+    InvalidCapitalContributionRecord,
+
+    /// Invalid capital withdraw amount
+    #[error("Invalid capital withdraw amount")]
+    InvalidCapitalWithdrawAmount,
+
+    /// Capital can only be withdrawn while the raise is open or after it failed
+    #[error("Capital can only be withdrawn while the raise is open or after it failed")]
+    CapitalRaiseNotRefundable,
+
+    /// Invalid state for the capital raise operation
+    #[error("Invalid state for the capital raise operation")]
+    InvalidCapitalRaiseState,
+
+    /// Capital raise hasn't closed yet
+    #[error("Capital raise hasn't closed yet")]
+    CapitalRaiseNotClosed,
+
+    /// Invalid distribution amount
+    #[error("Invalid distribution amount")]
+    InvalidDistributionAmount,
+
+    /// Accounts don't match the distribution
+    #[error("Accounts don't match the distribution")]
+    InvalidDistributionAccounts,
+
+    /// LP token supply is empty
+    #[error("LP token supply is empty")]
+    DistributionLpSupplyEmpty,
+
+    /// Distribution already claimed
+    #[error("Distribution already claimed")]
+    DistributionAlreadyClaimed,
+
+    /// Nothing to claim from the distribution
+    #[error("Nothing to claim from the distribution")]
+    NothingToClaim,
+
+    /// Distribution math overflow
+    #[error("Distribution math overflow")]
+    DistributionMathOverflow,
+
+    /// Invalid max vote weight absolute value
+    #[error("Invalid max vote weight absolute value")]
+    InvalidMaxVoteWeightAbsoluteValue,
+
+    /// Cannot execute transaction within cool off time
+    #[error("Cannot execute transaction within cool off time")]
+    CannotExecuteTransactionWithinCoolOffTime,
+
+    /// Council veto is not enabled for the Governance
+    #[error("Council veto is not enabled for the Governance")]
+    CouncilVetoNotEnabled,
+
+    /// Veto vote must be cast with the council mint on a community Proposal
+    #[error("Veto vote must be cast with the council mint on a community Proposal")]
+    InvalidGoverningTokenMintForVetoVote,
+
+    /// Identity verification program can't be removed while the Realm uses identity verification
+    #[error("Identity verification program can't be removed while the Realm uses identity verification")]
+    IdentityVerificationProgramInUse,
+
+    /// Investor must sign transaction
+    #[error("Investor must sign transaction")]
+    InvestorMustSign,
+
+    /// Council veto is not supported by Governance V1 accounts
+    #[error("Council veto is not supported by Governance V1 accounts")]
+    CouncilVetoNotSupportedByGovernanceV1,
+
+    /// Claimant and LP amount are not in the holders snapshot of the Distribution
+    #[error("Claimant and LP amount are not in the holders snapshot of the Distribution")]
+    InvalidDistributionClaimProof,
+
+    /// Owner doesn't have enough council tokens to cancel Proposal within cool off time
+    #[error("Owner doesn't have enough council tokens to cancel Proposal within cool off time")]
+    NotEnoughTokensToCancelProposal,
 }
 
 impl PrintProgramError for GovernanceError {
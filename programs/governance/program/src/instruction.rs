@@ -7,6 +7,10 @@ use solana_program::{bpf_loader_upgradeable, instruction::{AccountMeta, Instruct
 
 use crate::{
     state::{
+        capital_contribution_record::get_capital_contribution_record_address,
+        capital_raise::{get_capital_raise_address, CapitalRaiseConfig},
+        distribution::{get_distribution_address, get_distribution_holding_address},
+        distribution_claim_record::get_distribution_claim_record_address,
         enums::MintMaxVoteWeightSource,
         governance::{
             get_governance_address, get_mint_governance_address, get_program_governance_address,
@@ -18,7 +22,7 @@ use crate::{
         proposal_transaction::{get_proposal_transaction_address, InstructionData},
         realm::{get_governing_token_holding_address, get_realm_address, RealmConfigArgs},
         realm::SetRealmAuthorityAction,
-        realm_config::get_realm_config_address,
+        realm_config::{get_realm_config_address, IdentityPolicy, InvestorRequirements},
         signatory_record::get_signatory_record_address,
         token_owner_record::get_token_owner_record_address,
         vote_record::{get_vote_record_address, Vote},
@@ -73,7 +77,12 @@ pub enum GovernanceInstruction {
     ///  6. `[signer]` Payer
     ///  7. `[]` System
     ///  8. `[]` SPL Token
-    ///  9. `[]` Sysvar Rent
+    ///  9. `[]` Optional Realm Config, required when the Realm uses a permissioned list or identity verification
+    ///  10. `[]` Optional Permissioned List, required when the Realm uses a permissioned list
+    ///  11. `[]` Optional Permissioned List entry of the Governing Token Owner. PDA seeds: [permissioned_list, governing_token_owner]
+    ///  12. `[]` Optional Identity Record of the Governing Token Owner, required when the Realm uses identity verification. PDA seeds: ['identity', realm, governing_token_owner]
+    ///  13. `[]` Optional Identity Verification program
+    ///  14. `[]` Optional Frozen Subject account of the Governing Token Owner. PDA seeds: ['freeze', governing_token_owner]
     DepositGoverningTokens {
         /// The amount to deposit into the realm
         #[allow(dead_code)]
@@ -163,6 +172,11 @@ pub enum GovernanceInstruction {
     ///   7. `[]` System program
     ///   8. `[]` Realm Config
     ///   9. `[]` Optional Voter Weight Record
+    ///   10. `[]` Optional Permissioned List, required when the Realm uses a permissioned list
+    ///   11. `[]` Optional Permissioned List entry of the Proposal owner. PDA seeds: [permissioned_list, governing_token_owner]
+    ///   12. `[]` Optional Identity Record of the Proposal owner, required when the Realm uses identity verification. PDA seeds: ['identity', realm, governing_token_owner]
+    ///   13. `[]` Optional Identity Verification program
+    ///   14. `[]` Optional Frozen Subject account of the Proposal owner. PDA seeds: ['freeze', governing_token_owner]
     CreateProposal {
         #[allow(dead_code)]
         /// UTF-8 encoded name of the proposal
@@ -254,12 +268,16 @@ pub enum GovernanceInstruction {
     RemoveTransaction,
 
     /// Cancels Proposal by changing its state to Canceled
+    /// Succeeded Proposal can be canceled by the Realm authority or a council member within the Governance proposal_cool_off_time
     ///
     ///   0. `[writable]` Realm account
     ///   1. `[writable]` Governance account
     ///   2. `[writable]` Proposal account
     ///   3. `[writable]`  TokenOwnerRecord account of the  Proposal owner
     ///   4. `[signer]` Governance Authority (Token Owner or Governance Delegate)
+    ///       Or Realm authority or council Token Owner or Governance Delegate when the Proposal is within cool off time
+    ///   5. `[]` Optional council TokenOwnerRecord of the Governance Authority, required when the Proposal is canceled
+    ///       within cool off time by a council member. The council weight required to create a proposal is required to cancel it
     CancelProposal,
 
     /// Signs off Proposal indicating the Signatory approves the Proposal
@@ -280,6 +298,7 @@ pub enum GovernanceInstruction {
     ///  Uses your voter weight (deposited Community or Council tokens) to cast a vote on a Proposal
     ///  By doing so you indicate you approve or disapprove of running the Proposal set of transactions
     ///  If you tip the consensus then the transactions can begin to be run after their hold up time
+    ///  Council members can cast Veto vote on a community Proposal while it's being voted on or within its cool off time
     ///
     ///   0. `[writable]` Realm account
     ///   1. `[writable]` Governance account
@@ -288,12 +307,17 @@ pub enum GovernanceInstruction {
     ///   3. `[writable]` TokenOwnerRecord of the voter. PDA seeds: ['governance',realm, governing_token_mint, governing_token_owner]
     ///   4. `[signer]` Governance Authority (Token Owner or Governance Delegate)
     ///   5. `[writable]` Proposal VoteRecord account. PDA seeds: ['governance',proposal,governing_token_owner_record]
-    ///   6. `[]` Governing Token Mint
+    ///   6. `[]` Governing Token Mint of the voter. The council mint for Veto vote
     ///   7. `[signer]` Payer
     ///   8. `[]` System program
     ///   9. `[]` Realm Config
     ///   10. `[]` Optional Voter Weight Record
-    ///   11. `[]` Optional Max Voter Weight Record
+    ///   11. `[]` Optional Max Voter Weight Record, required when the Realm uses the max voter weight addin even if it's not used for Veto vote
+    ///   12. `[]` Optional Permissioned List, required when the Realm uses a permissioned list
+    ///   13. `[]` Optional Permissioned List entry of the voter. PDA seeds: [permissioned_list, governing_token_owner]
+    ///   14. `[]` Optional Identity Record of the voter, required when the Realm uses identity verification. PDA seeds: ['identity', realm, governing_token_owner]
+    ///   15. `[]` Optional Identity Verification program
+    ///   16. `[]` Optional Frozen Subject account of the voter. PDA seeds: ['freeze', governing_token_owner]
     CastVote {
         #[allow(dead_code)]
         /// User's vote
@@ -320,7 +344,7 @@ pub enum GovernanceInstruction {
     ///   1. `[writable]` Proposal account
     ///   2. `[writable]` TokenOwnerRecord account. PDA seeds: ['governance',realm, governing_token_mint, governing_token_owner]
     ///   3. `[writable]` Proposal VoteRecord account. PDA seeds: ['governance',proposal,governing_token_owner_record]
-    ///   4. `[]` Governing Token Mint
+    ///   4. `[]` Governing Token Mint of the voter. The council mint for Veto vote
     ///   5. `[signer]` Optional Governance Authority (Token Owner or Governance Delegate)
     ///       It's required only when Proposal is still being voted on
     ///   6. `[writable]` Optional Beneficiary account which would receive lamports when VoteRecord Account is disposed
@@ -408,6 +432,9 @@ pub enum GovernanceInstruction {
     ///   1. `[]` TokenOwnerRecord account of the Proposal owner
     ///   2. `[signer]` Governance Authority (Token Owner or Governance Delegate)    
     ///   3. `[writable]` ProposalTransaction account to flag
+    ///   4. `[]` Governance account of the Proposal
+    ///       Note: Breaking change, the account is required to enforce the Proposal cool off time
+    ///       and clients built for the previous version must append it
     FlagTransactionError,
 
     /// Sets new Realm authority
@@ -471,6 +498,7 @@ pub enum GovernanceInstruction {
     CreateNativeTreasury,
 
     /// Deposits capital tokens into capital treasury and distribute community governance tokens
+    /// The deposit is validated against the Realm's CapitalRaise and priced at its LP per capital token ratio
     ///
     /// 0. `[]` Realm account
     /// 1. `[]` LP Governance who owns the lp holding token account
@@ -480,21 +508,228 @@ pub enum GovernanceInstruction {
     /// 5. `[writable]` LP Token Account
     /// 6. `[writable]` LP Holding Token Account
     /// 7. `[]` LP Token Mint
-    /// 8. `[]` Delegate Token Mint
-    /// 9. `[]` Identity Verification Record
-    /// 10. `[]` Identity Verification Program
+    /// 8. `[writable]` CapitalRaise account. PDA seeds: ['capital-raise', realm]
+    /// 9. `[]` Identity Verification Record. PDA seeds: ['identity', realm, capital_token_account_authority]
+    /// 10. `[]` Identity Verification Program, must be the program set on the RealmConfig account
     /// 11. `[]` Token Program
     /// 12. `[]` System Program
     /// 13. `[]` Rent Program
     /// 14. `[]` Associated Token Program
+    /// 15. `[]` RealmConfig account. PDA seeds: ['realm-config', realm]
+    /// 16. `[]` Frozen Subject account of the Identity Verification Program. PDA seeds: ['freeze', capital_token_account_authority]
+    /// 17. `[writable]` CapitalContributionRecord account. PDA seeds: ['capital-contribution', realm, capital_token_account_authority]
     DepositCapital {
         #[allow(dead_code)]
         /// The amount of capital tokens to deposit into the capital treasury
         amount: u64,
+    },
 
+    /// Sets the investor requirements enforced by DepositCapital
+    /// The RealmConfig account is created if it doesn't exist yet
+    ///
+    ///   0. `[]` Realm account
+    ///   1. `[signer]` Realm authority
+    ///   2. `[writable]` RealmConfig account. PDA seeds: ['realm-config', realm]
+    ///   3. `[signer]` Payer
+    ///   4. `[]` System
+    SetRealmInvestorRequirements {
         #[allow(dead_code)]
-        /// The amount of decimals associated with the capital token mint
-        decimals: u8
+        /// Investor requirements
+        investor_requirements: InvestorRequirements,
+    },
+
+    /// Sets the permissioned list token owners must be members of
+    /// to deposit governing tokens, create proposals and cast votes
+    /// The RealmConfig account is created if it doesn't exist yet
+    ///
+    ///   0. `[writable]` Realm account
+    ///   1. `[signer]` Realm authority
+    ///   2. `[writable]` RealmConfig account. PDA seeds: ['realm-config', realm]
+    ///   3. `[signer]` Payer
+    ///   4. `[]` System
+    SetRealmPermissionedList {
+        #[allow(dead_code)]
+        /// Permissioned list account, None to stop gating the Realm
+        permissioned_list: Option<Pubkey>,
+    },
+
+    /// Deposits governing tokens like DepositGoverningTokens
+    /// but shows membership of the Realm's permissioned list with a merkle proof instead of a list entry account
+    ///
+    ///  0. `[]` Governance Realm account
+    ///  1. `[writable]` Governing Token Holding account. PDA seeds: ['governance',realm, governing_token_mint]
+    ///  2. `[writable]` Governing Token Source account. All tokens from the account will be transferred to the Holding account
+    ///  3. `[signer]` Governing Token Owner account
+    ///  4. `[signer]` Governing Token Transfer authority
+    ///  5. `[writable]` Token Owner Record account. PDA seeds: ['governance',realm, governing_token_mint, governing_token_owner]
+    ///  6. `[signer]` Payer
+    ///  7. `[]` System
+    ///  8. `[]` SPL Token
+    ///  9. `[]` Realm Config
+    ///  10. `[]` Permissioned List
+    ///  11. `[]` Optional Identity Record of the Governing Token Owner, required when the Realm uses identity verification. PDA seeds: ['identity', realm, governing_token_owner]
+    ///  12. `[]` Optional Identity Verification program
+    ///  13. `[]` Optional Frozen Subject account of the Governing Token Owner. PDA seeds: ['freeze', governing_token_owner]
+    DepositGoverningTokensWithProof {
+        #[allow(dead_code)]
+        /// The amount to deposit into the realm
+        amount: u64,
+
+        #[allow(dead_code)]
+        /// Sibling hashes from the leaf of the Governing Token Owner up to the merkle root of the list
+        membership_proof: Vec<[u8; 32]>,
+    },
+
+    /// Sets the identity verification program the Realm trusts to own the identity records of investors
+    /// The RealmConfig account is created if it doesn't exist yet
+    /// The program can't be removed while the Realm uses identity verification
+    ///
+    ///   0. `[]` Realm account
+    ///   1. `[signer]` Realm authority
+    ///   2. `[writable]` RealmConfig account. PDA seeds: ['realm-config', realm]
+    ///   3. `[signer]` Payer
+    ///   4. `[]` System
+    SetRealmIdentityVerificationProgram {
+        #[allow(dead_code)]
+        /// Identity verification program id
+        identity_verification_program: Option<Pubkey>,
+    },
+
+    /// Sets the identity checks the Realm requires from investors
+    /// The RealmConfig account is created if it doesn't exist yet
+    ///
+    ///   0. `[]` Realm account
+    ///   1. `[signer]` Realm authority
+    ///   2. `[writable]` RealmConfig account. PDA seeds: ['realm-config', realm]
+    ///   3. `[signer]` Payer
+    ///   4. `[]` System
+    SetRealmIdentityPolicy {
+        #[allow(dead_code)]
+        /// Identity policy
+        identity_policy: IdentityPolicy,
+    },
+
+    /// Sets whether governing token owners must hold a valid identity record
+    /// to deposit governing tokens, create proposals and cast votes
+    /// The identity verification program must be set on the RealmConfig account to enable it
+    ///
+    ///   0. `[writable]` Realm account
+    ///   1. `[signer]` Realm authority
+    ///   2. `[]` RealmConfig account. PDA seeds: ['realm-config', realm]
+    SetRealmUseIdentityVerification {
+        #[allow(dead_code)]
+        /// Indicates whether identity verification is required
+        use_identity_verification: bool,
+    },
+
+    /// Creates the CapitalRaise account of a Realm which DepositCapital is validated against
+    ///
+    ///   0. `[]` Realm account
+    ///   1. `[signer]` Realm authority
+    ///   2. `[writable]` CapitalRaise account. PDA seeds: ['capital-raise', realm]
+    ///   3. `[]` Capital Token Mint
+    ///   4. `[]` LP Token Mint
+    ///   5. `[]` LP Governance who owns the LP holding token account
+    ///   6. `[]` Capital Governance who owns the Capital Holding Token Account
+    ///   7. `[]` Capital Holding Token Account
+    ///   8. `[]` LP Holding Token Account
+    ///   9. `[signer]` Payer
+    ///   10. `[]` System
+    CreateCapitalRaise {
+        #[allow(dead_code)]
+        /// Capital raise config
+        config: CapitalRaiseConfig,
+    },
+
+    /// Withdraws capital tokens deposited with DepositCapital and gives back the LP tokens issued for them
+    /// Capital can be withdrawn only while the CapitalRaise is open or after it failed
+    ///
+    ///   0. `[]` Realm account
+    ///   1. `[writable]` CapitalRaise account. PDA seeds: ['capital-raise', realm]
+    ///   2. `[]` Capital Governance who owns the Capital Holding Token Account
+    ///   3. `[signer]` Investor
+    ///   4. `[writable]` Capital Token Account receiving the capital tokens
+    ///   5. `[writable]` Capital Holding Token Account
+    ///   6. `[writable]` LP Token Account of the Investor
+    ///   7. `[writable]` LP Holding Token Account
+    ///   8. `[writable]` CapitalContributionRecord account. PDA seeds: ['capital-contribution', realm, investor]
+    ///   9. `[]` SPL Token
+    WithdrawCapital {
+        #[allow(dead_code)]
+        /// The amount of capital tokens to withdraw
+        amount: u64,
+    },
+
+    /// Refunds the whole contribution of an investor
+    /// It takes the same accounts as WithdrawCapital
+    RefundCapital {},
+
+    /// Cancels an open CapitalRaise and marks it as failed so investors can withdraw their capital
+    ///
+    ///   0. `[]` Realm account
+    ///   1. `[signer]` Realm authority
+    ///   2. `[writable]` CapitalRaise account. PDA seeds: ['capital-raise', realm]
+    CancelCapitalRaise {},
+
+    /// Marks a CapitalRaise past its close timestamp as succeeded or failed depending on whether its min raise was collected
+    ///
+    ///   0. `[]` Realm account
+    ///   1. `[writable]` CapitalRaise account. PDA seeds: ['capital-raise', realm]
+    FinalizeCapitalRaise {},
+
+    /// Creates a Distribution funded from a Governance treasury token account
+    /// The Distribution takes a snapshot of the LP supply issued by the Realm's CapitalRaise
+    /// and LP token holders can claim their pro rata share of it with ClaimDistribution
+    /// The LP token balances of the holders are committed to with a merkle root computed off chain
+    /// Note: The instruction must be signed by the Governance PDA and is meant to be executed by a Proposal
+    ///
+    ///   0. `[]` Realm account
+    ///   1. `[signer]` Governance account funding the distribution
+    ///   2. `[writable]` Distribution account. PDA seeds: ['distribution', governance, distribution_index]
+    ///   3. `[writable]` Distribution Holding Token Account. PDA seeds: ['distribution-holding', distribution]
+    ///   4. `[writable]` Treasury Token Account owned by the Governance the tokens are transferred from
+    ///   5. `[]` Distribution Token Mint
+    ///   6. `[]` CapitalRaise account. PDA seeds: ['capital-raise', realm]
+    ///   7. `[]` LP Token Mint
+    ///   8. `[]` LP Holding Token Account
+    ///   9. `[signer]` Payer
+    ///   10. `[]` System
+    ///   11. `[]` SPL Token
+    ///   12. `[]` Sysvar Rent
+    CreateDistribution {
+        #[allow(dead_code)]
+        /// Index of the distribution within the Governance
+        distribution_index: u64,
+
+        #[allow(dead_code)]
+        /// The amount of tokens to distribute
+        amount: u64,
+
+        #[allow(dead_code)]
+        /// Root of a merkle tree of (holder, lp_amount) leaves taken from the LP token balances at funding
+        /// Leaves are keccak(0, holder, lp_amount as u64 le) and nodes keccak(1, min(a, b), max(a, b))
+        holders_merkle_root: [u8; 32],
+    },
+
+    /// Claims the pro rata share of a Distribution for the LP tokens the claimant held in its holders snapshot
+    /// Each LP token holder can claim only once from a Distribution
+    ///
+    ///   0. `[writable]` Distribution account
+    ///   1. `[writable]` Distribution Holding Token Account. PDA seeds: ['distribution-holding', distribution]
+    ///   2. `[signer]` Claimant
+    ///   3. `[writable]` Token Account receiving the distributed tokens
+    ///   4. `[writable]` DistributionClaimRecord account. PDA seeds: ['distribution-claim', distribution, claimant]
+    ///   5. `[signer]` Payer
+    ///   6. `[]` System
+    ///   7. `[]` SPL Token
+    ClaimDistribution {
+        #[allow(dead_code)]
+        /// LP token balance of the claimant in the holders snapshot
+        lp_amount: u64,
+
+        #[allow(dead_code)]
+        /// Sibling hashes from the leaf of the claimant up to the holders merkle root of the Distribution
+        proof: Vec<[u8; 32]>,
     },
 
 }
@@ -513,17 +748,20 @@ pub fn deposit_capital(
     lp_token_account: &Pubkey,
     lp_holding_account: &Pubkey,
     lp_token_mint: &Pubkey,
-    delegate_token_mint: &Pubkey,
     identity_verification_record: &Pubkey,
     identity_verification_program: &Pubkey,
     // Args
     amount: u64,
-    decimals: u8,
 ) -> Instruction {
 
     // @TODO: Remove once ::id has been added to the associated token account spl library
     let spl_associated_token_account_id = Pubkey::from_str("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL").unwrap();
 
+    let frozen_subject_address = Pubkey::find_program_address(
+        &[b"freeze", capital_token_account_authority.as_ref()],
+        identity_verification_program,
+    ).0;
+
     let accounts = vec![
         AccountMeta::new_readonly(*realm, false), // 0
         AccountMeta::new_readonly(*lp_governance, false), // 1
@@ -533,16 +771,26 @@ pub fn deposit_capital(
         AccountMeta::new(*lp_token_account, false), // 5
         AccountMeta::new(*lp_holding_account, false), // 6
         AccountMeta::new_readonly(*lp_token_mint, false), // 7
-        AccountMeta::new_readonly(*delegate_token_mint, false), // 8
+        AccountMeta::new(get_capital_raise_address(program_id, realm), false), // 8
         AccountMeta::new_readonly(*identity_verification_record, false), // 9
         AccountMeta::new_readonly(*identity_verification_program, false), // 10
         AccountMeta::new_readonly(spl_token::id(), false), // 11
         AccountMeta::new_readonly(system_program::id(), false), // 12
         AccountMeta::new_readonly(sysvar::rent::id(), false), // 13
         AccountMeta::new_readonly(spl_associated_token_account_id, false), // 14
+        AccountMeta::new_readonly(get_realm_config_address(program_id, realm), false), // 15
+        AccountMeta::new_readonly(frozen_subject_address, false), // 16
+        AccountMeta::new(
+            get_capital_contribution_record_address(
+                program_id,
+                realm,
+                capital_token_account_authority,
+            ),
+            false,
+        ), // 17
     ];
 
-    let instruction = GovernanceInstruction::DepositCapital { amount, decimals };
+    let instruction = GovernanceInstruction::DepositCapital { amount };
 
     Instruction {
         program_id: *program_id,
@@ -653,6 +901,8 @@ pub fn deposit_governing_tokens(
     governing_token_owner: &Pubkey,
     governing_token_transfer_authority: &Pubkey,
     payer: &Pubkey,
+    permissioned_list: Option<(Pubkey, Pubkey)>,
+    identity_verification_program: Option<Pubkey>,
     // Args
     amount: u64,
     governing_token_mint: &Pubkey,
@@ -667,7 +917,7 @@ pub fn deposit_governing_tokens(
     let governing_token_holding_address =
         get_governing_token_holding_address(program_id, realm, governing_token_mint);
 
-    let accounts = vec![
+    let mut accounts = vec![
         AccountMeta::new_readonly(*realm, false),
         AccountMeta::new(governing_token_holding_address, false),
         AccountMeta::new(*governing_token_source, false),
@@ -679,6 +929,18 @@ pub fn deposit_governing_tokens(
         AccountMeta::new_readonly(spl_token::id(), false),
     ];
 
+    if permissioned_list.is_some() || identity_verification_program.is_some() {
+        let realm_config_address = get_realm_config_address(program_id, realm);
+        accounts.push(AccountMeta::new_readonly(realm_config_address, false));
+    }
+
+    with_permissioned_list_accounts(&mut accounts, permissioned_list);
+    with_identity_verification_accounts(
+        &mut accounts,
+        realm,
+        identity_verification_program.map(|program| (program, *governing_token_owner)),
+    );
+
     let instruction = GovernanceInstruction::DepositGoverningTokens { amount };
 
     Instruction {
@@ -949,6 +1211,8 @@ pub fn create_proposal(
     governance_authority: &Pubkey,
     payer: &Pubkey,
     voter_weight_record: Option<Pubkey>,
+    permissioned_list: Option<(Pubkey, Pubkey)>,
+    identity_verification: Option<(Pubkey, Pubkey)>,
     // Args
     realm: &Pubkey,
     name: String,
@@ -978,6 +1242,8 @@ pub fn create_proposal(
     ];
 
     with_realm_config_accounts(program_id, &mut accounts, realm, voter_weight_record, None);
+    with_permissioned_list_accounts(&mut accounts, permissioned_list);
+    with_identity_verification_accounts(&mut accounts, realm, identity_verification);
 
     let instruction = GovernanceInstruction::CreateProposal {
         name,
@@ -1107,6 +1373,8 @@ pub fn cast_vote(
     payer: &Pubkey,
     voter_weight_record: Option<Pubkey>,
     max_voter_weight_record: Option<Pubkey>,
+    permissioned_list: Option<(Pubkey, Pubkey)>,
+    identity_verification: Option<(Pubkey, Pubkey)>,
     // Args
     vote: Vote,
 ) -> Instruction {
@@ -1133,6 +1401,8 @@ pub fn cast_vote(
         voter_weight_record,
         max_voter_weight_record,
     );
+    with_permissioned_list_accounts(&mut accounts, permissioned_list);
+    with_identity_verification_accounts(&mut accounts, realm, identity_verification);
 
     let instruction = GovernanceInstruction::CastVote { vote };
 
@@ -1223,8 +1493,9 @@ pub fn cancel_proposal(
     proposal: &Pubkey,
     proposal_owner_record: &Pubkey,
     governance_authority: &Pubkey,
+    council_token_owner_record: Option<Pubkey>,
 ) -> Instruction {
-    let accounts = vec![
+    let mut accounts = vec![
         AccountMeta::new(*realm, false),
         AccountMeta::new(*governance, false),
         AccountMeta::new(*proposal, false),
@@ -1232,6 +1503,10 @@ pub fn cancel_proposal(
         AccountMeta::new_readonly(*governance_authority, true),
     ];
 
+    if let Some(council_token_owner_record) = council_token_owner_record {
+        accounts.push(AccountMeta::new_readonly(council_token_owner_record, false));
+    }
+
     let instruction = GovernanceInstruction::CancelProposal {};
 
     Instruction {
@@ -1364,6 +1639,7 @@ pub fn set_governance_config(
 }
 
 /// Creates FlagTransactionError instruction
+/// Note: Breaking change, the governance of the Proposal is a required account since the cool off time was introduced
 pub fn flag_transaction_error(
     program_id: &Pubkey,
     // Accounts
@@ -1371,12 +1647,14 @@ pub fn flag_transaction_error(
     token_owner_record: &Pubkey,
     governance_authority: &Pubkey,
     proposal_transaction: &Pubkey,
+    governance: &Pubkey,
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new(*proposal, false),
         AccountMeta::new_readonly(*token_owner_record, false),
         AccountMeta::new_readonly(*governance_authority, true),
         AccountMeta::new(*proposal_transaction, false),
+        AccountMeta::new_readonly(*governance, false),
     ];
 
     let instruction = GovernanceInstruction::FlagTransactionError {};
@@ -1531,6 +1809,44 @@ pub fn with_realm_config_accounts(
     };
 }
 
+/// Adds the permissioned list and the list entry of the token owner
+/// Both accounts are required when the Realm uses a permissioned list
+pub fn with_permissioned_list_accounts(
+    accounts: &mut Vec<AccountMeta>,
+    permissioned_list: Option<(Pubkey, Pubkey)>,
+) {
+    if let Some((permissioned_list, list_entry)) = permissioned_list {
+        accounts.push(AccountMeta::new_readonly(permissioned_list, false));
+        accounts.push(AccountMeta::new_readonly(list_entry, false));
+    }
+}
+
+/// Adds the identity record, identity verification program and frozen subject accounts
+/// for the given (identity_verification_program, governing_token_owner) pair
+pub fn with_identity_verification_accounts(
+    accounts: &mut Vec<AccountMeta>,
+    realm: &Pubkey,
+    identity_verification: Option<(Pubkey, Pubkey)>,
+) {
+    if let Some((identity_verification_program, governing_token_owner)) = identity_verification {
+        let identity_record_address = Pubkey::find_program_address(
+            &[b"identity", realm.as_ref(), governing_token_owner.as_ref()],
+            &identity_verification_program,
+        )
+        .0;
+
+        let frozen_subject_address = Pubkey::find_program_address(
+            &[b"freeze", governing_token_owner.as_ref()],
+            &identity_verification_program,
+        )
+        .0;
+
+        accounts.push(AccountMeta::new_readonly(identity_record_address, false));
+        accounts.push(AccountMeta::new_readonly(identity_verification_program, false));
+        accounts.push(AccountMeta::new_readonly(frozen_subject_address, false));
+    }
+}
+
 /// Creates CreateTokenOwnerRecord instruction
 pub fn create_token_owner_record(
     program_id: &Pubkey,
@@ -1612,3 +1928,430 @@ pub fn create_native_treasury(
         data: instruction.try_to_vec().unwrap(),
     }
 }
+
+/// Creates SetRealmInvestorRequirements instruction
+pub fn set_realm_investor_requirements(
+    program_id: &Pubkey,
+    // Accounts
+    realm: &Pubkey,
+    realm_authority: &Pubkey,
+    payer: &Pubkey,
+    // Args
+    investor_requirements: InvestorRequirements,
+) -> Instruction {
+    let realm_config_address = get_realm_config_address(program_id, realm);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*realm, false),
+        AccountMeta::new_readonly(*realm_authority, true),
+        AccountMeta::new(realm_config_address, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    let instruction = GovernanceInstruction::SetRealmInvestorRequirements {
+        investor_requirements,
+    };
+
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    }
+}
+
+/// Creates SetRealmPermissionedList instruction
+pub fn set_realm_permissioned_list(
+    program_id: &Pubkey,
+    // Accounts
+    realm: &Pubkey,
+    realm_authority: &Pubkey,
+    payer: &Pubkey,
+    // Args
+    permissioned_list: Option<Pubkey>,
+) -> Instruction {
+    let realm_config_address = get_realm_config_address(program_id, realm);
+
+    let accounts = vec![
+        AccountMeta::new(*realm, false),
+        AccountMeta::new_readonly(*realm_authority, true),
+        AccountMeta::new(realm_config_address, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    let instruction = GovernanceInstruction::SetRealmPermissionedList { permissioned_list };
+
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    }
+}
+
+/// Creates DepositGoverningTokensWithProof instruction
+#[allow(clippy::too_many_arguments)]
+pub fn deposit_governing_tokens_with_proof(
+    program_id: &Pubkey,
+    // Accounts
+    realm: &Pubkey,
+    governing_token_source: &Pubkey,
+    governing_token_owner: &Pubkey,
+    governing_token_transfer_authority: &Pubkey,
+    payer: &Pubkey,
+    permissioned_list: &Pubkey,
+    identity_verification_program: Option<Pubkey>,
+    // Args
+    amount: u64,
+    governing_token_mint: &Pubkey,
+    membership_proof: Vec<[u8; 32]>,
+) -> Instruction {
+    let token_owner_record_address = get_token_owner_record_address(
+        program_id,
+        realm,
+        governing_token_mint,
+        governing_token_owner,
+    );
+
+    let governing_token_holding_address =
+        get_governing_token_holding_address(program_id, realm, governing_token_mint);
+
+    let realm_config_address = get_realm_config_address(program_id, realm);
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*realm, false),
+        AccountMeta::new(governing_token_holding_address, false),
+        AccountMeta::new(*governing_token_source, false),
+        AccountMeta::new_readonly(*governing_token_owner, true),
+        AccountMeta::new_readonly(*governing_token_transfer_authority, true),
+        AccountMeta::new(token_owner_record_address, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(realm_config_address, false),
+        AccountMeta::new_readonly(*permissioned_list, false),
+    ];
+
+    with_identity_verification_accounts(
+        &mut accounts,
+        realm,
+        identity_verification_program.map(|program| (program, *governing_token_owner)),
+    );
+
+    let instruction = GovernanceInstruction::DepositGoverningTokensWithProof {
+        amount,
+        membership_proof,
+    };
+
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    }
+}
+
+/// Creates SetRealmIdentityVerificationProgram instruction
+pub fn set_realm_identity_verification_program(
+    program_id: &Pubkey,
+    // Accounts
+    realm: &Pubkey,
+    realm_authority: &Pubkey,
+    payer: &Pubkey,
+    // Args
+    identity_verification_program: Option<Pubkey>,
+) -> Instruction {
+    let realm_config_address = get_realm_config_address(program_id, realm);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*realm, false),
+        AccountMeta::new_readonly(*realm_authority, true),
+        AccountMeta::new(realm_config_address, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    let instruction = GovernanceInstruction::SetRealmIdentityVerificationProgram {
+        identity_verification_program,
+    };
+
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    }
+}
+
+/// Creates SetRealmIdentityPolicy instruction
+pub fn set_realm_identity_policy(
+    program_id: &Pubkey,
+    // Accounts
+    realm: &Pubkey,
+    realm_authority: &Pubkey,
+    payer: &Pubkey,
+    // Args
+    identity_policy: IdentityPolicy,
+) -> Instruction {
+    let realm_config_address = get_realm_config_address(program_id, realm);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*realm, false),
+        AccountMeta::new_readonly(*realm_authority, true),
+        AccountMeta::new(realm_config_address, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    let instruction = GovernanceInstruction::SetRealmIdentityPolicy { identity_policy };
+
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    }
+}
+
+/// Creates SetRealmUseIdentityVerification instruction
+pub fn set_realm_use_identity_verification(
+    program_id: &Pubkey,
+    // Accounts
+    realm: &Pubkey,
+    realm_authority: &Pubkey,
+    // Args
+    use_identity_verification: bool,
+) -> Instruction {
+    let realm_config_address = get_realm_config_address(program_id, realm);
+
+    let accounts = vec![
+        AccountMeta::new(*realm, false),
+        AccountMeta::new_readonly(*realm_authority, true),
+        AccountMeta::new_readonly(realm_config_address, false),
+    ];
+
+    let instruction = GovernanceInstruction::SetRealmUseIdentityVerification {
+        use_identity_verification,
+    };
+
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    }
+}
+
+/// Creates CreateCapitalRaise instruction
+#[allow(clippy::too_many_arguments)]
+pub fn create_capital_raise(
+    program_id: &Pubkey,
+    // Accounts
+    realm: &Pubkey,
+    realm_authority: &Pubkey,
+    capital_mint: &Pubkey,
+    lp_mint: &Pubkey,
+    lp_governance: &Pubkey,
+    capital_governance: &Pubkey,
+    capital_holding_account: &Pubkey,
+    lp_holding_account: &Pubkey,
+    payer: &Pubkey,
+    // Args
+    config: CapitalRaiseConfig,
+) -> Instruction {
+    let capital_raise_address = get_capital_raise_address(program_id, realm);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*realm, false),
+        AccountMeta::new_readonly(*realm_authority, true),
+        AccountMeta::new(capital_raise_address, false),
+        AccountMeta::new_readonly(*capital_mint, false),
+        AccountMeta::new_readonly(*lp_mint, false),
+        AccountMeta::new_readonly(*lp_governance, false),
+        AccountMeta::new_readonly(*capital_governance, false),
+        AccountMeta::new_readonly(*capital_holding_account, false),
+        AccountMeta::new_readonly(*lp_holding_account, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    let instruction = GovernanceInstruction::CreateCapitalRaise { config };
+
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    }
+}
+
+/// Creates WithdrawCapital instruction
+#[allow(clippy::too_many_arguments)]
+pub fn withdraw_capital(
+    program_id: &Pubkey,
+    // Accounts
+    realm: &Pubkey,
+    capital_governance: &Pubkey,
+    investor: &Pubkey,
+    capital_token_account: &Pubkey,
+    capital_holding_account: &Pubkey,
+    lp_token_account: &Pubkey,
+    lp_holding_account: &Pubkey,
+    // Args
+    amount: Option<u64>,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*realm, false),
+        AccountMeta::new(get_capital_raise_address(program_id, realm), false),
+        AccountMeta::new_readonly(*capital_governance, false),
+        AccountMeta::new_readonly(*investor, true),
+        AccountMeta::new(*capital_token_account, false),
+        AccountMeta::new(*capital_holding_account, false),
+        AccountMeta::new(*lp_token_account, false),
+        AccountMeta::new(*lp_holding_account, false),
+        AccountMeta::new(
+            get_capital_contribution_record_address(program_id, realm, investor),
+            false,
+        ),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+
+    // Without an amount the whole contribution is refunded
+    let instruction = match amount {
+        Some(amount) => GovernanceInstruction::WithdrawCapital { amount },
+        None => GovernanceInstruction::RefundCapital {},
+    };
+
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    }
+}
+
+/// Creates CancelCapitalRaise instruction
+pub fn cancel_capital_raise(
+    program_id: &Pubkey,
+    // Accounts
+    realm: &Pubkey,
+    realm_authority: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*realm, false),
+        AccountMeta::new_readonly(*realm_authority, true),
+        AccountMeta::new(get_capital_raise_address(program_id, realm), false),
+    ];
+
+    let instruction = GovernanceInstruction::CancelCapitalRaise {};
+
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    }
+}
+
+/// Creates FinalizeCapitalRaise instruction
+pub fn finalize_capital_raise(
+    program_id: &Pubkey,
+    // Accounts
+    realm: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*realm, false),
+        AccountMeta::new(get_capital_raise_address(program_id, realm), false),
+    ];
+
+    let instruction = GovernanceInstruction::FinalizeCapitalRaise {};
+
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    }
+}
+
+/// Creates CreateDistribution instruction
+#[allow(clippy::too_many_arguments)]
+pub fn create_distribution(
+    program_id: &Pubkey,
+    // Accounts
+    realm: &Pubkey,
+    governance: &Pubkey,
+    treasury_token_account: &Pubkey,
+    distribution_mint: &Pubkey,
+    lp_mint: &Pubkey,
+    lp_holding_account: &Pubkey,
+    payer: &Pubkey,
+    // Args
+    distribution_index: u64,
+    amount: u64,
+    holders_merkle_root: [u8; 32],
+) -> Instruction {
+    let distribution_address =
+        get_distribution_address(program_id, governance, &distribution_index.to_le_bytes());
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*realm, false),
+        AccountMeta::new_readonly(*governance, true),
+        AccountMeta::new(distribution_address, false),
+        AccountMeta::new(
+            get_distribution_holding_address(program_id, &distribution_address),
+            false,
+        ),
+        AccountMeta::new(*treasury_token_account, false),
+        AccountMeta::new_readonly(*distribution_mint, false),
+        AccountMeta::new_readonly(get_capital_raise_address(program_id, realm), false),
+        AccountMeta::new_readonly(*lp_mint, false),
+        AccountMeta::new_readonly(*lp_holding_account, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+    ];
+
+    let instruction = GovernanceInstruction::CreateDistribution {
+        distribution_index,
+        amount,
+        holders_merkle_root,
+    };
+
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    }
+}
+
+/// Creates ClaimDistribution instruction
+pub fn claim_distribution(
+    program_id: &Pubkey,
+    // Accounts
+    distribution: &Pubkey,
+    claimant: &Pubkey,
+    destination_token_account: &Pubkey,
+    payer: &Pubkey,
+    // Args
+    lp_amount: u64,
+    proof: Vec<[u8; 32]>,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*distribution, false),
+        AccountMeta::new(
+            get_distribution_holding_address(program_id, distribution),
+            false,
+        ),
+        AccountMeta::new_readonly(*claimant, true),
+        AccountMeta::new(*destination_token_account, false),
+        AccountMeta::new(
+            get_distribution_claim_record_address(program_id, distribution, claimant),
+            false,
+        ),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+
+    let instruction = GovernanceInstruction::ClaimDistribution { lp_amount, proof };
+
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    }
+}
@@ -27,6 +27,17 @@ mod process_sign_off_proposal;
 mod process_update_program_metadata;
 mod process_withdraw_governing_tokens;
 mod process_deposit_capital;
+mod process_set_realm_investor_requirements;
+mod process_set_realm_permissioned_list;
+mod process_set_realm_identity_verification_program;
+mod process_set_realm_identity_policy;
+mod process_set_realm_use_identity_verification;
+mod process_create_capital_raise;
+mod process_withdraw_capital;
+mod process_cancel_capital_raise;
+mod process_finalize_capital_raise;
+mod process_create_distribution;
+mod process_claim_distribution;
 
 use crate::instruction::GovernanceInstruction;
 
@@ -57,6 +68,17 @@ use process_sign_off_proposal::*;
 use process_update_program_metadata::*;
 use process_withdraw_governing_tokens::*;
 use process_deposit_capital::*;
+use process_set_realm_investor_requirements::*;
+use process_set_realm_permissioned_list::*;
+use process_set_realm_identity_verification_program::*;
+use process_set_realm_identity_policy::*;
+use process_set_realm_use_identity_verification::*;
+use process_create_capital_raise::*;
+use process_withdraw_capital::*;
+use process_cancel_capital_raise::*;
+use process_finalize_capital_raise::*;
+use process_create_distribution::*;
+use process_claim_distribution::*;
 
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg,
@@ -101,7 +123,7 @@ pub fn process_instruction(
         }
 
         GovernanceInstruction::DepositGoverningTokens { amount } => {
-            process_deposit_governing_tokens(program_id, accounts, amount)
+            process_deposit_governing_tokens(program_id, accounts, amount, None)
         }
 
         GovernanceInstruction::WithdrawGoverningTokens {} => {
@@ -215,8 +237,76 @@ pub fn process_instruction(
         GovernanceInstruction::CreateNativeTreasury {} => {
             process_create_native_treasury(program_id, accounts)
         }
-        GovernanceInstruction::DepositCapital { amount, decimals } => {
-            process_deposit_capital(program_id, accounts, amount, decimals)
+        GovernanceInstruction::DepositCapital { amount } => {
+            process_deposit_capital(program_id, accounts, amount)
+        }
+        GovernanceInstruction::SetRealmInvestorRequirements {
+            investor_requirements,
+        } => process_set_realm_investor_requirements(program_id, accounts, investor_requirements),
+
+        GovernanceInstruction::SetRealmPermissionedList { permissioned_list } => {
+            process_set_realm_permissioned_list(program_id, accounts, permissioned_list)
+        }
+
+        GovernanceInstruction::DepositGoverningTokensWithProof {
+            amount,
+            membership_proof,
+        } => process_deposit_governing_tokens(program_id, accounts, amount, Some(membership_proof)),
+
+        GovernanceInstruction::SetRealmIdentityVerificationProgram {
+            identity_verification_program,
+        } => process_set_realm_identity_verification_program(
+            program_id,
+            accounts,
+            identity_verification_program,
+        ),
+
+        GovernanceInstruction::SetRealmIdentityPolicy { identity_policy } => {
+            process_set_realm_identity_policy(program_id, accounts, identity_policy)
+        }
+
+        GovernanceInstruction::SetRealmUseIdentityVerification {
+            use_identity_verification,
+        } => process_set_realm_use_identity_verification(
+            program_id,
+            accounts,
+            use_identity_verification,
+        ),
+
+        GovernanceInstruction::CreateCapitalRaise { config } => {
+            process_create_capital_raise(program_id, accounts, config)
+        }
+
+        GovernanceInstruction::WithdrawCapital { amount } => {
+            process_withdraw_capital(program_id, accounts, Some(amount))
+        }
+
+        GovernanceInstruction::RefundCapital {} => {
+            process_withdraw_capital(program_id, accounts, None)
+        }
+
+        GovernanceInstruction::CancelCapitalRaise {} => {
+            process_cancel_capital_raise(program_id, accounts)
+        }
+
+        GovernanceInstruction::FinalizeCapitalRaise {} => {
+            process_finalize_capital_raise(program_id, accounts)
+        }
+
+        GovernanceInstruction::CreateDistribution {
+            distribution_index,
+            amount,
+            holders_merkle_root,
+        } => process_create_distribution(
+            program_id,
+            accounts,
+            distribution_index,
+            amount,
+            holders_merkle_root,
+        ),
+
+        GovernanceInstruction::ClaimDistribution { lp_amount, proof } => {
+            process_claim_distribution(program_id, accounts, lp_amount, proof)
         }
     }
 }
@@ -0,0 +1,39 @@
+//! Program state processor
+
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    error::GovernanceError,
+    state::{
+        capital_raise::get_capital_raise_data_for_realm, realm::get_realm_data_for_authority,
+    },
+};
+
+/// Processes CancelCapitalRaise instruction
+pub fn process_cancel_capital_raise(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let realm_info = next_account_info(account_info_iter)?; // 0
+    let realm_authority_info = next_account_info(account_info_iter)?; // 1
+    let capital_raise_info = next_account_info(account_info_iter)?; // 2
+
+    get_realm_data_for_authority(program_id, realm_info, realm_authority_info.key)?;
+
+    if !realm_authority_info.is_signer {
+        return Err(GovernanceError::RealmAuthorityMustSign.into());
+    }
+
+    let mut capital_raise_data =
+        get_capital_raise_data_for_realm(program_id, capital_raise_info, realm_info.key)?;
+
+    capital_raise_data.cancel()?;
+
+    capital_raise_data.serialize(&mut *capital_raise_info.data.borrow_mut())?;
+
+    Ok(())
+}
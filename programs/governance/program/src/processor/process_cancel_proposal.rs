@@ -8,10 +8,17 @@ use solana_program::{
     sysvar::Sysvar,
 };
 
-use crate::state::{
-    enums::ProposalState, governance::get_governance_data_for_realm,
-    proposal::get_proposal_data_for_governance, realm::get_realm_data,
-    token_owner_record::get_token_owner_record_data_for_proposal_owner,
+use crate::{
+    error::GovernanceError,
+    state::{
+        enums::ProposalState,
+        governance::get_governance_data_for_realm,
+        proposal::get_proposal_data_for_governance,
+        realm::get_realm_data,
+        token_owner_record::{
+            get_token_owner_record_data_for_proposal_owner, get_token_owner_record_data_for_realm,
+        },
+    },
 };
 
 /// Processes CancelProposal instruction
@@ -41,11 +48,37 @@ pub fn process_cancel_proposal(program_id: &Pubkey, accounts: &[AccountInfo]) ->
         &proposal_data.token_owner_record,
     )?;
 
-    proposal_owner_record_data
-        .assert_token_owner_or_delegate_is_signer(governance_authority_info)?;
-
-    proposal_owner_record_data.decrease_outstanding_proposal_count();
-    proposal_owner_record_data.serialize(&mut *proposal_owner_record_info.data.borrow_mut())?;
+    if proposal_data.state == ProposalState::Succeeded {
+        // Within cool off time the vote is already finalized and the Proposal can be canceled only by the Realm authority
+        // or a council member with enough tokens
+        // The outstanding proposal count was already decreased when the vote was finalized
+        if realm_data.authority != Some(*governance_authority_info.key)
+            || !governance_authority_info.is_signer
+        {
+            let council_token_owner_record_info = next_account_info(account_info_iter)
+                .map_err(|_| GovernanceError::RealmAuthorityMustSign)?; // 5
+
+            let council_token_owner_record_data = get_token_owner_record_data_for_realm(
+                program_id,
+                council_token_owner_record_info,
+                realm_info.key,
+            )?;
+
+            council_token_owner_record_data
+                .assert_token_owner_or_delegate_is_signer(governance_authority_info)?;
+
+            council_token_owner_record_data.assert_can_cancel_proposal_within_cool_off_time(
+                &realm_data,
+                &governance_data.config,
+            )?;
+        }
+    } else {
+        proposal_owner_record_data
+            .assert_token_owner_or_delegate_is_signer(governance_authority_info)?;
+
+        proposal_owner_record_data.decrease_outstanding_proposal_count();
+        proposal_owner_record_data.serialize(&mut *proposal_owner_record_info.data.borrow_mut())?;
+    }
 
     if proposal_data.state == ProposalState::Voting {
         // Update Realm voting_proposal_count
@@ -14,7 +14,7 @@ use spl_governance_tools::account::create_and_serialize_account_signed;
 use crate::{
     error::GovernanceError,
     state::{
-        enums::GovernanceAccountType,
+        enums::{GovernanceAccountType, ProposalState},
         governance::get_governance_data_for_realm,
         proposal::get_proposal_data_for_governance_and_governing_mint,
         realm::get_realm_data_for_governing_token_mint,
@@ -64,13 +64,25 @@ pub fn process_cast_vote(
     let mut governance_data =
         get_governance_data_for_realm(program_id, governance_info, realm_info.key)?;
 
+    // Veto votes are cast with the council mint on community Proposals
+    let proposal_governing_token_mint =
+        realm_data.get_proposal_governing_token_mint_for_vote(governing_token_mint_info.key, &vote)?;
+
     let mut proposal_data = get_proposal_data_for_governance_and_governing_mint(
         program_id,
         proposal_info,
         governance_info.key,
-        governing_token_mint_info.key,
+        &proposal_governing_token_mint,
     )?;
-    proposal_data.assert_can_cast_vote(&governance_data.config, clock.unix_timestamp)?;
+
+    if vote == Vote::Veto {
+        proposal_data.assert_can_cast_veto_vote(&governance_data.config, clock.unix_timestamp)?;
+    } else {
+        proposal_data.assert_can_cast_vote(&governance_data.config, clock.unix_timestamp)?;
+    }
+
+    // Only Veto vote can be cast on a Proposal within cool off time and it doesn't change the Proposal voting counts
+    let is_voting_state = proposal_data.state == ProposalState::Voting;
 
     let mut voter_token_owner_record_data =
         get_token_owner_record_data_for_realm_and_governing_mint(
@@ -129,25 +141,79 @@ pub fn process_cast_vote(
                     .unwrap(),
             )
         }
-        Vote::Abstain | Vote::Veto => {
-            return Err(GovernanceError::NotSupportedVoteType.into());
+        Vote::Veto => {
+            proposal_data.veto_vote_weight = Some(
+                proposal_data
+                    .veto_vote_weight
+                    .unwrap()
+                    .checked_add(voter_weight)
+                    .unwrap(),
+            )
+        }
+        Vote::Abstain => {
+            proposal_data.abstain_vote_weight = Some(
+                proposal_data
+                    .abstain_vote_weight
+                    .unwrap()
+                    .checked_add(voter_weight)
+                    .unwrap(),
+            )
         }
     }
 
-    let max_voter_weight = proposal_data.resolve_max_voter_weight(
+    let max_voter_weight = if vote == Vote::Veto {
+        // The max voter weight record isn't used for Veto votes but it's still passed when the addin is configured
+        // to keep the same accounts layout for all vote types
+        if realm_data.config.use_max_community_voter_weight_addin
+            && realm_data.community_mint == proposal_data.governing_token_mint
+        {
+            next_account_info(account_info_iter)?; // max_voter_weight_record  11
+        }
+
+        proposal_data.resolve_max_veto_voter_weight(governing_token_mint_info)?
+    } else {
+        proposal_data.resolve_max_voter_weight(
+            program_id,
+            realm_config_info,
+            governing_token_mint_info,
+            account_info_iter, // max_voter_weight_record  11
+            realm_info.key,
+            &realm_data,
+        )?
+    };
+
+    realm_data.assert_is_permissioned_list_member(
         program_id,
+        realm_info.key,
         realm_config_info,
-        governing_token_mint_info,
-        account_info_iter, // max_voter_weight_record  11
+        account_info_iter, // permissioned_list, list_entry
+        &voter_token_owner_record_data.governing_token_owner,
+        None,
+    )?;
+
+    realm_data.assert_governing_token_owner_identity(
+        program_id,
         realm_info.key,
-        &realm_data,
+        realm_config_info,
+        account_info_iter, // identity_record, identity_verification_program, frozen_subject
+        &voter_token_owner_record_data.governing_token_owner,
     )?;
 
-    if proposal_data.try_tip_vote(
-        max_voter_weight,
-        &governance_data.config,
-        clock.unix_timestamp,
-    )? {
+    let is_vote_tipped = if vote == Vote::Veto {
+        proposal_data.try_tip_veto_vote(
+            max_voter_weight,
+            &governance_data.config,
+            clock.unix_timestamp,
+        )?
+    } else {
+        proposal_data.try_tip_vote(
+            max_voter_weight,
+            &governance_data.config,
+            clock.unix_timestamp,
+        )?
+    };
+
+    if is_vote_tipped && is_voting_state {
         // Deserialize proposal owner and validate it's the actual owner of the proposal
         let mut proposal_owner_record_data = get_token_owner_record_data_for_proposal_owner(
             program_id,
@@ -0,0 +1,105 @@
+//! Program state processor
+
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+use spl_governance_tools::account::create_and_serialize_account_signed;
+
+use crate::{
+    error::GovernanceError,
+    state::{
+        distribution::{
+            get_distribution_address_seeds, get_distribution_data, get_distribution_holding_address,
+        },
+        distribution_claim_record::{
+            get_distribution_claim_record_address_seeds, DistributionClaimRecord,
+        },
+        enums::GovernanceAccountType,
+    },
+    tools::spl_token::transfer_spl_tokens_signed,
+};
+
+/// Processes ClaimDistribution instruction
+pub fn process_claim_distribution(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    lp_amount: u64,
+    proof: Vec<[u8; 32]>,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let distribution_info = next_account_info(account_info_iter)?; // 0
+    let distribution_holding_info = next_account_info(account_info_iter)?; // 1
+    let claimant_info = next_account_info(account_info_iter)?; // 2
+    let destination_token_account_info = next_account_info(account_info_iter)?; // 3
+    let distribution_claim_record_info = next_account_info(account_info_iter)?; // 4
+    let payer_info = next_account_info(account_info_iter)?; // 5
+    let system_info = next_account_info(account_info_iter)?; // 6
+    let spl_token_info = next_account_info(account_info_iter)?; // 7
+
+    if !claimant_info.is_signer {
+        return Err(GovernanceError::TokenOwnerMustSign.into());
+    }
+
+    let mut distribution_data = get_distribution_data(program_id, distribution_info)?;
+
+    if get_distribution_holding_address(program_id, distribution_info.key)
+        != *distribution_holding_info.key
+    {
+        return Err(GovernanceError::InvalidDistributionAccounts.into());
+    }
+
+    if !distribution_claim_record_info.data_is_empty() {
+        return Err(GovernanceError::DistributionAlreadyClaimed.into());
+    }
+
+    // The entitlement comes from the holders snapshot and not the current LP balance
+    // so LP tokens moved to another wallet after the snapshot can't be claimed for again
+    distribution_data.assert_is_snapshot_holder(claimant_info.key, lp_amount, &proof)?;
+
+    let amount = distribution_data.claim(lp_amount)?;
+
+    let clock = Clock::get()?;
+
+    let distribution_claim_record_data = DistributionClaimRecord {
+        account_type: GovernanceAccountType::DistributionClaimRecord,
+        distribution: *distribution_info.key,
+        claimant: *claimant_info.key,
+        lp_amount,
+        amount,
+        claimed_at: clock.unix_timestamp,
+    };
+
+    create_and_serialize_account_signed::<DistributionClaimRecord>(
+        payer_info,
+        distribution_claim_record_info,
+        &distribution_claim_record_data,
+        &get_distribution_claim_record_address_seeds(distribution_info.key, claimant_info.key),
+        program_id,
+        system_info,
+        &Rent::get()?,
+    )?;
+
+    transfer_spl_tokens_signed(
+        distribution_holding_info,
+        destination_token_account_info,
+        distribution_info,
+        &get_distribution_address_seeds(
+            &distribution_data.governance,
+            &distribution_data.distribution_index.to_le_bytes(),
+        ),
+        program_id,
+        amount,
+        spl_token_info,
+    )?;
+
+    distribution_data.serialize(&mut *distribution_info.data.borrow_mut())?;
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+//! Program state processor
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+use spl_governance_tools::account::create_and_serialize_account_signed;
+
+use crate::{
+    error::GovernanceError,
+    state::{
+        capital_raise::{get_capital_raise_address_seeds, CapitalRaise, CapitalRaiseConfig},
+        enums::{CapitalRaiseState, GovernanceAccountType},
+        governance::assert_governance_for_realm,
+        realm::get_realm_data_for_authority,
+    },
+    tools::spl_token::{
+        assert_is_valid_spl_token_mint, get_spl_token_mint, get_spl_token_owner,
+    },
+};
+
+/// Processes CreateCapitalRaise instruction
+pub fn process_create_capital_raise(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    config: CapitalRaiseConfig,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let realm_info = next_account_info(account_info_iter)?; // 0
+    let realm_authority_info = next_account_info(account_info_iter)?; // 1
+    let capital_raise_info = next_account_info(account_info_iter)?; // 2
+    let capital_mint_info = next_account_info(account_info_iter)?; // 3
+    let lp_mint_info = next_account_info(account_info_iter)?; // 4
+    let lp_governance_info = next_account_info(account_info_iter)?; // 5
+    let capital_governance_info = next_account_info(account_info_iter)?; // 6
+    let capital_holding_account_info = next_account_info(account_info_iter)?; // 7
+    let lp_holding_account_info = next_account_info(account_info_iter)?; // 8
+    let payer_info = next_account_info(account_info_iter)?; // 9
+    let system_info = next_account_info(account_info_iter)?; // 10
+
+    get_realm_data_for_authority(program_id, realm_info, realm_authority_info.key)?;
+
+    if !realm_authority_info.is_signer {
+        return Err(GovernanceError::RealmAuthorityMustSign.into());
+    }
+
+    config.assert_is_valid()?;
+
+    assert_governance_for_realm(program_id, lp_governance_info, realm_info.key)?;
+    assert_governance_for_realm(program_id, capital_governance_info, realm_info.key)?;
+
+    assert_is_valid_spl_token_mint(capital_mint_info)?;
+    assert_is_valid_spl_token_mint(lp_mint_info)?;
+
+    // The LP tokens are issued by the LP governance and the capital must land in an account of the capital governance
+    if get_spl_token_mint(capital_holding_account_info)? != *capital_mint_info.key
+        || get_spl_token_owner(capital_holding_account_info)? != *capital_governance_info.key
+        || get_spl_token_mint(lp_holding_account_info)? != *lp_mint_info.key
+        || get_spl_token_owner(lp_holding_account_info)? != *lp_governance_info.key
+    {
+        return Err(GovernanceError::InvalidCapitalRaiseAccounts.into());
+    }
+
+    let capital_raise_data = CapitalRaise {
+        account_type: GovernanceAccountType::CapitalRaise,
+        realm: *realm_info.key,
+        capital_mint: *capital_mint_info.key,
+        lp_mint: *lp_mint_info.key,
+        lp_governance: *lp_governance_info.key,
+        capital_governance: *capital_governance_info.key,
+        capital_holding_account: *capital_holding_account_info.key,
+        lp_holding_account: *lp_holding_account_info.key,
+        state: CapitalRaiseState::Open,
+        config,
+        total_raised: 0,
+        total_lp_issued: 0,
+    };
+
+    create_and_serialize_account_signed::<CapitalRaise>(
+        payer_info,
+        capital_raise_info,
+        &capital_raise_data,
+        &get_capital_raise_address_seeds(realm_info.key),
+        program_id,
+        system_info,
+        &Rent::get()?,
+    )?;
+
+    Ok(())
+}
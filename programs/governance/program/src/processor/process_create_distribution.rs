@@ -0,0 +1,132 @@
+//! Program state processor
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+use spl_governance_tools::account::create_and_serialize_account_signed;
+
+use crate::{
+    error::GovernanceError,
+    state::{
+        capital_raise::get_capital_raise_data_for_realm,
+        distribution::{
+            get_distribution_address_seeds, get_distribution_holding_address_seeds, Distribution,
+        },
+        enums::GovernanceAccountType,
+        governance::assert_governance_for_realm,
+    },
+    tools::spl_token::{
+        create_spl_token_account_signed, get_spl_token_amount, get_spl_token_mint_supply,
+        transfer_spl_tokens,
+    },
+};
+
+/// Processes CreateDistribution instruction
+pub fn process_create_distribution(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    distribution_index: u64,
+    amount: u64,
+    holders_merkle_root: [u8; 32],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let realm_info = next_account_info(account_info_iter)?; // 0
+    let governance_info = next_account_info(account_info_iter)?; // 1
+    let distribution_info = next_account_info(account_info_iter)?; // 2
+    let distribution_holding_info = next_account_info(account_info_iter)?; // 3
+    let treasury_token_account_info = next_account_info(account_info_iter)?; // 4
+    let distribution_mint_info = next_account_info(account_info_iter)?; // 5
+    let capital_raise_info = next_account_info(account_info_iter)?; // 6
+    let lp_mint_info = next_account_info(account_info_iter)?; // 7
+    let lp_holding_account_info = next_account_info(account_info_iter)?; // 8
+    let payer_info = next_account_info(account_info_iter)?; // 9
+    let system_info = next_account_info(account_info_iter)?; // 10
+    let spl_token_info = next_account_info(account_info_iter)?; // 11
+    let rent_sysvar_info = next_account_info(account_info_iter)?; // 12
+
+    let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+    assert_governance_for_realm(program_id, governance_info, realm_info.key)?;
+
+    // Distributions are funded from the Governance treasury and can only be created by a Proposal
+    if !governance_info.is_signer {
+        return Err(GovernanceError::GovernancePdaMustSign.into());
+    }
+
+    if amount == 0 {
+        return Err(GovernanceError::InvalidDistributionAmount.into());
+    }
+
+    let capital_raise_data =
+        get_capital_raise_data_for_realm(program_id, capital_raise_info, realm_info.key)?;
+
+    if capital_raise_data.lp_mint != *lp_mint_info.key
+        || capital_raise_data.lp_holding_account != *lp_holding_account_info.key
+    {
+        return Err(GovernanceError::InvalidDistributionAccounts.into());
+    }
+
+    // LP tokens which were never issued to investors don't take part in the distribution
+    let lp_supply_snapshot = get_spl_token_mint_supply(lp_mint_info)?
+        .checked_sub(get_spl_token_amount(lp_holding_account_info)?)
+        .ok_or(GovernanceError::DistributionMathOverflow)?;
+
+    if lp_supply_snapshot == 0 {
+        return Err(GovernanceError::DistributionLpSupplyEmpty.into());
+    }
+
+    let clock = Clock::get()?;
+
+    let distribution_data = Distribution {
+        account_type: GovernanceAccountType::Distribution,
+        realm: *realm_info.key,
+        governance: *governance_info.key,
+        distribution_index,
+        lp_mint: *lp_mint_info.key,
+        distribution_mint: *distribution_mint_info.key,
+        lp_supply_snapshot,
+        holders_merkle_root,
+        total_amount: amount,
+        claimed_amount: 0,
+        funded_at: clock.unix_timestamp,
+    };
+
+    create_and_serialize_account_signed::<Distribution>(
+        payer_info,
+        distribution_info,
+        &distribution_data,
+        &get_distribution_address_seeds(governance_info.key, &distribution_index.to_le_bytes()),
+        program_id,
+        system_info,
+        rent,
+    )?;
+
+    create_spl_token_account_signed(
+        payer_info,
+        distribution_holding_info,
+        &get_distribution_holding_address_seeds(distribution_info.key),
+        distribution_mint_info,
+        distribution_info,
+        program_id,
+        system_info,
+        spl_token_info,
+        rent_sysvar_info,
+        rent,
+    )?;
+
+    transfer_spl_tokens(
+        treasury_token_account_info,
+        distribution_holding_info,
+        governance_info,
+        amount,
+        spl_token_info,
+    )?;
+
+    Ok(())
+}
@@ -4,7 +4,7 @@ use crate::state::{
     enums::GovernanceAccountType,
     governance::{
         assert_valid_create_governance_args, get_governance_address_seeds, GovernanceConfig,
-        GovernanceV2,
+        GovernanceV2, Reserved126,
     },
     realm::get_realm_data,
 };
@@ -57,9 +57,9 @@ pub fn process_create_governance(
         governed_account: *governed_account_info.key,
         config,
         proposals_count: 0,
-        reserved: [0; 6],
+        reserved: [0; 5],
         voting_proposal_count: 0,
-        reserved_v2: [0; 128],
+        reserved_v2: Reserved126::default(),
     };
 
     create_and_serialize_account_signed::<GovernanceV2>(
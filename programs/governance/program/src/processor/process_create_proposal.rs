@@ -86,6 +86,23 @@ pub fn process_create_proposal(
         governance_info.key,
     )?;
 
+    realm_data.assert_is_permissioned_list_member(
+        program_id,
+        realm_info.key,
+        realm_config_info,
+        account_info_iter, // permissioned_list, list_entry
+        &proposal_owner_record_data.governing_token_owner,
+        None,
+    )?;
+
+    realm_data.assert_governing_token_owner_identity(
+        program_id,
+        realm_info.key,
+        realm_config_info,
+        account_info_iter, // identity_record, identity_verification_program, frozen_subject
+        &proposal_owner_record_data.governing_token_owner,
+    )?;
+
     // Ensure proposal owner (TokenOwner) has enough tokens to create proposal and no outstanding proposals
     proposal_owner_record_data.assert_can_create_proposal(
         &realm_data,
@@ -143,8 +160,8 @@ pub fn process_create_proposal(
         options: proposal_options,
         deny_vote_weight,
 
-        veto_vote_weight: None,
-        abstain_vote_weight: None,
+        veto_vote_weight: Some(0),
+        abstain_vote_weight: Some(0),
 
         max_vote_weight: None,
         max_voting_time: None,
@@ -11,7 +11,9 @@ use crate::{
             assert_valid_realm_config_args, get_governing_token_holding_address_seeds,
             get_realm_address_seeds, RealmConfig, RealmConfigArgs, RealmV2,
         },
-        realm_config::{get_realm_config_address_seeds, RealmConfigAccount},
+        realm_config::{
+            get_realm_config_address_seeds, IdentityPolicy, InvestorRequirements, RealmConfigAccount,
+        },
     },
     tools::spl_token::create_spl_token_account_signed,
 };
@@ -110,7 +112,11 @@ pub fn process_create_realm(
             max_community_voter_weight_addin,
             council_voter_weight_addin: None,
             council_max_vote_weight_addin: None,
-            reserved: [0; 128],
+            investor_requirements: InvestorRequirements::default(),
+            permissioned_list: None,
+            identity_verification_program: None,
+            identity_policy: IdentityPolicy::default(),
+            reserved: [0; 6],
         };
 
         create_and_serialize_account_signed::<RealmConfigAccount>(
@@ -133,13 +139,15 @@ pub fn process_create_realm(
         authority: Some(*realm_authority_info.key),
         config: RealmConfig {
             council_mint: council_token_mint_address,
-            reserved: [0; 6],
+            reserved: [0; 4],
             community_mint_max_vote_weight_source: config_args
                 .community_mint_max_vote_weight_source,
             min_community_weight_to_create_governance: config_args
                 .min_community_weight_to_create_governance,
             use_community_voter_weight_addin: config_args.use_community_voter_weight_addin,
             use_max_community_voter_weight_addin: config_args.use_max_community_voter_weight_addin,
+            use_permissioned_list: false,
+            use_identity_verification: false,
         },
         voting_proposal_count: 0,
         reserved_v2: [0; 128],
@@ -5,7 +5,7 @@ use crate::{
         enums::GovernanceAccountType,
         governance::{
             assert_valid_create_governance_args, get_token_governance_address_seeds,
-            GovernanceConfig, GovernanceV2,
+            GovernanceConfig, GovernanceV2, Reserved126,
         },
         realm::get_realm_data,
     },
@@ -67,9 +67,9 @@ pub fn process_create_token_governance(
         governed_account: *governed_token_info.key,
         config,
         proposals_count: 0,
-        reserved: [0; 6],
+        reserved: [0; 5],
         voting_proposal_count: 0,
-        reserved_v2: [0; 128],
+        reserved_v2: Reserved126::default(),
     };
 
     create_and_serialize_account_signed::<GovernanceV2>(
@@ -1,9 +1,21 @@
 //! Program state processor
 
+use borsh::BorshSerialize;
 use solana_program::{account_info::{
     AccountInfo,
     next_account_info,
-}, entrypoint::ProgramResult, program::{invoke, invoke_signed}, pubkey::Pubkey};
+}, clock::Clock, entrypoint::ProgramResult, program::invoke, pubkey::Pubkey, rent::Rent, sysvar::Sysvar};
+use spl_governance_tools::account::create_and_serialize_account_signed;
+use crate::error::GovernanceError;
+use crate::state::capital_contribution_record::{
+    get_capital_contribution_record_address_seeds,
+    get_capital_contribution_record_data_for_realm_and_investor, CapitalContributionRecord,
+};
+use crate::state::capital_raise::get_capital_raise_data_for_realm;
+use crate::state::enums::GovernanceAccountType;
+use crate::state::governance::get_governance_data_for_realm;
+use crate::state::realm_config::{get_realm_config_address, get_realm_config_data_for_realm};
+use crate::tools::spl_token::{transfer_spl_tokens, transfer_spl_tokens_signed};
 use crate::tools::verification::{assert_identity_verification};
 
 /// Processes DepositCapital instruction
@@ -11,7 +23,6 @@ pub fn process_deposit_capital(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     amount: u64,
-    decimals: u8,
 ) -> ProgramResult {
     let account_info_iter = &mut accounts.iter();
 
@@ -23,22 +34,91 @@ pub fn process_deposit_capital(
     let lp_token_account = next_account_info(account_info_iter)?; // 5
     let lp_token_holding_account = next_account_info(account_info_iter)?; // 6
     let lp_token_mint = next_account_info(account_info_iter)?; // 7
-    let delegate_token_mint = next_account_info(account_info_iter)?; // 8
+    let capital_raise = next_account_info(account_info_iter)?; // 8
     let identity_verification_record = next_account_info(account_info_iter)?; // 9
     let identity_verification_program = next_account_info(account_info_iter)?; // 10
     let token_program = next_account_info(account_info_iter)?; // 11
     let system_program = next_account_info(account_info_iter)?; // 12
     let rent_program = next_account_info(account_info_iter)?; // 13
+    let _associated_token_program = next_account_info(account_info_iter)?; // 14
+    let realm_config = next_account_info(account_info_iter)?; // 15
+    let frozen_subject = next_account_info(account_info_iter)?; // 16
+    let capital_contribution_record = next_account_info(account_info_iter)?; // 17
 
-    // assert user's identity has been verified
+    // assert user's identity has been verified by the identity verification program the realm trusts
 
-    assert_identity_verification(
+    if *realm_config.key != get_realm_config_address(program_id, realm.key) {
+        return Err(GovernanceError::InvalidRealmConfigForRealm.into());
+    }
+
+    if realm_config.data_is_empty() {
+        return Err(GovernanceError::IdentityVerificationProgramNotConfigured.into());
+    }
+
+    let realm_config_data = get_realm_config_data_for_realm(program_id, realm_config, realm.key)?;
+
+    let trusted_identity_verification_program = realm_config_data
+        .identity_verification_program
+        .ok_or(GovernanceError::IdentityVerificationProgramNotConfigured)?;
+
+    let identity = assert_identity_verification(
         identity_verification_record,
-        capital_token_authority,
-        realm,
-        identity_verification_program
+        capital_token_authority.key,
+        realm.key,
+        identity_verification_program,
+        frozen_subject,
+        &trusted_identity_verification_program,
+        &realm_config_data.identity_policy,
+        amount,
+    )?;
+
+    // assert user meets the investor requirements of the realm
+
+    realm_config_data.investor_requirements.assert_investor_is_eligible(&identity)?;
+
+    // assert the deposit fits the capital raise and price it
+
+    let mut capital_raise_data = get_capital_raise_data_for_realm(program_id, capital_raise, realm.key)?;
+
+    capital_raise_data.assert_is_valid_deposit_accounts(
+        lp_governance.key,
+        capital_token_holding_account.key,
+        lp_token_holding_account.key,
+        lp_token_mint.key,
+    )?;
+
+    let mut capital_contribution_record_data = if capital_contribution_record.data_is_empty() {
+        CapitalContributionRecord {
+            account_type: GovernanceAccountType::CapitalContributionRecord,
+            realm: *realm.key,
+            investor: *capital_token_authority.key,
+            total_contributed: 0,
+            lp_received: 0,
+            first_deposit_at: 0,
+            last_deposit_at: 0,
+            deposit_count: 0,
+        }
+    } else {
+        get_capital_contribution_record_data_for_realm_and_investor(
+            program_id,
+            capital_contribution_record,
+            realm.key,
+            capital_token_authority.key,
+        )?
+    };
+
+    let clock = Clock::get()?;
+
+    let lp_amount = capital_raise_data.deposit(
+        amount,
+        capital_contribution_record_data.total_contributed,
+        clock.unix_timestamp,
     )?;
 
+    capital_contribution_record_data.deposit(amount, lp_amount, clock.unix_timestamp)?;
+
+    let lp_governance_data = get_governance_data_for_realm(program_id, lp_governance, realm.key)?;
+
     // create account if it doesn't exist
 
     if lp_token_account.data_is_empty() {
@@ -67,59 +147,44 @@ pub fn process_deposit_capital(
 
     // transfer capital
 
-    let capital_token_transfer_instruction = spl_token::instruction::transfer(
-        token_program.key,
-        capital_token_account.key,
-        capital_token_holding_account.key,
-        capital_token_authority.key,
-        &[capital_token_authority.key],
-        spl_token::ui_amount_to_amount(amount as f64, decimals),
-    )?;
-
-    invoke(
-        &capital_token_transfer_instruction,
-        &[
-            capital_token_account.clone(),
-            capital_token_authority.clone(),
-            token_program.clone(),
-            capital_token_holding_account.clone()
-        ],
+    transfer_spl_tokens(
+        capital_token_account,
+        capital_token_holding_account,
+        capital_token_authority,
+        amount,
+        token_program,
     )?;
 
     // transfer lp
 
-    let lp_token_transfer_instruction = spl_token::instruction::transfer(
-        token_program.key,
-        lp_token_holding_account.key,
-        lp_token_account.key,
-        lp_governance.key,
-        &[lp_governance.key],
-        amount
+    transfer_spl_tokens_signed(
+        lp_token_holding_account,
+        lp_token_account,
+        lp_governance,
+        &lp_governance_data.get_governance_address_seeds()?,
+        program_id,
+        lp_amount,
+        token_program,
     )?;
 
-    let (_address, bump) = Pubkey::find_program_address(
-        &[
-            b"mint-governance",
-            realm.key.as_ref(),
-            delegate_token_mint.key.as_ref(),
-        ],
-        program_id,
-    );
+    capital_raise_data.serialize(&mut *capital_raise.data.borrow_mut())?;
 
-    invoke_signed(
-        &lp_token_transfer_instruction,
-        &[
-            lp_token_holding_account.clone(),
-            lp_token_account.clone(),
-            lp_governance.clone(),
-        ],
-        &[&[
-            b"mint-governance",
-            realm.key.as_ref(),
-            delegate_token_mint.key.as_ref(),
-            &[bump]
-        ]],
-    )?;
+    // record the investor's contribution
+
+    if capital_contribution_record.data_is_empty() {
+        create_and_serialize_account_signed::<CapitalContributionRecord>(
+            capital_token_authority,
+            capital_contribution_record,
+            &capital_contribution_record_data,
+            &get_capital_contribution_record_address_seeds(realm.key, capital_token_authority.key),
+            program_id,
+            system_program,
+            &Rent::get()?,
+        )?;
+    } else {
+        capital_contribution_record_data
+            .serialize(&mut *capital_contribution_record.data.borrow_mut())?;
+    }
 
     Ok(())
 }
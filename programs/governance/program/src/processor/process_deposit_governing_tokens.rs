@@ -27,6 +27,7 @@ pub fn process_deposit_governing_tokens(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     amount: u64,
+    membership_proof: Option<Vec<[u8; 32]>>,
 ) -> ProgramResult {
     let account_info_iter = &mut accounts.iter();
 
@@ -47,6 +48,27 @@ pub fn process_deposit_governing_tokens(
 
     realm_data.asset_governing_tokens_deposits_allowed(&governing_token_mint)?;
 
+    if realm_data.config.use_permissioned_list || realm_data.config.use_identity_verification {
+        let realm_config_info = next_account_info(account_info_iter)?; // 9
+
+        realm_data.assert_is_permissioned_list_member(
+            program_id,
+            realm_info.key,
+            realm_config_info,
+            account_info_iter, // permissioned_list, list_entry (without membership_proof)
+            governing_token_owner_info.key,
+            membership_proof.as_deref(),
+        )?;
+
+        realm_data.assert_governing_token_owner_identity(
+            program_id,
+            realm_info.key,
+            realm_config_info,
+            account_info_iter, // identity_record, identity_verification_program, frozen_subject
+            governing_token_owner_info.key,
+        )?;
+    }
+
     realm_data.assert_is_valid_governing_token_mint_and_holding(
         program_id,
         realm_info.key,
@@ -0,0 +1,34 @@
+//! Program state processor
+
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::state::capital_raise::get_capital_raise_data_for_realm;
+
+/// Processes FinalizeCapitalRaise instruction
+pub fn process_finalize_capital_raise(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let realm_info = next_account_info(account_info_iter)?; // 0
+    let capital_raise_info = next_account_info(account_info_iter)?; // 1
+
+    let mut capital_raise_data =
+        get_capital_raise_data_for_realm(program_id, capital_raise_info, realm_info.key)?;
+
+    let clock = Clock::get()?;
+
+    capital_raise_data.finalize(clock.unix_timestamp)?;
+
+    capital_raise_data.serialize(&mut *capital_raise_info.data.borrow_mut())?;
+
+    Ok(())
+}
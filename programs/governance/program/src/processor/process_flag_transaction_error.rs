@@ -10,7 +10,8 @@ use solana_program::{
 
 use crate::state::{
     enums::{ProposalState, TransactionExecutionStatus},
-    proposal::get_proposal_data,
+    governance::get_governance_data,
+    proposal::get_proposal_data_for_governance,
     proposal_transaction::get_proposal_transaction_data_for_proposal,
     token_owner_record::get_token_owner_record_data_for_proposal_owner,
 };
@@ -27,10 +28,14 @@ pub fn process_flag_transaction_error(
     let governance_authority_info = next_account_info(account_info_iter)?; // 2
 
     let proposal_transaction_info = next_account_info(account_info_iter)?; // 3
+    let governance_info = next_account_info(account_info_iter)?; // 4
 
     let clock = Clock::get()?;
 
-    let mut proposal_data = get_proposal_data(program_id, proposal_info)?;
+    let governance_data = get_governance_data(program_id, governance_info)?;
+
+    let mut proposal_data =
+        get_proposal_data_for_governance(program_id, proposal_info, governance_info.key)?;
 
     let mut proposal_transaction_data = get_proposal_transaction_data_for_proposal(
         program_id,
@@ -38,8 +43,11 @@ pub fn process_flag_transaction_error(
         proposal_info.key,
     )?;
 
-    proposal_data
-        .assert_can_flag_transaction_error(&proposal_transaction_data, clock.unix_timestamp)?;
+    proposal_data.assert_can_flag_transaction_error(
+        &governance_data.config,
+        &proposal_transaction_data,
+        clock.unix_timestamp,
+    )?;
 
     let token_owner_record_data = get_token_owner_record_data_for_proposal_owner(
         program_id,
@@ -14,7 +14,7 @@ use crate::{
     state::{
         enums::ProposalState,
         governance::get_governance_data,
-        proposal::get_proposal_data_for_governance_and_governing_mint,
+        proposal::get_proposal_data_for_governance,
         token_owner_record::get_token_owner_record_data_for_realm_and_governing_mint,
         vote_record::{get_vote_record_data_for_proposal_and_token_owner, Vote},
     },
@@ -33,12 +33,8 @@ pub fn process_relinquish_vote(program_id: &Pubkey, accounts: &[AccountInfo]) ->
 
     let governance_data = get_governance_data(program_id, governance_info)?;
 
-    let mut proposal_data = get_proposal_data_for_governance_and_governing_mint(
-        program_id,
-        proposal_info,
-        governance_info.key,
-        governing_token_mint_info.key,
-    )?;
+    let mut proposal_data =
+        get_proposal_data_for_governance(program_id, proposal_info, governance_info.key)?;
 
     let mut token_owner_record_data = get_token_owner_record_data_for_realm_and_governing_mint(
         program_id,
@@ -55,6 +51,14 @@ pub fn process_relinquish_vote(program_id: &Pubkey, accounts: &[AccountInfo]) ->
     )?;
     vote_record_data.assert_can_relinquish_vote()?;
 
+    // Veto votes are cast with the council mint on community Proposals and other votes with the Proposal governing token mint
+    let is_proposal_governing_token_mint =
+        proposal_data.governing_token_mint == *governing_token_mint_info.key;
+
+    if is_proposal_governing_token_mint == (vote_record_data.vote == Vote::Veto) {
+        return Err(GovernanceError::InvalidGoverningMintForProposal.into());
+    }
+
     let clock = Clock::get()?;
 
     // If the Proposal is still being voted on then the token owner vote will be withdrawn and it won't count towards the vote outcome
@@ -89,8 +93,23 @@ pub fn process_relinquish_vote(program_id: &Pubkey, accounts: &[AccountInfo]) ->
                         .unwrap(),
                 )
             }
-            Vote::Abstain | Vote::Veto => {
-                return Err(GovernanceError::NotSupportedVoteType.into());
+            Vote::Veto => {
+                proposal_data.veto_vote_weight = Some(
+                    proposal_data
+                        .veto_vote_weight
+                        .unwrap()
+                        .checked_sub(vote_record_data.voter_weight)
+                        .unwrap(),
+                )
+            }
+            Vote::Abstain => {
+                proposal_data.abstain_vote_weight = Some(
+                    proposal_data
+                        .abstain_vote_weight
+                        .unwrap()
+                        .checked_sub(vote_record_data.voter_weight)
+                        .unwrap(),
+                )
             }
         }
 
@@ -5,19 +5,13 @@ use solana_program::{
     account_info::{next_account_info, AccountInfo},
     entrypoint::ProgramResult,
     pubkey::Pubkey,
-    rent::Rent,
-    sysvar::Sysvar,
 };
-use spl_governance_tools::account::create_and_serialize_account_signed;
 
 use crate::{
     error::GovernanceError,
     state::{
-        enums::GovernanceAccountType,
         realm::{assert_valid_realm_config_args, get_realm_data_for_authority, RealmConfigArgs},
-        realm_config::{
-            get_realm_config_address_seeds, get_realm_config_data_for_realm, RealmConfigAccount,
-        },
+        realm_config::{get_or_create_realm_config_data, get_realm_config_data_for_realm},
     },
 };
 
@@ -95,50 +89,34 @@ pub fn process_set_realm_config(
     };
 
     // If any of the addins is needed then update or create (if doesn't exist yet)  RealmConfigAccount
-    let update_realm_config = if realm_config_args.use_community_voter_weight_addin
+    let realm_config_data = if realm_config_args.use_community_voter_weight_addin
         || realm_config_args.use_max_community_voter_weight_addin
     {
         // We need the payer to pay for the new account if it's created
         let payer_info = next_account_info(account_info_iter)?; // 8
 
-        // If RealmConfigAccount doesn't exist yet then create it
-        if realm_config_info.data_is_empty() {
-            let realm_config_data = RealmConfigAccount {
-                account_type: GovernanceAccountType::RealmConfig,
-                realm: *realm_info.key,
-                community_voter_weight_addin,
-                max_community_voter_weight_addin,
-                council_voter_weight_addin: None,
-                council_max_vote_weight_addin: None,
-                reserved: [0; 128],
-            };
-
-            let rent = Rent::get()?;
-
-            create_and_serialize_account_signed::<RealmConfigAccount>(
-                payer_info,
-                realm_config_info,
-                &realm_config_data,
-                &get_realm_config_address_seeds(realm_info.key),
-                program_id,
-                system_info,
-                &rent,
-            )?;
-            false // RealmConfigAccount didn't exist and was created
-        } else {
-            true // RealmConfigAccount existed before and needs to be updated
-        }
+        Some(get_or_create_realm_config_data(
+            program_id,
+            realm_config_info,
+            realm_info.key,
+            payer_info,
+            system_info,
+        )?)
+    } else if realm_data.config.use_community_voter_weight_addin
+        || realm_data.config.use_max_community_voter_weight_addin
+    {
+        // If RealmConfigAccount existed before we have to update it to remove the addins which are not used any longer
+        Some(get_realm_config_data_for_realm(
+            program_id,
+            realm_config_info,
+            realm_info.key,
+        )?)
     } else {
-        // True: If RealmConfigAccount existed before we have to update it to remove the addins which are not used any longer
-        // False: We don't want to setup the addins and RealmConfigAccount didn't exist before
-        realm_data.config.use_community_voter_weight_addin
-            || realm_data.config.use_max_community_voter_weight_addin
+        // We don't want to setup the addins and RealmConfigAccount didn't exist before
+        None
     };
 
-    if update_realm_config {
-        let mut realm_config_data =
-            get_realm_config_data_for_realm(program_id, realm_config_info, realm_info.key)?;
-
+    if let Some(mut realm_config_data) = realm_config_data {
         realm_config_data.community_voter_weight_addin = community_voter_weight_addin;
         realm_config_data.max_community_voter_weight_addin = max_community_voter_weight_addin;
 
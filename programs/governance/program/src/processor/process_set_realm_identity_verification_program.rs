@@ -0,0 +1,54 @@
+//! Program state processor
+
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    error::GovernanceError,
+    state::{
+        realm::get_realm_data_for_authority,
+        realm_config::get_or_create_realm_config_data,
+    },
+};
+
+/// Processes SetRealmIdentityVerificationProgram instruction
+pub fn process_set_realm_identity_verification_program(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    identity_verification_program: Option<Pubkey>,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let realm_info = next_account_info(account_info_iter)?; // 0
+    let realm_authority_info = next_account_info(account_info_iter)?; // 1
+    let realm_config_info = next_account_info(account_info_iter)?; // 2
+    let payer_info = next_account_info(account_info_iter)?; // 3
+    let system_info = next_account_info(account_info_iter)?; // 4
+
+    let realm_data =
+        get_realm_data_for_authority(program_id, realm_info, realm_authority_info.key)?;
+
+    if !realm_authority_info.is_signer {
+        return Err(GovernanceError::RealmAuthorityMustSign.into());
+    }
+
+    realm_data.assert_can_set_identity_verification_program(&identity_verification_program)?;
+
+    let mut realm_config_data = get_or_create_realm_config_data(
+        program_id,
+        realm_config_info,
+        realm_info.key,
+        payer_info,
+        system_info,
+    )?;
+
+    realm_config_data.identity_verification_program = identity_verification_program;
+
+    realm_config_data.serialize(&mut *realm_config_info.data.borrow_mut())?;
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+//! Program state processor
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    error::GovernanceError,
+    state::{realm::get_realm_data_for_authority, realm_config::get_realm_config_data_for_realm},
+};
+
+/// Processes SetRealmUseIdentityVerification instruction
+pub fn process_set_realm_use_identity_verification(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    use_identity_verification: bool,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let realm_info = next_account_info(account_info_iter)?; // 0
+    let realm_authority_info = next_account_info(account_info_iter)?; // 1
+    let realm_config_info = next_account_info(account_info_iter)?; // 2
+
+    let mut realm_data =
+        get_realm_data_for_authority(program_id, realm_info, realm_authority_info.key)?;
+
+    if !realm_authority_info.is_signer {
+        return Err(GovernanceError::RealmAuthorityMustSign.into());
+    }
+
+    if use_identity_verification {
+        let realm_config_data =
+            get_realm_config_data_for_realm(program_id, realm_config_info, realm_info.key)?;
+
+        if realm_config_data.identity_verification_program.is_none() {
+            return Err(GovernanceError::IdentityVerificationProgramNotConfigured.into());
+        }
+    }
+
+    realm_data.config.use_identity_verification = use_identity_verification;
+
+    realm_data.serialize(&mut *realm_info.data.borrow_mut())?;
+
+    Ok(())
+}
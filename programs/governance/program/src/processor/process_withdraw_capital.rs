@@ -0,0 +1,101 @@
+//! Program state processor
+
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::{
+    error::GovernanceError,
+    state::{
+        capital_contribution_record::get_capital_contribution_record_data_for_realm_and_investor,
+        capital_raise::get_capital_raise_data_for_realm,
+        governance::get_governance_data_for_realm,
+    },
+    tools::spl_token::{get_spl_token_owner, transfer_spl_tokens, transfer_spl_tokens_signed},
+};
+
+/// Processes WithdrawCapital and RefundCapital instructions
+/// When amount is None the whole contribution of the investor is refunded
+pub fn process_withdraw_capital(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: Option<u64>,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let realm_info = next_account_info(account_info_iter)?; // 0
+    let capital_raise_info = next_account_info(account_info_iter)?; // 1
+    let capital_governance_info = next_account_info(account_info_iter)?; // 2
+    let investor_info = next_account_info(account_info_iter)?; // 3
+    let capital_token_account_info = next_account_info(account_info_iter)?; // 4
+    let capital_holding_account_info = next_account_info(account_info_iter)?; // 5
+    let lp_token_account_info = next_account_info(account_info_iter)?; // 6
+    let lp_holding_account_info = next_account_info(account_info_iter)?; // 7
+    let capital_contribution_record_info = next_account_info(account_info_iter)?; // 8
+    let spl_token_info = next_account_info(account_info_iter)?; // 9
+
+    if !investor_info.is_signer {
+        return Err(GovernanceError::InvestorMustSign.into());
+    }
+
+    let mut capital_raise_data =
+        get_capital_raise_data_for_realm(program_id, capital_raise_info, realm_info.key)?;
+
+    // The capital is released by the capital governance which must own the holding account
+    if capital_raise_data.capital_holding_account != *capital_holding_account_info.key
+        || capital_raise_data.lp_holding_account != *lp_holding_account_info.key
+        || capital_raise_data.capital_governance != *capital_governance_info.key
+        || get_spl_token_owner(capital_holding_account_info)? != *capital_governance_info.key
+    {
+        return Err(GovernanceError::InvalidCapitalRaiseAccounts.into());
+    }
+
+    let mut capital_contribution_record_data =
+        get_capital_contribution_record_data_for_realm_and_investor(
+            program_id,
+            capital_contribution_record_info,
+            realm_info.key,
+            investor_info.key,
+        )?;
+
+    let amount = amount.unwrap_or(capital_contribution_record_data.total_contributed);
+
+    let lp_amount = capital_contribution_record_data.withdraw(amount)?;
+
+    let clock = Clock::get()?;
+
+    capital_raise_data.withdraw(amount, lp_amount, clock.unix_timestamp)?;
+
+    let capital_governance_data =
+        get_governance_data_for_realm(program_id, capital_governance_info, realm_info.key)?;
+
+    // Take the LP tokens back before releasing the capital
+    transfer_spl_tokens(
+        lp_token_account_info,
+        lp_holding_account_info,
+        investor_info,
+        lp_amount,
+        spl_token_info,
+    )?;
+
+    transfer_spl_tokens_signed(
+        capital_holding_account_info,
+        capital_token_account_info,
+        capital_governance_info,
+        &capital_governance_data.get_governance_address_seeds()?,
+        program_id,
+        amount,
+        spl_token_info,
+    )?;
+
+    capital_raise_data.serialize(&mut *capital_raise_info.data.borrow_mut())?;
+    capital_contribution_record_data
+        .serialize(&mut *capital_contribution_record_info.data.borrow_mut())?;
+
+    Ok(())
+}
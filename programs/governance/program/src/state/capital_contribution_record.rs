@@ -0,0 +1,226 @@
+//! Capital Contribution Record Account
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, clock::UnixTimestamp, program_error::ProgramError,
+    program_pack::IsInitialized, pubkey::Pubkey,
+};
+use spl_governance_tools::account::{get_account_data, AccountMaxSize};
+
+use crate::{error::GovernanceError, state::enums::GovernanceAccountType};
+
+/// Capital contributed by an investor to the capital raise of a Realm
+/// Account PDA seeds: ['capital-contribution', realm, investor]
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct CapitalContributionRecord {
+    /// Governance account type
+    pub account_type: GovernanceAccountType,
+
+    /// The Realm the contribution was made to
+    pub realm: Pubkey,
+
+    /// The investor who made the contribution
+    pub investor: Pubkey,
+
+    /// Amount of capital tokens contributed and not withdrawn
+    pub total_contributed: u64,
+
+    /// Amount of LP tokens received for the contributed capital
+    pub lp_received: u64,
+
+    /// When the investor made the first deposit
+    pub first_deposit_at: UnixTimestamp,
+
+    /// When the investor made the last deposit
+    pub last_deposit_at: UnixTimestamp,
+
+    /// Number of deposits the investor made
+    pub deposit_count: u32,
+}
+
+impl AccountMaxSize for CapitalContributionRecord {}
+
+impl IsInitialized for CapitalContributionRecord {
+    fn is_initialized(&self) -> bool {
+        self.account_type == GovernanceAccountType::CapitalContributionRecord
+    }
+}
+
+impl CapitalContributionRecord {
+    /// Records a deposit of capital tokens for the given amount of LP tokens
+    pub fn deposit(
+        &mut self,
+        amount: u64,
+        lp_amount: u64,
+        unix_timestamp: UnixTimestamp,
+    ) -> Result<(), ProgramError> {
+        self.total_contributed = self
+            .total_contributed
+            .checked_add(amount)
+            .ok_or(GovernanceError::CapitalRaiseMathOverflow)?;
+
+        self.lp_received = self
+            .lp_received
+            .checked_add(lp_amount)
+            .ok_or(GovernanceError::CapitalRaiseMathOverflow)?;
+
+        if self.deposit_count == 0 {
+            self.first_deposit_at = unix_timestamp;
+        }
+
+        self.last_deposit_at = unix_timestamp;
+        self.deposit_count = self
+            .deposit_count
+            .checked_add(1)
+            .ok_or(GovernanceError::CapitalRaiseMathOverflow)?;
+
+        Ok(())
+    }
+
+    /// Records a withdrawal of capital tokens
+    /// Returns the amount of LP tokens the investor must give back
+    /// LP tokens kept for the remaining contribution are rounded down so withdrawing everything returns all LP tokens
+    pub fn withdraw(&mut self, amount: u64) -> Result<u64, ProgramError> {
+        if amount == 0 || amount > self.total_contributed {
+            return Err(GovernanceError::InvalidCapitalWithdrawAmount.into());
+        }
+
+        let remaining_contributed = self.total_contributed - amount;
+
+        let remaining_lp = (self.lp_received as u128)
+            .checked_mul(remaining_contributed as u128)
+            .ok_or(GovernanceError::CapitalRaiseMathOverflow)?
+            / self.total_contributed as u128;
+
+        let lp_amount = self.lp_received - remaining_lp as u64;
+
+        self.total_contributed = remaining_contributed;
+        self.lp_received = remaining_lp as u64;
+
+        Ok(lp_amount)
+    }
+}
+
+/// Deserializes CapitalContributionRecord account and checks owner program
+pub fn get_capital_contribution_record_data(
+    program_id: &Pubkey,
+    capital_contribution_record_info: &AccountInfo,
+) -> Result<CapitalContributionRecord, ProgramError> {
+    get_account_data::<CapitalContributionRecord>(program_id, capital_contribution_record_info)
+}
+
+/// Deserializes CapitalContributionRecord account and checks the Realm and the investor it belongs to
+pub fn get_capital_contribution_record_data_for_realm_and_investor(
+    program_id: &Pubkey,
+    capital_contribution_record_info: &AccountInfo,
+    realm: &Pubkey,
+    investor: &Pubkey,
+) -> Result<CapitalContributionRecord, ProgramError> {
+    let capital_contribution_record_data =
+        get_capital_contribution_record_data(program_id, capital_contribution_record_info)?;
+
+    if capital_contribution_record_data.realm != *realm
+        || capital_contribution_record_data.investor != *investor
+    {
+        return Err(GovernanceError::InvalidCapitalContributionRecord.into());
+    }
+
+    Ok(capital_contribution_record_data)
+}
+
+/// Returns CapitalContributionRecord PDA seeds
+pub fn get_capital_contribution_record_address_seeds<'a>(
+    realm: &'a Pubkey,
+    investor: &'a Pubkey,
+) -> [&'a [u8]; 3] {
+    [b"capital-contribution", realm.as_ref(), investor.as_ref()]
+}
+
+/// Returns CapitalContributionRecord PDA address
+pub fn get_capital_contribution_record_address(
+    program_id: &Pubkey,
+    realm: &Pubkey,
+    investor: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &get_capital_contribution_record_address_seeds(realm, investor),
+        program_id,
+    )
+    .0
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn create_test_capital_contribution_record() -> CapitalContributionRecord {
+        CapitalContributionRecord {
+            account_type: GovernanceAccountType::CapitalContributionRecord,
+            realm: Pubkey::new_unique(),
+            investor: Pubkey::new_unique(),
+            total_contributed: 300,
+            lp_received: 451,
+            first_deposit_at: 10,
+            last_deposit_at: 10,
+            deposit_count: 1,
+        }
+    }
+
+    #[test]
+    fn test_deposit() {
+        // Arrange
+        let mut record = create_test_capital_contribution_record();
+
+        // Act
+        record.deposit(100, 150, 20).unwrap();
+
+        // Assert
+        assert_eq!(record.total_contributed, 400);
+        assert_eq!(record.lp_received, 601);
+        assert_eq!(record.first_deposit_at, 10);
+        assert_eq!(record.last_deposit_at, 20);
+        assert_eq!(record.deposit_count, 2);
+    }
+
+    #[test]
+    fn test_partial_withdraw() {
+        // Arrange
+        let mut record = create_test_capital_contribution_record();
+
+        // Act
+        let lp_amount = record.withdraw(100).unwrap();
+
+        // Assert
+        assert_eq!(lp_amount, 151);
+        assert_eq!(record.total_contributed, 200);
+        assert_eq!(record.lp_received, 300);
+    }
+
+    #[test]
+    fn test_full_withdraw_returns_all_lp() {
+        // Arrange
+        let mut record = create_test_capital_contribution_record();
+
+        // Act
+        let lp_amount = record.withdraw(300).unwrap();
+
+        // Assert
+        assert_eq!(lp_amount, 451);
+        assert_eq!(record.total_contributed, 0);
+        assert_eq!(record.lp_received, 0);
+    }
+
+    #[test]
+    fn test_withdraw_more_than_contributed_error() {
+        // Arrange
+        let mut record = create_test_capital_contribution_record();
+
+        // Act
+        let err = record.withdraw(301).err().unwrap();
+
+        // Assert
+        assert_eq!(err, GovernanceError::InvalidCapitalWithdrawAmount.into());
+        assert_eq!(record.total_contributed, 300);
+    }
+}
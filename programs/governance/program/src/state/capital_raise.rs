@@ -0,0 +1,512 @@
+//! Capital Raise Account
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, clock::UnixTimestamp, program_error::ProgramError,
+    program_pack::IsInitialized, pubkey::Pubkey,
+};
+use spl_governance_tools::account::{get_account_data, AccountMaxSize};
+
+use crate::{
+    error::GovernanceError,
+    state::enums::{CapitalRaiseState, GovernanceAccountType},
+};
+
+/// Capital raise config
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct CapitalRaiseConfig {
+    /// Numerator of the price expressed as LP tokens issued per capital token
+    /// lp_amount = capital_amount * price_numerator / price_denominator
+    pub price_numerator: u64,
+
+    /// Denominator of the price expressed as LP tokens issued per capital token
+    pub price_denominator: u64,
+
+    /// Minimum amount of capital tokens the raise must collect to succeed
+    pub min_raise: u64,
+
+    /// Maximum amount of capital tokens the raise can collect
+    pub max_raise: u64,
+
+    /// Minimum amount of capital tokens an investor must contribute in total
+    pub min_ticket: u64,
+
+    /// Maximum amount of capital tokens an investor can contribute in total
+    pub max_ticket: u64,
+
+    /// When the raise opens for deposits
+    pub open_at: UnixTimestamp,
+
+    /// When the raise closes for deposits
+    pub close_at: UnixTimestamp,
+}
+
+impl CapitalRaiseConfig {
+    /// Asserts the config is consistent
+    pub fn assert_is_valid(&self) -> Result<(), ProgramError> {
+        if self.price_numerator == 0
+            || self.price_denominator == 0
+            || self.min_raise > self.max_raise
+            || self.max_raise == 0
+            || self.min_ticket > self.max_ticket
+            || self.max_ticket == 0
+            || self.open_at >= self.close_at
+        {
+            return Err(GovernanceError::InvalidCapitalRaiseConfig.into());
+        }
+
+        Ok(())
+    }
+
+    /// Returns the amount of LP tokens issued for the given amount of capital tokens
+    /// The amount is rounded down in favour of the raise
+    pub fn get_lp_amount(&self, capital_amount: u64) -> Result<u64, ProgramError> {
+        let lp_amount = (capital_amount as u128)
+            .checked_mul(self.price_numerator as u128)
+            .ok_or(GovernanceError::CapitalRaiseMathOverflow)?
+            .checked_div(self.price_denominator as u128)
+            .ok_or(GovernanceError::CapitalRaiseMathOverflow)?;
+
+        if lp_amount > u64::MAX as u128 {
+            return Err(GovernanceError::CapitalRaiseMathOverflow.into());
+        }
+
+        Ok(lp_amount as u64)
+    }
+
+    /// Checks whether the raise accepts deposits at the given time
+    pub fn is_open(&self, unix_timestamp: UnixTimestamp) -> bool {
+        self.open_at <= unix_timestamp && unix_timestamp < self.close_at
+    }
+}
+
+/// Capital raise of a Realm exchanging capital tokens for LP tokens at a fixed price
+/// Account PDA seeds: ['capital-raise', realm]
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct CapitalRaise {
+    /// Governance account type
+    pub account_type: GovernanceAccountType,
+
+    /// The Realm the capital raise belongs to
+    pub realm: Pubkey,
+
+    /// Mint of the capital tokens investors deposit
+    pub capital_mint: Pubkey,
+
+    /// Mint of the LP tokens investors receive
+    pub lp_mint: Pubkey,
+
+    /// Governance owning the LP holding account
+    pub lp_governance: Pubkey,
+
+    /// Governance owning the capital holding account
+    pub capital_governance: Pubkey,
+
+    /// Token account receiving the capital tokens
+    pub capital_holding_account: Pubkey,
+
+    /// Token account holding the LP tokens issued to investors
+    pub lp_holding_account: Pubkey,
+
+    /// Current state of the capital raise
+    pub state: CapitalRaiseState,
+
+    /// Capital raise config
+    pub config: CapitalRaiseConfig,
+
+    /// Total amount of capital tokens deposited
+    pub total_raised: u64,
+
+    /// Total amount of LP tokens issued to investors
+    pub total_lp_issued: u64,
+}
+
+impl AccountMaxSize for CapitalRaise {}
+
+impl IsInitialized for CapitalRaise {
+    fn is_initialized(&self) -> bool {
+        self.account_type == GovernanceAccountType::CapitalRaise
+    }
+}
+
+impl CapitalRaise {
+    /// Asserts the given accounts are the ones the capital raise was created with
+    pub fn assert_is_valid_deposit_accounts(
+        &self,
+        lp_governance: &Pubkey,
+        capital_holding_account: &Pubkey,
+        lp_holding_account: &Pubkey,
+        lp_mint: &Pubkey,
+    ) -> Result<(), ProgramError> {
+        if self.lp_governance != *lp_governance
+            || self.capital_holding_account != *capital_holding_account
+            || self.lp_holding_account != *lp_holding_account
+            || self.lp_mint != *lp_mint
+        {
+            return Err(GovernanceError::InvalidCapitalRaiseAccounts.into());
+        }
+
+        Ok(())
+    }
+
+    /// Checks whether the raise is in Open state and accepts deposits at the given time
+    pub fn is_open(&self, unix_timestamp: UnixTimestamp) -> bool {
+        self.state == CapitalRaiseState::Open && self.config.is_open(unix_timestamp)
+    }
+
+    /// Validates a deposit of capital tokens and records it on the raise
+    /// investor_contributed is the amount the investor contributed before the deposit
+    /// Returns the amount of LP tokens to issue for the deposit
+    pub fn deposit(
+        &mut self,
+        amount: u64,
+        investor_contributed: u64,
+        unix_timestamp: UnixTimestamp,
+    ) -> Result<u64, ProgramError> {
+        if !self.is_open(unix_timestamp) {
+            return Err(GovernanceError::CapitalRaiseNotOpen.into());
+        }
+
+        let investor_total = investor_contributed
+            .checked_add(amount)
+            .ok_or(GovernanceError::CapitalRaiseMathOverflow)?;
+
+        // Both limits apply to the investor's total contribution so the first deposit must reach min_ticket
+        // and later deposits can top it up by any amount
+        if investor_total < self.config.min_ticket || investor_total > self.config.max_ticket {
+            return Err(GovernanceError::CapitalRaiseTicketOutOfRange.into());
+        }
+
+        let total_raised = self
+            .total_raised
+            .checked_add(amount)
+            .ok_or(GovernanceError::CapitalRaiseMathOverflow)?;
+
+        if total_raised > self.config.max_raise {
+            return Err(GovernanceError::CapitalRaiseMaxRaiseExceeded.into());
+        }
+
+        let lp_amount = self.config.get_lp_amount(amount)?;
+
+        if lp_amount == 0 {
+            return Err(GovernanceError::CapitalRaiseDepositTooSmall.into());
+        }
+
+        self.total_raised = total_raised;
+        self.total_lp_issued = self
+            .total_lp_issued
+            .checked_add(lp_amount)
+            .ok_or(GovernanceError::CapitalRaiseMathOverflow)?;
+
+        Ok(lp_amount)
+    }
+
+    /// Validates a withdrawal of capital tokens and removes it from the raise
+    /// Capital can be withdrawn only while the raise is open or after it failed
+    pub fn withdraw(
+        &mut self,
+        amount: u64,
+        lp_amount: u64,
+        unix_timestamp: UnixTimestamp,
+    ) -> Result<(), ProgramError> {
+        if !self.is_open(unix_timestamp) && self.state != CapitalRaiseState::Failed {
+            return Err(GovernanceError::CapitalRaiseNotRefundable.into());
+        }
+
+        self.total_raised = self
+            .total_raised
+            .checked_sub(amount)
+            .ok_or(GovernanceError::CapitalRaiseMathOverflow)?;
+
+        self.total_lp_issued = self
+            .total_lp_issued
+            .checked_sub(lp_amount)
+            .ok_or(GovernanceError::CapitalRaiseMathOverflow)?;
+
+        Ok(())
+    }
+
+    /// Marks the raise as Failed so investors can withdraw their capital
+    pub fn cancel(&mut self) -> Result<(), ProgramError> {
+        if self.state != CapitalRaiseState::Open {
+            return Err(GovernanceError::InvalidCapitalRaiseState.into());
+        }
+
+        self.state = CapitalRaiseState::Failed;
+
+        Ok(())
+    }
+
+    /// Resolves the raise once it closed
+    /// It succeeds when min raise was collected and fails otherwise
+    pub fn finalize(&mut self, unix_timestamp: UnixTimestamp) -> Result<(), ProgramError> {
+        if self.state != CapitalRaiseState::Open {
+            return Err(GovernanceError::InvalidCapitalRaiseState.into());
+        }
+
+        if unix_timestamp < self.config.close_at {
+            return Err(GovernanceError::CapitalRaiseNotClosed.into());
+        }
+
+        self.state = if self.total_raised >= self.config.min_raise {
+            CapitalRaiseState::Succeeded
+        } else {
+            CapitalRaiseState::Failed
+        };
+
+        Ok(())
+    }
+}
+
+/// Deserializes CapitalRaise account and checks owner program
+pub fn get_capital_raise_data(
+    program_id: &Pubkey,
+    capital_raise_info: &AccountInfo,
+) -> Result<CapitalRaise, ProgramError> {
+    get_account_data::<CapitalRaise>(program_id, capital_raise_info)
+}
+
+/// Deserializes CapitalRaise account and checks the owner program and the Realm it belongs to
+pub fn get_capital_raise_data_for_realm(
+    program_id: &Pubkey,
+    capital_raise_info: &AccountInfo,
+    realm: &Pubkey,
+) -> Result<CapitalRaise, ProgramError> {
+    let capital_raise_data = get_capital_raise_data(program_id, capital_raise_info)?;
+
+    if capital_raise_data.realm != *realm {
+        return Err(GovernanceError::InvalidRealmForCapitalRaise.into());
+    }
+
+    Ok(capital_raise_data)
+}
+
+/// Returns CapitalRaise PDA seeds
+pub fn get_capital_raise_address_seeds(realm: &Pubkey) -> [&[u8]; 2] {
+    [b"capital-raise", realm.as_ref()]
+}
+
+/// Returns CapitalRaise PDA address
+pub fn get_capital_raise_address(program_id: &Pubkey, realm: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&get_capital_raise_address_seeds(realm), program_id).0
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn create_test_capital_raise() -> CapitalRaise {
+        CapitalRaise {
+            account_type: GovernanceAccountType::CapitalRaise,
+            realm: Pubkey::new_unique(),
+            capital_mint: Pubkey::new_unique(),
+            lp_mint: Pubkey::new_unique(),
+            lp_governance: Pubkey::new_unique(),
+            capital_governance: Pubkey::new_unique(),
+            capital_holding_account: Pubkey::new_unique(),
+            lp_holding_account: Pubkey::new_unique(),
+            state: CapitalRaiseState::Open,
+            config: CapitalRaiseConfig {
+                price_numerator: 3,
+                price_denominator: 2,
+                min_raise: 1_000,
+                max_raise: 10_000,
+                min_ticket: 100,
+                max_ticket: 5_000,
+                open_at: 10,
+                close_at: 20,
+            },
+            total_raised: 0,
+            total_lp_issued: 0,
+        }
+    }
+
+    #[test]
+    fn test_get_lp_amount_rounds_down() {
+        // Arrange
+        let capital_raise = create_test_capital_raise();
+
+        // Act
+        let lp_amount = capital_raise.config.get_lp_amount(101).unwrap();
+
+        // Assert
+        assert_eq!(lp_amount, 151);
+    }
+
+    #[test]
+    fn test_get_lp_amount_without_overflow() {
+        // Arrange
+        let mut capital_raise = create_test_capital_raise();
+        capital_raise.config.price_numerator = u64::MAX;
+        capital_raise.config.price_denominator = u64::MAX;
+
+        // Act
+        let lp_amount = capital_raise.config.get_lp_amount(u64::MAX).unwrap();
+
+        // Assert
+        assert_eq!(lp_amount, u64::MAX);
+    }
+
+    #[test]
+    fn test_deposit() {
+        // Arrange
+        let mut capital_raise = create_test_capital_raise();
+
+        // Act
+        let lp_amount = capital_raise.deposit(1_000, 0, 10).unwrap();
+
+        // Assert
+        assert_eq!(lp_amount, 1_500);
+        assert_eq!(capital_raise.total_raised, 1_000);
+        assert_eq!(capital_raise.total_lp_issued, 1_500);
+    }
+
+    #[test]
+    fn test_deposit_outside_raise_window_error() {
+        // Arrange
+        let mut capital_raise = create_test_capital_raise();
+
+        // Act
+        let before_open_err = capital_raise.deposit(1_000, 0, 9).err().unwrap();
+        let after_close_err = capital_raise.deposit(1_000, 0, 20).err().unwrap();
+
+        // Assert
+        assert_eq!(before_open_err, GovernanceError::CapitalRaiseNotOpen.into());
+        assert_eq!(after_close_err, GovernanceError::CapitalRaiseNotOpen.into());
+    }
+
+    #[test]
+    fn test_deposit_ticket_out_of_range_error() {
+        // Arrange
+        let mut capital_raise = create_test_capital_raise();
+
+        // Act
+        let below_min_err = capital_raise.deposit(99, 0, 10).err().unwrap();
+        let above_max_err = capital_raise.deposit(1_001, 4_000, 10).err().unwrap();
+
+        // Assert
+        assert_eq!(below_min_err, GovernanceError::CapitalRaiseTicketOutOfRange.into());
+        assert_eq!(above_max_err, GovernanceError::CapitalRaiseTicketOutOfRange.into());
+    }
+
+    #[test]
+    fn test_deposit_top_up_below_min_ticket() {
+        // Arrange
+        let mut capital_raise = create_test_capital_raise();
+
+        // Act
+        let lp_amount = capital_raise.deposit(10, 100, 10).unwrap();
+
+        // Assert
+        assert_eq!(lp_amount, 15);
+        assert_eq!(capital_raise.total_raised, 10);
+    }
+
+    #[test]
+    fn test_deposit_total_below_min_ticket_error() {
+        // Arrange
+        let mut capital_raise = create_test_capital_raise();
+
+        // Act
+        let err = capital_raise.deposit(50, 40, 10).err().unwrap();
+
+        // Assert
+        assert_eq!(err, GovernanceError::CapitalRaiseTicketOutOfRange.into());
+    }
+
+    #[test]
+    fn test_deposit_max_raise_exceeded_error() {
+        // Arrange
+        let mut capital_raise = create_test_capital_raise();
+        capital_raise.total_raised = 9_000;
+
+        // Act
+        let err = capital_raise.deposit(1_001, 0, 10).err().unwrap();
+
+        // Assert
+        assert_eq!(err, GovernanceError::CapitalRaiseMaxRaiseExceeded.into());
+        assert_eq!(capital_raise.total_raised, 9_000);
+    }
+
+    #[test]
+    fn test_withdraw_after_close_error() {
+        // Arrange
+        let mut capital_raise = create_test_capital_raise();
+        capital_raise.deposit(1_000, 0, 10).unwrap();
+
+        // Act
+        let err = capital_raise.withdraw(1_000, 1_500, 20).err().unwrap();
+
+        // Assert
+        assert_eq!(err, GovernanceError::CapitalRaiseNotRefundable.into());
+    }
+
+    #[test]
+    fn test_withdraw_after_failed() {
+        // Arrange
+        let mut capital_raise = create_test_capital_raise();
+        capital_raise.deposit(500, 0, 10).unwrap();
+        capital_raise.finalize(20).unwrap();
+
+        // Act
+        capital_raise.withdraw(500, 750, 30).unwrap();
+
+        // Assert
+        assert_eq!(capital_raise.state, CapitalRaiseState::Failed);
+        assert_eq!(capital_raise.total_raised, 0);
+        assert_eq!(capital_raise.total_lp_issued, 0);
+    }
+
+    #[test]
+    fn test_finalize_succeeded() {
+        // Arrange
+        let mut capital_raise = create_test_capital_raise();
+        capital_raise.deposit(1_000, 0, 10).unwrap();
+
+        // Act
+        capital_raise.finalize(20).unwrap();
+
+        // Assert
+        assert_eq!(capital_raise.state, CapitalRaiseState::Succeeded);
+    }
+
+    #[test]
+    fn test_finalize_before_close_error() {
+        // Arrange
+        let mut capital_raise = create_test_capital_raise();
+
+        // Act
+        let err = capital_raise.finalize(19).err().unwrap();
+
+        // Assert
+        assert_eq!(err, GovernanceError::CapitalRaiseNotClosed.into());
+    }
+
+    #[test]
+    fn test_deposit_after_cancel_error() {
+        // Arrange
+        let mut capital_raise = create_test_capital_raise();
+        capital_raise.cancel().unwrap();
+
+        // Act
+        let err = capital_raise.deposit(1_000, 0, 10).err().unwrap();
+
+        // Assert
+        assert_eq!(err, GovernanceError::CapitalRaiseNotOpen.into());
+    }
+
+    #[test]
+    fn test_invalid_config_error() {
+        // Arrange
+        let mut capital_raise = create_test_capital_raise();
+        capital_raise.config.close_at = capital_raise.config.open_at;
+
+        // Act
+        let err = capital_raise.config.assert_is_valid().err().unwrap();
+
+        // Assert
+        assert_eq!(err, GovernanceError::InvalidCapitalRaiseConfig.into());
+    }
+}
@@ -0,0 +1,288 @@
+//! Distribution Account
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, clock::UnixTimestamp, keccak::hashv, program_error::ProgramError,
+    program_pack::IsInitialized, pubkey::Pubkey,
+};
+use spl_governance_tools::account::{get_account_data, AccountMaxSize};
+
+use crate::{error::GovernanceError, state::enums::GovernanceAccountType};
+
+/// Distribution of tokens to LP token holders pro rata to the LP supply snapshot taken when it was funded
+/// Holders claim the LP balance they had in the holders snapshot, LP tokens moved afterwards don't entitle to a claim
+/// Account PDA seeds: ['distribution', governance, distribution_index]
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct Distribution {
+    /// Governance account type
+    pub account_type: GovernanceAccountType,
+
+    /// The Realm the distribution belongs to
+    pub realm: Pubkey,
+
+    /// Governance which funded the distribution from its treasury
+    pub governance: Pubkey,
+
+    /// Index of the distribution within the Governance
+    pub distribution_index: u64,
+
+    /// Mint of the LP tokens entitling holders to claim
+    pub lp_mint: Pubkey,
+
+    /// Mint of the distributed tokens
+    pub distribution_mint: Pubkey,
+
+    /// Circulating LP token supply when the distribution was funded
+    /// LP tokens still held by the capital raise are excluded
+    pub lp_supply_snapshot: u64,
+
+    /// Root of a merkle tree of (holder, lp_amount) leaves taken from the LP token balances when the distribution was funded
+    pub holders_merkle_root: [u8; 32],
+
+    /// Amount of tokens distributed
+    pub total_amount: u64,
+
+    /// Amount of tokens claimed so far
+    pub claimed_amount: u64,
+
+    /// When the distribution was funded
+    pub funded_at: UnixTimestamp,
+}
+
+impl AccountMaxSize for Distribution {}
+
+impl IsInitialized for Distribution {
+    fn is_initialized(&self) -> bool {
+        self.account_type == GovernanceAccountType::Distribution
+    }
+}
+
+impl Distribution {
+    /// Asserts the proof shows the holder had the given LP token balance in the holders snapshot
+    pub fn assert_is_snapshot_holder(
+        &self,
+        holder: &Pubkey,
+        lp_amount: u64,
+        proof: &[[u8; 32]],
+    ) -> Result<(), ProgramError> {
+        let leaf = hashv(&[&[0], holder.as_ref(), &lp_amount.to_le_bytes()]).to_bytes();
+
+        let computed = proof.iter().fold(leaf, |node, sibling| {
+            let (left, right) = if node <= *sibling {
+                (&node, sibling)
+            } else {
+                (sibling, &node)
+            };
+            hashv(&[&[1], left, right]).to_bytes()
+        });
+
+        if computed != self.holders_merkle_root {
+            return Err(GovernanceError::InvalidDistributionClaimProof.into());
+        }
+
+        Ok(())
+    }
+
+    /// Records a claim for the given snapshot LP token balance
+    /// Returns the pro rata amount of tokens to transfer to the claimant, rounded down
+    pub fn claim(&mut self, lp_amount: u64) -> Result<u64, ProgramError> {
+        let amount = (self.total_amount as u128)
+            .checked_mul(lp_amount as u128)
+            .ok_or(GovernanceError::DistributionMathOverflow)?
+            / self.lp_supply_snapshot as u128;
+
+        // A holders snapshot which doesn't add up to the LP supply snapshot can't drain more than what is left
+        let amount = (amount as u64).min(self.total_amount - self.claimed_amount);
+
+        if amount == 0 {
+            return Err(GovernanceError::NothingToClaim.into());
+        }
+
+        self.claimed_amount += amount;
+
+        Ok(amount)
+    }
+}
+
+/// Deserializes Distribution account and checks owner program
+pub fn get_distribution_data(
+    program_id: &Pubkey,
+    distribution_info: &AccountInfo,
+) -> Result<Distribution, ProgramError> {
+    get_account_data::<Distribution>(program_id, distribution_info)
+}
+
+/// Returns Distribution PDA seeds
+pub fn get_distribution_address_seeds<'a>(
+    governance: &'a Pubkey,
+    distribution_index_le_bytes: &'a [u8],
+) -> [&'a [u8]; 3] {
+    [
+        b"distribution",
+        governance.as_ref(),
+        distribution_index_le_bytes,
+    ]
+}
+
+/// Returns Distribution PDA address
+pub fn get_distribution_address<'a>(
+    program_id: &Pubkey,
+    governance: &'a Pubkey,
+    distribution_index_le_bytes: &'a [u8],
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &get_distribution_address_seeds(governance, distribution_index_le_bytes),
+        program_id,
+    )
+    .0
+}
+
+/// Returns Distribution holding token account PDA seeds
+pub fn get_distribution_holding_address_seeds(distribution: &Pubkey) -> [&[u8]; 2] {
+    [b"distribution-holding", distribution.as_ref()]
+}
+
+/// Returns Distribution holding token account PDA address
+pub fn get_distribution_holding_address(program_id: &Pubkey, distribution: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &get_distribution_holding_address_seeds(distribution),
+        program_id,
+    )
+    .0
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn create_test_distribution() -> Distribution {
+        Distribution {
+            account_type: GovernanceAccountType::Distribution,
+            realm: Pubkey::new_unique(),
+            governance: Pubkey::new_unique(),
+            distribution_index: 0,
+            lp_mint: Pubkey::new_unique(),
+            distribution_mint: Pubkey::new_unique(),
+            lp_supply_snapshot: 3_000,
+            holders_merkle_root: [0; 32],
+            total_amount: 1_000,
+            claimed_amount: 0,
+            funded_at: 10,
+        }
+    }
+
+    #[test]
+    fn test_claim_pro_rata() {
+        // Arrange
+        let mut distribution = create_test_distribution();
+
+        // Act
+        let amount = distribution.claim(1_000).unwrap();
+
+        // Assert
+        assert_eq!(amount, 333);
+        assert_eq!(distribution.claimed_amount, 333);
+    }
+
+    #[test]
+    fn test_claim_capped_by_remaining_amount() {
+        // Arrange
+        let mut distribution = create_test_distribution();
+        distribution.claimed_amount = 900;
+
+        // Act
+        let amount = distribution.claim(3_000).unwrap();
+
+        // Assert
+        assert_eq!(amount, 100);
+        assert_eq!(distribution.claimed_amount, 1_000);
+    }
+
+    #[test]
+    fn test_claim_nothing_error() {
+        // Arrange
+        let mut distribution = create_test_distribution();
+
+        // Act
+        let err = distribution.claim(2).err().unwrap();
+
+        // Assert
+        assert_eq!(err, GovernanceError::NothingToClaim.into());
+    }
+
+    fn leaf(holder: &Pubkey, lp_amount: u64) -> [u8; 32] {
+        hashv(&[&[0], holder.as_ref(), &lp_amount.to_le_bytes()]).to_bytes()
+    }
+
+    fn node(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+        let (left, right) = if a <= b { (a, b) } else { (b, a) };
+        hashv(&[&[1], left, right]).to_bytes()
+    }
+
+    #[test]
+    fn test_assert_is_snapshot_holder() {
+        // Arrange
+        let mut distribution = create_test_distribution();
+
+        let holder = Pubkey::new_unique();
+        let other_holder = Pubkey::new_unique();
+
+        distribution.holders_merkle_root = node(&leaf(&holder, 1_000), &leaf(&other_holder, 2_000));
+
+        // Act
+        let result =
+            distribution.assert_is_snapshot_holder(&holder, 1_000, &[leaf(&other_holder, 2_000)]);
+
+        // Assert
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn test_assert_is_snapshot_holder_with_inflated_lp_amount_error() {
+        // Arrange
+        let mut distribution = create_test_distribution();
+
+        let holder = Pubkey::new_unique();
+        let other_holder = Pubkey::new_unique();
+
+        distribution.holders_merkle_root = node(&leaf(&holder, 1_000), &leaf(&other_holder, 2_000));
+
+        // Act
+        let err = distribution
+            .assert_is_snapshot_holder(&holder, 3_000, &[leaf(&other_holder, 2_000)])
+            .err()
+            .unwrap();
+
+        // Assert
+        assert_eq!(err, GovernanceError::InvalidDistributionClaimProof.into());
+    }
+
+    #[test]
+    fn test_claim_with_lp_moved_to_another_wallet_error() {
+        // Arrange
+        let mut distribution = create_test_distribution();
+
+        let holder = Pubkey::new_unique();
+        let other_holder = Pubkey::new_unique();
+        let holder_second_wallet = Pubkey::new_unique();
+
+        distribution.holders_merkle_root = node(&leaf(&holder, 1_000), &leaf(&other_holder, 2_000));
+
+        distribution
+            .assert_is_snapshot_holder(&holder, 1_000, &[leaf(&other_holder, 2_000)])
+            .unwrap();
+        distribution.claim(1_000).unwrap();
+
+        // Act
+        // The holder moved the LP tokens to another wallet after claiming and tries to claim again
+        let err = distribution
+            .assert_is_snapshot_holder(&holder_second_wallet, 1_000, &[leaf(&other_holder, 2_000)])
+            .err()
+            .unwrap();
+
+        // Assert
+        assert_eq!(err, GovernanceError::InvalidDistributionClaimProof.into());
+        assert_eq!(distribution.claimed_amount, 333);
+    }
+}
@@ -0,0 +1,65 @@
+//! Distribution Claim Record Account
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use solana_program::{clock::UnixTimestamp, program_pack::IsInitialized, pubkey::Pubkey};
+use spl_governance_tools::account::AccountMaxSize;
+
+use crate::state::enums::GovernanceAccountType;
+
+/// Claim of an LP token holder on a Distribution
+/// The account exists once the holder claimed which prevents claiming twice
+/// Account PDA seeds: ['distribution-claim', distribution, claimant]
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct DistributionClaimRecord {
+    /// Governance account type
+    pub account_type: GovernanceAccountType,
+
+    /// The Distribution claimed
+    pub distribution: Pubkey,
+
+    /// The LP token holder who claimed
+    pub claimant: Pubkey,
+
+    /// LP token balance in the holders snapshot the claim was computed from
+    pub lp_amount: u64,
+
+    /// Amount of tokens claimed
+    pub amount: u64,
+
+    /// When the claim was made
+    pub claimed_at: UnixTimestamp,
+}
+
+impl AccountMaxSize for DistributionClaimRecord {}
+
+impl IsInitialized for DistributionClaimRecord {
+    fn is_initialized(&self) -> bool {
+        self.account_type == GovernanceAccountType::DistributionClaimRecord
+    }
+}
+
+/// Returns DistributionClaimRecord PDA seeds
+pub fn get_distribution_claim_record_address_seeds<'a>(
+    distribution: &'a Pubkey,
+    claimant: &'a Pubkey,
+) -> [&'a [u8]; 3] {
+    [
+        b"distribution-claim",
+        distribution.as_ref(),
+        claimant.as_ref(),
+    ]
+}
+
+/// Returns DistributionClaimRecord PDA address
+pub fn get_distribution_claim_record_address(
+    program_id: &Pubkey,
+    distribution: &Pubkey,
+    claimant: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &get_distribution_claim_record_address_seeds(distribution, claimant),
+        program_id,
+    )
+    .0
+}
@@ -88,6 +88,18 @@ pub enum GovernanceAccountType {
     /// Proposal Signatory account
     /// V2 adds extra reserved space reserved_v2
     SignatoryRecordV2,
+
+    /// Capital raise account of a Realm
+    CapitalRaise,
+
+    /// Capital contributed by an investor to the capital raise of a Realm
+    CapitalContributionRecord,
+
+    /// Distribution of tokens to LP token holders funded by a Governance
+    Distribution,
+
+    /// Claim of an LP token holder on a Distribution
+    DistributionClaimRecord,
 }
 
 impl Default for GovernanceAccountType {
@@ -129,6 +141,10 @@ pub enum ProposalState {
     /// Same as Executing but indicates some instructions failed to execute
     /// Proposal can't be transitioned from ExecutingWithErrors to Completed state
     ExecutingWithErrors,
+
+    /// Vetoed - The council vetoed the Proposal
+    /// Proposal enters the state as soon as the veto votes reach the Governance council_veto_vote_threshold_percentage
+    Vetoed,
 }
 
 impl Default for ProposalState {
@@ -137,6 +153,27 @@ impl Default for ProposalState {
     }
 }
 
+/// What state a CapitalRaise is in
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub enum CapitalRaiseState {
+    /// Open - The raise takes deposits between its open and close timestamps
+    Open,
+
+    /// Succeeded - The raise closed with at least its min raise collected
+    Succeeded,
+
+    /// Failed - The raise closed below its min raise or was cancelled by the Realm authority
+    /// Investors can withdraw their capital
+    Failed,
+}
+
+impl Default for CapitalRaiseState {
+    fn default() -> Self {
+        CapitalRaiseState::Open
+    }
+}
+
 /// The type of the vote threshold percentage used to resolve a vote on a Proposal
 #[repr(C)]
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
@@ -150,7 +187,6 @@ pub enum VoteThresholdPercentage {
     /// The minimum number of votes in % out of the entire pool of governance tokens eligible to vote
     /// which must be cast for the vote to be valid
     /// Once the quorum is achieved a simple majority (50%+1) of Yes votes is required for the vote to succeed
-    /// Note: Quorum is not implemented in the current version
     Quorum(u8),
 }
 
@@ -219,7 +255,6 @@ pub enum MintMaxVoteWeightSource {
     SupplyFraction(u64),
 
     /// Absolute value, irrelevant of the actual mint supply, is used as max vote weight
-    /// Note: this option is not implemented in the current version
     Absolute(u64),
 }
 
@@ -24,7 +24,6 @@ use spl_governance_tools::{
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
 pub struct GovernanceConfig {
     /// The type of the vote threshold used for voting
-    /// Note: In the current version only YesVote threshold is supported
     pub vote_threshold_percentage: VoteThresholdPercentage,
 
     /// Minimum community weight a governance token owner must possess to be able to create a proposal
@@ -41,11 +40,16 @@ pub struct GovernanceConfig {
 
     /// The time period in seconds within which a Proposal can be still cancelled after being voted on
     /// Once cool off time expires Proposal can't be cancelled any longer and becomes a law
-    /// Note: This field is not implemented in the current version
+    /// Transactions can't be executed within the cool off time
     pub proposal_cool_off_time: u32,
 
     /// Minimum council weight a governance token owner must possess to be able to create a proposal
     pub min_council_weight_to_create_proposal: u64,
+
+    /// The threshold of council veto votes required to veto a community Proposal
+    /// It's computed from the council mint supply and None disables council veto
+    /// Note: Governance accounts created before council veto was introduced have None in place of the reserved space
+    pub council_veto_vote_threshold_percentage: Option<VoteThresholdPercentage>,
 }
 
 /// Governance Account
@@ -76,17 +80,41 @@ pub struct GovernanceV2 {
     pub config: GovernanceConfig,
 
     /// Reserved space for future versions
-    pub reserved: [u8; 6],
+    pub reserved: [u8; 5],
 
     /// The number of proposals in voting state in the Governance
     pub voting_proposal_count: u16,
 
     /// Reserved space for versions v2 and onwards
     /// Note: This space won't be available to v1 accounts until runtime supports resizing
-    pub reserved_v2: [u8; 128],
+    /// Note: 2 bytes of the original space are taken by config.council_veto_vote_threshold_percentage when it's set
+    pub reserved_v2: Reserved126,
+}
+
+/// Reserved space of 126 bytes split into array sizes supported by borsh
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct Reserved126 {
+    reserved64: [u8; 64],
+    reserved32: [u8; 32],
+    reserved30: [u8; 30],
+}
+
+impl Default for Reserved126 {
+    fn default() -> Self {
+        Self {
+            reserved64: [0; 64],
+            reserved32: [0; 32],
+            reserved30: [0; 30],
+        }
+    }
 }
 
-impl AccountMaxSize for GovernanceV2 {}
+impl AccountMaxSize for GovernanceV2 {
+    fn get_max_size(&self) -> Option<usize> {
+        // Reserve the space for council_veto_vote_threshold_percentage so it can be set later
+        Some(1 + 32 + 32 + 4 + 31 + 3 + 5 + 2 + 126)
+    }
+}
 
 /// Checks if the given account type is one of the Governance V2 account types
 pub fn is_governance_v2_account_type(account_type: &GovernanceAccountType) -> bool {
@@ -113,7 +141,11 @@ pub fn is_governance_v2_account_type(account_type: &GovernanceAccountType) -> bo
         | GovernanceAccountType::ProposalTransactionV2
         | GovernanceAccountType::VoteRecordV1
         | GovernanceAccountType::VoteRecordV2
-        | GovernanceAccountType::ProgramMetadata => false,
+        | GovernanceAccountType::ProgramMetadata
+        | GovernanceAccountType::CapitalRaise
+        | GovernanceAccountType::CapitalContributionRecord
+        | GovernanceAccountType::Distribution
+        | GovernanceAccountType::DistributionClaimRecord => false,
     }
 }
 
@@ -159,7 +191,11 @@ impl GovernanceV2 {
             | GovernanceAccountType::ProgramMetadata
             | GovernanceAccountType::RealmV2
             | GovernanceAccountType::TokenOwnerRecordV2
-            | GovernanceAccountType::SignatoryRecordV2 => {
+            | GovernanceAccountType::SignatoryRecordV2
+            | GovernanceAccountType::CapitalRaise
+            | GovernanceAccountType::CapitalContributionRecord
+            | GovernanceAccountType::Distribution
+            | GovernanceAccountType::DistributionClaimRecord => {
                 return Err(GovernanceToolsError::InvalidAccountType.into())
             }
         };
@@ -175,10 +211,15 @@ impl GovernanceV2 {
             // V1 account can't be resized and we have to translate it back to the original format
 
             // If reserved_v2 is used it must be individually asses for v1 backward compatibility impact
-            if self.reserved_v2 != [0; 128] {
+            if self.reserved_v2 != Reserved126::default() {
                 panic!("Extended data not supported by GovernanceV1")
             }
 
+            // The veto threshold takes 2 extra bytes which v1 accounts don't have
+            if self.config.council_veto_vote_threshold_percentage.is_some() {
+                return Err(GovernanceError::CouncilVetoNotSupportedByGovernanceV1.into());
+            }
+
             let governance_data_v1 = GovernanceV1 {
                 account_type: self.account_type,
                 realm: self.realm,
@@ -222,7 +263,7 @@ pub fn get_governance_data(
             voting_proposal_count: governance_data_v1.voting_proposal_count,
 
             // Add the extra reserved_v2 padding
-            reserved_v2: [0; 128],
+            reserved_v2: Reserved126::default(),
         });
     }
 
@@ -383,14 +424,178 @@ pub fn assert_is_valid_governance_config(
                 return Err(GovernanceError::InvalidVoteThresholdPercentage.into());
             }
         }
-        _ => {
-            return Err(GovernanceError::VoteThresholdPercentageTypeNotSupported.into());
+        VoteThresholdPercentage::Quorum(quorum_percentage) => {
+            if !(1..=100).contains(&quorum_percentage) {
+                return Err(GovernanceError::InvalidVoteThresholdPercentage.into());
+            }
         }
     }
 
-    if governance_config.proposal_cool_off_time > 0 {
-        return Err(GovernanceError::ProposalCoolOffTimeNotSupported.into());
+    match governance_config.council_veto_vote_threshold_percentage {
+        Some(VoteThresholdPercentage::YesVote(veto_vote_threshold_percentage)) => {
+            if !(1..=100).contains(&veto_vote_threshold_percentage) {
+                return Err(GovernanceError::InvalidVoteThresholdPercentage.into());
+            }
+        }
+        Some(VoteThresholdPercentage::Quorum(_)) => {
+            return Err(GovernanceError::VoteThresholdPercentageTypeNotSupported.into());
+        }
+        None => {}
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use solana_program::clock::Epoch;
+
+    /// GovernanceConfig layout before council veto was introduced
+    #[derive(BorshSerialize)]
+    struct GovernanceConfigLegacy {
+        vote_threshold_percentage: VoteThresholdPercentage,
+        min_community_weight_to_create_proposal: u64,
+        min_transaction_hold_up_time: u32,
+        max_voting_time: u32,
+        vote_tipping: VoteTipping,
+        proposal_cool_off_time: u32,
+        min_council_weight_to_create_proposal: u64,
+    }
+
+    /// GovernanceV2 layout before council veto was introduced
+    #[derive(BorshSerialize)]
+    struct GovernanceV2Legacy {
+        account_type: GovernanceAccountType,
+        realm: Pubkey,
+        governed_account: Pubkey,
+        proposals_count: u32,
+        config: GovernanceConfigLegacy,
+        reserved: [u8; 6],
+        voting_proposal_count: u16,
+        reserved_v2: [u8; 128],
+    }
+
+    fn create_test_governance_legacy_data() -> Vec<u8> {
+        let governance_legacy = GovernanceV2Legacy {
+            account_type: GovernanceAccountType::GovernanceV2,
+            realm: Pubkey::new_unique(),
+            governed_account: Pubkey::new_unique(),
+            proposals_count: 10,
+            config: GovernanceConfigLegacy {
+                vote_threshold_percentage: VoteThresholdPercentage::YesVote(60),
+                min_community_weight_to_create_proposal: 5,
+                min_transaction_hold_up_time: 10,
+                max_voting_time: 100,
+                vote_tipping: VoteTipping::Strict,
+                proposal_cool_off_time: 0,
+                min_council_weight_to_create_proposal: 1,
+            },
+            reserved: [0; 6],
+            voting_proposal_count: 3,
+            reserved_v2: [0; 128],
+        };
+
+        governance_legacy.try_to_vec().unwrap()
+    }
+
+    #[test]
+    fn test_max_size() {
+        let governance = GovernanceV2 {
+            account_type: GovernanceAccountType::GovernanceV2,
+            realm: Pubkey::new_unique(),
+            governed_account: Pubkey::new_unique(),
+            proposals_count: 10,
+            config: GovernanceConfig {
+                vote_threshold_percentage: VoteThresholdPercentage::YesVote(60),
+                min_community_weight_to_create_proposal: 5,
+                min_transaction_hold_up_time: 10,
+                max_voting_time: 100,
+                vote_tipping: VoteTipping::Strict,
+                proposal_cool_off_time: 0,
+                min_council_weight_to_create_proposal: 1,
+                council_veto_vote_threshold_percentage: Some(VoteThresholdPercentage::YesVote(60)),
+            },
+            reserved: [0; 5],
+            voting_proposal_count: 3,
+            reserved_v2: Reserved126::default(),
+        };
+
+        let size = governance.try_to_vec().unwrap().len();
+
+        assert_eq!(governance.get_max_size(), Some(size));
+        assert_eq!(create_test_governance_legacy_data().len(), size);
+    }
+
+    #[test]
+    fn test_deserialize_governance_created_before_council_veto() {
+        // Arrange
+        let mut account_data = create_test_governance_legacy_data();
+
+        let program_id = Pubkey::new_unique();
+
+        let info_key = Pubkey::new_unique();
+        let mut lamports = 10u64;
+
+        let account_info = AccountInfo::new(
+            &info_key,
+            false,
+            false,
+            &mut lamports,
+            &mut account_data[..],
+            &program_id,
+            false,
+            Epoch::default(),
+        );
+
+        // Act
+        let governance_data = get_governance_data(&program_id, &account_info).unwrap();
+
+        // Assert
+        assert_eq!(governance_data.config.council_veto_vote_threshold_percentage, None);
+        assert_eq!(governance_data.config.min_council_weight_to_create_proposal, 1);
+        assert_eq!(governance_data.proposals_count, 10);
+        assert_eq!(governance_data.voting_proposal_count, 3);
+    }
+
+    #[test]
+    fn test_enable_council_veto_on_governance_created_before_council_veto() {
+        // Arrange
+        let mut account_data = create_test_governance_legacy_data();
+
+        let program_id = Pubkey::new_unique();
+
+        let info_key = Pubkey::new_unique();
+        let mut lamports = 10u64;
+
+        let account_info = AccountInfo::new(
+            &info_key,
+            false,
+            false,
+            &mut lamports,
+            &mut account_data[..],
+            &program_id,
+            false,
+            Epoch::default(),
+        );
+
+        let mut governance_data = get_governance_data(&program_id, &account_info).unwrap();
+
+        // Act
+        governance_data.config.council_veto_vote_threshold_percentage =
+            Some(VoteThresholdPercentage::YesVote(60));
+
+        governance_data
+            .serialize(&mut &mut **account_info.data.borrow_mut())
+            .unwrap();
+
+        // Assert
+        let governance_data = get_governance_data(&program_id, &account_info).unwrap();
+
+        assert_eq!(
+            governance_data.config.council_veto_vote_threshold_percentage,
+            Some(VoteThresholdPercentage::YesVote(60))
+        );
+        assert_eq!(governance_data.voting_proposal_count, 3);
+    }
+}
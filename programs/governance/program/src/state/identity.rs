@@ -1,9 +1,55 @@
 //! Identity account
 
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use solana_program::clock::UnixTimestamp;
+use solana_program::hash::hash;
 use solana_program::pubkey::Pubkey;
 
+/// Status of a single identity check, mirrors the identity verification program
+#[derive(Clone, Copy, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub enum IdentityStatus {
+    /// Record has been created, but the status has not been updated yet
+    Initial,
+
+    /// The vendors have started to process the identity of the subject
+    Started,
+
+    /// The subject has passed the check
+    Approved,
+
+    /// The subject has failed the check
+    Denied,
+}
+
+/// Investor class flags, mirrors the identity verification program
+pub mod investor_class {
+    /// Accredited investor (Reg D)
+    pub const ACCREDITED: u8 = 1 << 0;
+
+    /// Qualified purchaser
+    pub const QUALIFIED_PURCHASER: u8 = 1 << 1;
+
+    /// Non-US person (Reg S)
+    pub const NON_US_PERSON: u8 = 1 << 2;
+}
+
+/// Identity check flags used by realm identity policies
+pub mod identity_check {
+    /// Anti money laundering
+    pub const AML: u8 = 1 << 0;
+
+    /// Know your customer
+    pub const KYC: u8 = 1 << 1;
+
+    /// Investor accreditation
+    pub const ACCREDITATION: u8 = 1 << 2;
+
+    /// Every known flag
+    pub const ALL: u8 = AML | KYC | ACCREDITATION;
+}
+
 /// identity for use in governance program
+/// Only the leading fields of the identity record are mirrored, the status history which follows isn't needed here
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
 pub struct Identity {
 
@@ -13,15 +59,160 @@ pub struct Identity {
     /// bump seed used in deriving the pda for the status account
     pub bump: u8,
 
-    /// Accreditation status of the user (0 == initial, 1 == started, 2 == approved, 3 == rejected).
-    pub ia_status: u8,
+    /// Accreditation status of the user
+    pub ia_status: IdentityStatus,
 
-    /// AML status of the user (0 == initial, 1 == started, 2 == approved, 3 == rejected).
-    pub aml_status: u8,
+    /// AML status of the user
+    pub aml_status: IdentityStatus,
 
-    /// KYC status of the user (0 == initial, 1 == started, 2 == approved, 3 == rejected).
-    pub kyc_status: u8,
+    /// KYC status of the user
+    pub kyc_status: IdentityStatus,
 
-    /// Account who has update authority over the account
+    /// Account who manages the record, statuses are updated by the verifiers of the group
     pub authority: Pubkey,
-}
\ No newline at end of file
+
+    /// Unix timestamp the accreditation status was approved at
+    pub ia_approved_at: UnixTimestamp,
+
+    /// Unix timestamp the accreditation approval lapses at
+    pub ia_expires_at: UnixTimestamp,
+
+    /// Unix timestamp the aml status was approved at
+    pub aml_approved_at: UnixTimestamp,
+
+    /// Unix timestamp the aml approval lapses at
+    pub aml_expires_at: UnixTimestamp,
+
+    /// Unix timestamp the kyc status was approved at
+    pub kyc_approved_at: UnixTimestamp,
+
+    /// Unix timestamp the kyc approval lapses at
+    pub kyc_expires_at: UnixTimestamp,
+
+    /// ISO 3166-1 alpha-2 country code of the user ([0, 0] when unknown)
+    pub jurisdiction: [u8; 2],
+
+    /// Bitmask of investor_class flags the user qualifies for
+    pub investor_class: u8,
+
+    /// Issue timestamp of the last imported attestation
+    pub attested_at: UnixTimestamp,
+}
+
+impl Identity {
+    /// Returns the status and the approval expiry of the given identity_check flag
+    pub fn get_check_status(&self, check: u8) -> (IdentityStatus, UnixTimestamp) {
+        match check {
+            identity_check::AML => (self.aml_status, self.aml_expires_at),
+            identity_check::KYC => (self.kyc_status, self.kyc_expires_at),
+            _ => (self.ia_status, self.ia_expires_at),
+        }
+    }
+
+    /// Returns true when the given identity_check is approved and the approval has not lapsed
+    pub fn is_check_verified(&self, check: u8, now: UnixTimestamp) -> bool {
+        let (status, expires_at) = self.get_check_status(check);
+        is_approved(status, expires_at, now)
+    }
+
+    /// Returns true when every check is approved and none of the approvals has lapsed
+    pub fn is_verified(&self, now: UnixTimestamp) -> bool {
+        is_approved(self.ia_status, self.ia_expires_at, now)
+            && is_approved(self.aml_status, self.aml_expires_at, now)
+            && is_approved(self.kyc_status, self.kyc_expires_at, now)
+    }
+}
+
+/// Returns the anchor discriminator of identity records
+pub fn get_identity_record_discriminator() -> [u8; 8] {
+    let mut discriminator = [0; 8];
+    discriminator.copy_from_slice(&hash(b"account:IdentityRecord").to_bytes()[..8]);
+    discriminator
+}
+
+fn is_approved(status: IdentityStatus, expires_at: UnixTimestamp, now: UnixTimestamp) -> bool {
+    status == IdentityStatus::Approved && now < expires_at
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn create_test_identity(status: IdentityStatus, expires_at: UnixTimestamp) -> Identity {
+        Identity {
+            discriminator: [0; 8],
+            bump: 255,
+            ia_status: status,
+            aml_status: status,
+            kyc_status: status,
+            authority: Pubkey::new_unique(),
+            ia_approved_at: 0,
+            ia_expires_at: expires_at,
+            aml_approved_at: 0,
+            aml_expires_at: expires_at,
+            kyc_approved_at: 0,
+            kyc_expires_at: expires_at,
+            jurisdiction: *b"US",
+            investor_class: 0,
+            attested_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_is_verified_before_expiry() {
+        // Arrange
+        let identity = create_test_identity(IdentityStatus::Approved, 100);
+
+        // Act
+        let is_verified = identity.is_verified(99);
+
+        // Assert
+        assert!(is_verified);
+    }
+
+    #[test]
+    fn test_is_not_verified_at_expiry() {
+        // Arrange
+        let identity = create_test_identity(IdentityStatus::Approved, 100);
+
+        // Act
+        let is_verified = identity.is_verified(100);
+
+        // Assert
+        assert!(!is_verified);
+    }
+
+    #[test]
+    fn test_is_not_verified_with_single_lapsed_check() {
+        // Arrange
+        let mut identity = create_test_identity(IdentityStatus::Approved, 100);
+        identity.kyc_expires_at = 50;
+
+        // Act
+        let is_verified = identity.is_verified(75);
+
+        // Assert
+        assert!(!is_verified);
+    }
+
+    #[test]
+    fn test_identity_record_discriminator() {
+        // Act
+        let discriminator = get_identity_record_discriminator();
+
+        // Assert
+        assert_eq!(discriminator, [168, 35, 21, 172, 113, 130, 226, 19]);
+    }
+
+    #[test]
+    fn test_is_not_verified_when_denied() {
+        // Arrange
+        let identity = create_test_identity(IdentityStatus::Denied, 100);
+
+        // Act
+        let is_verified = identity.is_verified(0);
+
+        // Assert
+        assert!(!is_verified);
+    }
+}
@@ -129,7 +129,7 @@ pub struct GovernanceV1 {
     pub config: GovernanceConfig,
 
     /// Reserved space for future versions
-    pub reserved: [u8; 6],
+    pub reserved: [u8; 5],
 
     /// The number of proposals in voting state in the Governance
     /// Note: This is field introduced in V2 but it took space from reserved
@@ -162,7 +162,11 @@ pub fn is_governance_v1_account_type(account_type: &GovernanceAccountType) -> bo
         | GovernanceAccountType::ProposalTransactionV2
         | GovernanceAccountType::VoteRecordV1
         | GovernanceAccountType::VoteRecordV2
-        | GovernanceAccountType::ProgramMetadata => false,
+        | GovernanceAccountType::ProgramMetadata
+        | GovernanceAccountType::CapitalRaise
+        | GovernanceAccountType::CapitalContributionRecord
+        | GovernanceAccountType::Distribution
+        | GovernanceAccountType::DistributionClaimRecord => false,
     }
 }
 
@@ -13,3 +13,8 @@ pub mod signatory_record;
 pub mod token_owner_record;
 pub mod vote_record;
 pub mod identity;
+pub mod permissioned_list;
+pub mod capital_raise;
+pub mod capital_contribution_record;
+pub mod distribution;
+pub mod distribution_claim_record;
\ No newline at end of file
@@ -0,0 +1,240 @@
+//! Permissioned list accounts
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use solana_program::clock::UnixTimestamp;
+use solana_program::keccak::hashv;
+use solana_program::pubkey::Pubkey;
+
+/// How membership of a list is read, mirrors the permissioned list program
+#[derive(Clone, Copy, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub enum ListType {
+    /// Members are admitted, everyone else is rejected
+    Allow,
+
+    /// Members are rejected, everyone else is admitted
+    Deny,
+}
+
+/// permissioned list for use in governance program, mirrors the permissioned list program
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct PermissionedList {
+
+    /// this is the anchor discriminator
+    pub discriminator: [u8; 8],
+
+    /// Account allowed to manage the list
+    pub authority: Pubkey,
+
+    /// Account which created the list
+    pub creator: Pubkey,
+
+    /// Identifier of the list among the lists of the creator
+    pub list_id: u64,
+
+    /// bump seed used in deriving the pda of the list
+    pub bump: u8,
+
+    /// Human readable name of the list
+    pub name: String,
+
+    /// Unix timestamp the list was created at
+    pub created_at: UnixTimestamp,
+
+    /// Number of entries currently on the list
+    pub entry_count: u64,
+
+    /// Root of a merkle tree of members
+    pub merkle_root: Option<[u8; 32]>,
+
+    /// Whether members of the list are admitted or rejected
+    pub list_type: ListType,
+}
+
+impl PermissionedList {
+    /// Returns true when the proof shows the user is a leaf of the merkle root of the list
+    pub fn is_member_by_proof(&self, user: &Pubkey, proof: &[[u8; 32]]) -> bool {
+        match self.merkle_root {
+            Some(root) => {
+                let leaf = hashv(&[&[0], user.as_ref()]).to_bytes();
+
+                let computed = proof.iter().fold(leaf, |node, sibling| {
+                    let (left, right) = if node <= *sibling { (&node, sibling) } else { (sibling, &node) };
+                    hashv(&[&[1], left, right]).to_bytes()
+                });
+
+                computed == root
+            }
+            None => false,
+        }
+    }
+}
+
+/// permissioned list entry for use in governance program, mirrors the permissioned list program
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct ListEntry {
+
+    /// this is the anchor discriminator
+    pub discriminator: [u8; 8],
+
+    /// bump seed used in deriving the pda of the entry
+    pub bump: u8,
+
+    /// List the entry belongs to
+    pub list: Pubkey,
+
+    /// User the entry was added for
+    pub user: Pubkey,
+
+    /// Unix timestamp the entry was added at
+    pub added_at: UnixTimestamp,
+
+    /// Account which added the entry
+    pub added_by: Pubkey,
+
+    /// Unix timestamp the entry lapses at, never when None
+    pub expires_at: Option<UnixTimestamp>,
+
+    /// Free form value set by the list authority
+    pub tag: u64,
+}
+
+impl ListEntry {
+    /// Returns true when the entry has not lapsed
+    pub fn is_active(&self, now: UnixTimestamp) -> bool {
+        match self.expires_at {
+            Some(expires_at) => now < expires_at,
+            None => true,
+        }
+    }
+}
+
+/// Returns permissioned list entry PDA seeds
+pub fn get_list_entry_address_seeds<'a>(list: &'a Pubkey, user: &'a Pubkey) -> [&'a [u8]; 2] {
+    [list.as_ref(), user.as_ref()]
+}
+
+/// Returns permissioned list entry PDA address
+pub fn get_list_entry_address(
+    permissioned_list_program_id: &Pubkey,
+    list: &Pubkey,
+    user: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &get_list_entry_address_seeds(list, user),
+        permissioned_list_program_id,
+    )
+    .0
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn leaf(user: &Pubkey) -> [u8; 32] {
+        hashv(&[&[0], user.as_ref()]).to_bytes()
+    }
+
+    fn node(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+        let (left, right) = if a <= b { (a, b) } else { (b, a) };
+        hashv(&[&[1], left, right]).to_bytes()
+    }
+
+    fn create_test_list(merkle_root: Option<[u8; 32]>) -> PermissionedList {
+        PermissionedList {
+            discriminator: [0; 8],
+            authority: Pubkey::new_unique(),
+            creator: Pubkey::new_unique(),
+            list_id: 0,
+            bump: 255,
+            name: "test-list".to_string(),
+            created_at: 100,
+            entry_count: 0,
+            merkle_root,
+            list_type: ListType::Allow,
+        }
+    }
+
+    #[test]
+    fn test_is_member_by_proof() {
+        // Arrange
+        let users: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let leaves: Vec<[u8; 32]> = users.iter().map(leaf).collect();
+        let left = node(&leaves[0], &leaves[1]);
+        let right = node(&leaves[2], &leaves[3]);
+        let list = create_test_list(Some(node(&left, &right)));
+
+        // Act
+        let is_member = list.is_member_by_proof(&users[2], &[leaves[3], left]);
+
+        // Assert
+        assert!(is_member);
+    }
+
+    #[test]
+    fn test_is_member_by_proof_with_wrong_proof_error() {
+        // Arrange
+        let users: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let leaves: Vec<[u8; 32]> = users.iter().map(leaf).collect();
+        let left = node(&leaves[0], &leaves[1]);
+        let right = node(&leaves[2], &leaves[3]);
+        let list = create_test_list(Some(node(&left, &right)));
+
+        // Act
+        let is_member = list.is_member_by_proof(&Pubkey::new_unique(), &[leaves[3], left]);
+
+        // Assert
+        assert!(!is_member);
+    }
+
+    #[test]
+    fn test_is_member_by_proof_without_root_error() {
+        // Arrange
+        let user = Pubkey::new_unique();
+        let list = create_test_list(None);
+
+        // Act
+        let is_member = list.is_member_by_proof(&user, &[]);
+
+        // Assert
+        assert!(!is_member);
+    }
+
+    fn create_test_entry(expires_at: Option<UnixTimestamp>) -> ListEntry {
+        ListEntry {
+            discriminator: [0; 8],
+            bump: 255,
+            list: Pubkey::new_unique(),
+            user: Pubkey::new_unique(),
+            added_at: 100,
+            added_by: Pubkey::new_unique(),
+            expires_at,
+            tag: 0,
+        }
+    }
+
+    #[test]
+    fn test_entry_without_expiry_is_active() {
+        // Arrange
+        let entry = create_test_entry(None);
+
+        // Act
+        let is_active = entry.is_active(i64::MAX);
+
+        // Assert
+        assert!(is_active);
+    }
+
+    #[test]
+    fn test_entry_is_inactive_at_expiry() {
+        // Arrange
+        let entry = create_test_entry(Some(200));
+
+        // Act
+        let is_active_before = entry.is_active(199);
+        let is_active_at = entry.is_active(200);
+
+        // Assert
+        assert!(is_active_before);
+        assert!(!is_active_at);
+    }
+}
@@ -139,12 +139,11 @@ pub struct ProposalV2 {
     /// Without the deny option a proposal is only non executable survey
     pub deny_vote_weight: Option<u64>,
 
-    /// The total weight of Veto votes
-    /// Note: Veto is not supported in the current version
+    /// The total weight of council Veto votes cast on a community Proposal
     pub veto_vote_weight: Option<u64>,
 
-    /// The total weight of  votes
-    /// Note: Abstain is not supported in the current version
+    /// The total weight of Abstain votes
+    /// Abstain votes count towards quorum but not towards the yes/deny ratio
     pub abstain_vote_weight: Option<u64>,
 
     /// Optional start time if the Proposal should not enter voting state immediately after being signed off
@@ -232,7 +231,8 @@ impl ProposalV2 {
             | ProposalState::Cancelled
             | ProposalState::Voting
             | ProposalState::Succeeded
-            | ProposalState::Defeated => Err(GovernanceError::InvalidStateCannotSignOff.into()),
+            | ProposalState::Defeated
+            | ProposalState::Vetoed => Err(GovernanceError::InvalidStateCannotSignOff.into()),
         }
     }
 
@@ -271,6 +271,36 @@ impl ProposalV2 {
         Ok(())
     }
 
+    /// Checks if council Veto vote can be cast for the Proposal
+    /// Veto can be cast while the Proposal is being voted on or within the cool off time once it succeeded
+    pub fn assert_can_cast_veto_vote(
+        &self,
+        config: &GovernanceConfig,
+        current_unix_timestamp: UnixTimestamp,
+    ) -> Result<(), ProgramError> {
+        if config.council_veto_vote_threshold_percentage.is_none() {
+            return Err(GovernanceError::CouncilVetoNotEnabled.into());
+        }
+
+        match self.state {
+            ProposalState::Voting => self.assert_can_cast_vote(config, current_unix_timestamp),
+            ProposalState::Succeeded => {
+                if !self.is_in_cool_off_time(config, current_unix_timestamp) {
+                    return Err(GovernanceError::InvalidStateCannotVote.into());
+                }
+                Ok(())
+            }
+            ProposalState::Draft
+            | ProposalState::SigningOff
+            | ProposalState::Executing
+            | ProposalState::ExecutingWithErrors
+            | ProposalState::Completed
+            | ProposalState::Cancelled
+            | ProposalState::Defeated
+            | ProposalState::Vetoed => Err(GovernanceError::InvalidStateCannotVote.into()),
+        }
+    }
+
     /// Checks whether the voting time has ended for the proposal
     pub fn has_vote_time_ended(
         &self,
@@ -330,11 +360,19 @@ impl ProposalV2 {
         max_vote_weight: u64,
         config: &GovernanceConfig,
     ) -> Result<ProposalState, ProgramError> {
-        // Get the min vote weight required for options to pass
+        // Get the min vote weight required for options to pass or for the vote to reach quorum
         let min_vote_threshold_weight =
             get_min_vote_threshold_weight(&config.vote_threshold_percentage, max_vote_weight)
                 .unwrap();
 
+        // With Quorum threshold the min weight applies to the vote turnout and options only need a simple majority
+        let (min_option_vote_weight, is_quorum_reached) = match config.vote_threshold_percentage {
+            VoteThresholdPercentage::YesVote(_) => (min_vote_threshold_weight, true),
+            VoteThresholdPercentage::Quorum(_) => {
+                (0, self.get_total_vote_weight() >= min_vote_threshold_weight)
+            }
+        };
+
         // If the proposal has a reject option then any other option must beat it regardless of the configured min_vote_threshold_weight
         let deny_vote_weight = self.deny_vote_weight.unwrap_or(0);
 
@@ -345,7 +383,8 @@ impl ProposalV2 {
             // Any positive vote (Yes) must be equal or above the required min_vote_threshold_weight and higher than the reject option vote (No)
             // The same number of positive (Yes) and rejecting (No) votes is a tie and resolved as Defeated
             // In other words  +1 vote as a tie breaker is required to succeed for the positive option vote
-            if option.vote_weight >= min_vote_threshold_weight
+            if is_quorum_reached
+                && option.vote_weight >= min_option_vote_weight
                 && option.vote_weight > deny_vote_weight
             {
                 option.vote_result = OptionVoteResult::Succeeded;
@@ -437,25 +476,61 @@ impl ProposalV2 {
                 // and we have to adjust it in case more votes have been cast
                 Ok(self.coerce_max_voter_weight(max_voter_weight))
             }
-            MintMaxVoteWeightSource::Absolute(_) => {
-                Err(GovernanceError::VoteWeightSourceNotSupported.into())
+            MintMaxVoteWeightSource::Absolute(value) => {
+                // The absolute value can be lower than the deposited governing tokens
+                // and we have to adjust it in case more votes have been cast
+                Ok(self.coerce_max_voter_weight(value))
             }
         }
     }
 
+    /// Returns the total weight of cast votes, including Abstain votes, used to check the vote turnout
+    /// For MultiChoice proposals a voter can approve several options and the weight of the most voted option is used
+    /// which makes it the lowest possible turnout
+    fn get_total_vote_weight(&self) -> u64 {
+        let options_vote_weight = match self.vote_type {
+            VoteType::SingleChoice => self
+                .options
+                .iter()
+                .fold(0u64, |weight, o| weight.saturating_add(o.vote_weight)),
+            VoteType::MultiChoice {
+                max_voter_options: _n,
+                max_winning_options: _m,
+            } => self.options.iter().map(|o| o.vote_weight).max().unwrap_or(0),
+        };
+
+        options_vote_weight
+            .saturating_add(self.deny_vote_weight.unwrap_or(0))
+            .saturating_add(self.abstain_vote_weight.unwrap_or(0))
+    }
+
     /// Adjusts max voter weight to ensure it's not lower than total cast votes
     fn coerce_max_voter_weight(&self, max_voter_weight: u64) -> u64 {
         let deny_vote_weight = self.deny_vote_weight.unwrap_or(0);
+        let abstain_vote_weight = self.abstain_vote_weight.unwrap_or(0);
 
         let max_option_vote_weight = self.options.iter().map(|o| o.vote_weight).max().unwrap();
 
         let total_vote_weight = max_option_vote_weight
             .checked_add(deny_vote_weight)
+            .unwrap()
+            .checked_add(abstain_vote_weight)
             .unwrap();
 
         max_voter_weight.max(total_vote_weight)
     }
 
+    /// Resolves max voter weight for council Veto votes
+    /// The council mint supply is used as max voter weight
+    pub fn resolve_max_veto_voter_weight(
+        &self,
+        council_mint_info: &AccountInfo,
+    ) -> Result<u64, ProgramError> {
+        let council_mint_supply = get_spl_token_mint_supply(council_mint_info)?;
+
+        Ok(council_mint_supply.max(self.veto_vote_weight.unwrap()))
+    }
+
     /// Resolves max voter weight
     pub fn resolve_max_voter_weight(
         &mut self,
@@ -544,6 +619,12 @@ impl ProposalV2 {
             return None;
         };
 
+        // With Quorum threshold the min weight applies to the vote turnout and yes votes only need a simple majority
+        let (threshold_vote_weight, is_quorum) = match config.vote_threshold_percentage {
+            VoteThresholdPercentage::YesVote(_) => (self.options[0].vote_weight, false),
+            VoteThresholdPercentage::Quorum(_) => (self.get_total_vote_weight(), true),
+        };
+
         let mut yes_option = &mut self.options[0];
 
         let yes_vote_weight = yes_option.vote_weight;
@@ -574,7 +655,7 @@ impl ProposalV2 {
                 }
             }
             VoteTipping::Early => {
-                if yes_vote_weight >= min_vote_threshold_weight
+                if threshold_vote_weight >= min_vote_threshold_weight
                     && yes_vote_weight > deny_vote_weight
                 {
                     yes_option.vote_result = OptionVoteResult::Succeeded;
@@ -587,8 +668,10 @@ impl ProposalV2 {
         // "defeated" if there is no possible way of reaching majority or the
         // min_vote_threshold_weight for another option. This tipping is always
         // strict, there's no equivalent to "early" tipping for deny votes.
+        // Deny votes count towards quorum and can't prevent the yes option from reaching it
         if config.vote_tipping != VoteTipping::Disabled
-            && (deny_vote_weight > (max_vote_weight.saturating_sub(min_vote_threshold_weight))
+            && ((!is_quorum
+                && deny_vote_weight > (max_vote_weight.saturating_sub(min_vote_threshold_weight)))
                 || deny_vote_weight >= (max_vote_weight.saturating_sub(deny_vote_weight)))
         {
             yes_option.vote_result = OptionVoteResult::Defeated;
@@ -598,7 +681,64 @@ impl ProposalV2 {
         None
     }
 
+    /// Checks if the Proposal was voted on and is still within the configured proposal_cool_off_time
+    pub fn is_in_cool_off_time(
+        &self,
+        config: &GovernanceConfig,
+        current_unix_timestamp: UnixTimestamp,
+    ) -> bool {
+        match self.voting_completed_at {
+            Some(voting_completed_at) => {
+                voting_completed_at
+                    .checked_add(config.proposal_cool_off_time as i64)
+                    .unwrap()
+                    >= current_unix_timestamp
+                    && config.proposal_cool_off_time > 0
+            }
+            None => false,
+        }
+    }
+
+    /// Checks if the Proposal is vetoed by the council
+    /// Veto is decided as soon as the veto votes reach the configured council_veto_vote_threshold_percentage
+    /// and doesn't depend on the configured vote_tipping
+    /// Returns true if the Proposal was vetoed
+    pub fn try_tip_veto_vote(
+        &mut self,
+        max_veto_voter_weight: u64,
+        config: &GovernanceConfig,
+        current_unix_timestamp: UnixTimestamp,
+    ) -> Result<bool, ProgramError> {
+        let council_veto_vote_threshold_percentage = config
+            .council_veto_vote_threshold_percentage
+            .as_ref()
+            .ok_or(GovernanceError::CouncilVetoNotEnabled)?;
+
+        let min_veto_vote_threshold_weight = get_min_vote_threshold_weight(
+            council_veto_vote_threshold_percentage,
+            max_veto_voter_weight,
+        )?;
+
+        if self.veto_vote_weight.unwrap() < min_veto_vote_threshold_weight {
+            return Ok(false);
+        }
+
+        // Vetoed Proposal within cool off time keeps the time its vote was completed at
+        if self.state == ProposalState::Voting {
+            self.voting_completed_at = Some(current_unix_timestamp);
+        }
+
+        for option in self.options.iter_mut() {
+            option.vote_result = OptionVoteResult::Defeated;
+        }
+
+        self.state = ProposalState::Vetoed;
+
+        Ok(true)
+    }
+
     /// Checks if Proposal can be canceled in the given state
+    /// Succeeded Proposal can be canceled only within the configured proposal_cool_off_time
     pub fn assert_can_cancel(
         &self,
         config: &GovernanceConfig,
@@ -614,12 +754,18 @@ impl ProposalV2 {
                 }
                 Ok(())
             }
+            ProposalState::Succeeded => {
+                if !self.is_in_cool_off_time(config, current_unix_timestamp) {
+                    return Err(GovernanceError::InvalidStateCannotCancelProposal.into());
+                }
+                Ok(())
+            }
             ProposalState::Executing
             | ProposalState::ExecutingWithErrors
             | ProposalState::Completed
             | ProposalState::Cancelled
-            | ProposalState::Succeeded
-            | ProposalState::Defeated => {
+            | ProposalState::Defeated
+            | ProposalState::Vetoed => {
                 Err(GovernanceError::InvalidStateCannotCancelProposal.into())
             }
         }
@@ -643,6 +789,7 @@ impl ProposalV2 {
     /// Checks if Instructions can be executed for the Proposal in the given state
     pub fn assert_can_execute_transaction(
         &self,
+        config: &GovernanceConfig,
         proposal_transaction_data: &ProposalTransactionV2,
         current_unix_timestamp: UnixTimestamp,
     ) -> Result<(), ProgramError> {
@@ -655,7 +802,8 @@ impl ProposalV2 {
             | ProposalState::Completed
             | ProposalState::Voting
             | ProposalState::Cancelled
-            | ProposalState::Defeated => {
+            | ProposalState::Defeated
+            | ProposalState::Vetoed => {
                 return Err(GovernanceError::InvalidStateCannotExecuteTransaction.into())
             }
         }
@@ -666,6 +814,10 @@ impl ProposalV2 {
             return Err(GovernanceError::CannotExecuteDefeatedOption.into());
         }
 
+        if self.is_in_cool_off_time(config, current_unix_timestamp) {
+            return Err(GovernanceError::CannotExecuteTransactionWithinCoolOffTime.into());
+        }
+
         if self
             .voting_completed_at
             .unwrap()
@@ -686,11 +838,16 @@ impl ProposalV2 {
     /// Checks if the instruction can be flagged with error for the Proposal in the given state
     pub fn assert_can_flag_transaction_error(
         &self,
+        config: &GovernanceConfig,
         proposal_transaction_data: &ProposalTransactionV2,
         current_unix_timestamp: UnixTimestamp,
     ) -> Result<(), ProgramError> {
         // Instruction can be flagged for error only when it's eligible for execution
-        self.assert_can_execute_transaction(proposal_transaction_data, current_unix_timestamp)?;
+        self.assert_can_execute_transaction(
+            config,
+            proposal_transaction_data,
+            current_unix_timestamp,
+        )?;
 
         if proposal_transaction_data.execution_status == TransactionExecutionStatus::Error {
             return Err(GovernanceError::TransactionAlreadyFlaggedWithError.into());
@@ -742,8 +899,15 @@ impl ProposalV2 {
                     return Err(GovernanceError::InvalidVote.into());
                 }
             }
-            Vote::Abstain | Vote::Veto => {
-                return Err(GovernanceError::NotSupportedVoteType.into());
+            Vote::Veto => {
+                if self.veto_vote_weight.is_none() {
+                    return Err(GovernanceError::InvalidVote.into());
+                }
+            }
+            Vote::Abstain => {
+                if self.abstain_vote_weight.is_none() {
+                    return Err(GovernanceError::InvalidVote.into());
+                }
             }
         }
 
@@ -813,6 +977,7 @@ impl ProposalV2 {
 
 /// Converts threshold in percentages to actual vote weight
 /// and returns the min weight required for a proposal option to pass
+/// For Quorum threshold it's the min weight of all cast votes required for the vote to be valid
 fn get_min_vote_threshold_weight(
     vote_threshold_percentage: &VoteThresholdPercentage,
     max_vote_weight: u64,
@@ -821,9 +986,7 @@ fn get_min_vote_threshold_weight(
         VoteThresholdPercentage::YesVote(yes_vote_threshold_percentage) => {
             *yes_vote_threshold_percentage
         }
-        _ => {
-            return Err(GovernanceError::VoteThresholdPercentageTypeNotSupported.into());
-        }
+        VoteThresholdPercentage::Quorum(quorum_percentage) => *quorum_percentage,
     };
 
     let numerator = (yes_vote_threshold_percentage as u128)
@@ -860,7 +1023,7 @@ pub fn get_proposal_data(
             | ProposalState::Executing
             | ProposalState::ExecutingWithErrors
             | ProposalState::Completed => OptionVoteResult::Succeeded,
-            ProposalState::Defeated => OptionVoteResult::None,
+            ProposalState::Defeated | ProposalState::Vetoed => OptionVoteResult::None,
         };
 
         return Ok(ProposalV2 {
@@ -1097,9 +1260,11 @@ mod test {
             name: "test-realm".to_string(),
             config: RealmConfig {
                 council_mint: Some(Pubkey::new_unique()),
-                reserved: [0; 6],
+                reserved: [0; 4],
                 use_community_voter_weight_addin: false,
                 use_max_community_voter_weight_addin: false,
+                use_permissioned_list: false,
+                use_identity_verification: false,
 
                 community_mint_max_vote_weight_source:
                     MintMaxVoteWeightSource::FULL_SUPPLY_FRACTION,
@@ -1119,6 +1284,7 @@ mod test {
             vote_threshold_percentage: VoteThresholdPercentage::YesVote(60),
             vote_tipping: VoteTipping::Strict,
             proposal_cool_off_time: 0,
+            council_veto_vote_threshold_percentage: Some(VoteThresholdPercentage::YesVote(60)),
         }
     }
 
@@ -1182,6 +1348,7 @@ mod test {
             Just(ProposalState::Completed),
             Just(ProposalState::Cancelled),
             Just(ProposalState::Defeated),
+            Just(ProposalState::Vetoed),
             Just(ProposalState::SigningOff),
         ]
     }
@@ -1223,6 +1390,7 @@ mod test {
             Just(ProposalState::Completed),
             Just(ProposalState::Cancelled),
             Just(ProposalState::Defeated),
+            Just(ProposalState::Vetoed),
         ]
     }
 
@@ -1275,6 +1443,7 @@ mod test {
             Just(ProposalState::Completed),
             Just(ProposalState::Cancelled),
             Just(ProposalState::Defeated),
+            Just(ProposalState::Vetoed),
         ]
     }
 
@@ -1857,6 +2026,71 @@ mod test {
         assert_eq!(proposal.max_vote_weight, Some(100));
     }
 
+    #[test]
+    fn test_finalize_vote_with_absolute_community_mint_max_vote_weight() {
+        // Arrange
+        let mut proposal = create_test_proposal();
+
+        proposal.options[0].vote_weight = 60;
+        proposal.deny_vote_weight = Some(10);
+
+        proposal.state = ProposalState::Voting;
+
+        let mut governance_config = create_test_governance_config();
+        governance_config.vote_threshold_percentage = VoteThresholdPercentage::YesVote(60);
+
+        let current_timestamp = 16_i64;
+        let community_token_supply = 1_000;
+
+        let mut realm = create_test_realm();
+        realm.config.community_mint_max_vote_weight_source = MintMaxVoteWeightSource::Absolute(100);
+
+        let max_voter_weight = proposal
+            .get_max_voter_weight_from_mint_supply(&realm, community_token_supply)
+            .unwrap();
+
+        // Act
+        proposal
+            .finalize_vote(max_voter_weight, &governance_config, current_timestamp)
+            .unwrap();
+
+        // Assert
+        assert_eq!(proposal.state, ProposalState::Succeeded);
+        assert_eq!(proposal.max_vote_weight, Some(100));
+    }
+
+    #[test]
+    fn test_try_tip_vote_with_absolute_community_mint_max_vote_weight_and_vote_overflow() {
+        // Arrange
+        let mut proposal = create_test_proposal();
+
+        proposal.options[0].vote_weight = 120;
+        proposal.deny_vote_weight = Some(10);
+
+        proposal.state = ProposalState::Voting;
+
+        let governance_config = create_test_governance_config();
+
+        let current_timestamp = 15_i64;
+        let community_token_supply = 1_000;
+
+        let mut realm = create_test_realm();
+        realm.config.community_mint_max_vote_weight_source = MintMaxVoteWeightSource::Absolute(100);
+
+        let max_voter_weight = proposal
+            .get_max_voter_weight_from_mint_supply(&realm, community_token_supply)
+            .unwrap();
+
+        // Act
+        proposal
+            .try_tip_vote(max_voter_weight, &governance_config, current_timestamp)
+            .unwrap();
+
+        // Assert
+        assert_eq!(proposal.state, ProposalState::Succeeded);
+        assert_eq!(proposal.max_vote_weight, Some(130));
+    }
+
     #[test]
     fn test_finalize_vote_with_reduced_community_mint_max_vote_weight_and_vote_overflow() {
         // Arrange
@@ -2271,4 +2505,480 @@ mod test {
 
         assert_eq!(proposal_v1_source, proposal_v1_target)
     }
+
+    fn create_test_quorum_governance_config() -> GovernanceConfig {
+        let mut governance_config = create_test_governance_config();
+        governance_config.vote_threshold_percentage = VoteThresholdPercentage::Quorum(20);
+
+        governance_config
+    }
+
+    #[test]
+    fn test_finalize_vote_with_quorum_reached_and_majority() {
+        // Arrange
+        let mut proposal = create_test_proposal();
+
+        proposal.options[0].vote_weight = 15;
+        proposal.deny_vote_weight = Some(10);
+
+        proposal.state = ProposalState::Voting;
+
+        let governance_config = create_test_quorum_governance_config();
+
+        let realm = create_test_realm();
+        let max_voter_weight = proposal
+            .get_max_voter_weight_from_mint_supply(&realm, 100)
+            .unwrap();
+
+        // Act
+        proposal
+            .finalize_vote(max_voter_weight, &governance_config, 16)
+            .unwrap();
+
+        // Assert
+        assert_eq!(proposal.state, ProposalState::Succeeded);
+        assert_eq!(proposal.options[0].vote_result, OptionVoteResult::Succeeded);
+    }
+
+    #[test]
+    fn test_finalize_vote_with_quorum_not_reached() {
+        // Arrange
+        let mut proposal = create_test_proposal();
+
+        proposal.options[0].vote_weight = 15;
+        proposal.deny_vote_weight = Some(4);
+
+        proposal.state = ProposalState::Voting;
+
+        let governance_config = create_test_quorum_governance_config();
+
+        let realm = create_test_realm();
+        let max_voter_weight = proposal
+            .get_max_voter_weight_from_mint_supply(&realm, 100)
+            .unwrap();
+
+        // Act
+        proposal
+            .finalize_vote(max_voter_weight, &governance_config, 16)
+            .unwrap();
+
+        // Assert
+        assert_eq!(proposal.state, ProposalState::Defeated);
+        assert_eq!(proposal.options[0].vote_result, OptionVoteResult::Defeated);
+    }
+
+    #[test]
+    fn test_finalize_vote_with_quorum_reached_and_no_majority() {
+        // Arrange
+        let mut proposal = create_test_proposal();
+
+        proposal.options[0].vote_weight = 15;
+        proposal.deny_vote_weight = Some(15);
+
+        proposal.state = ProposalState::Voting;
+
+        let governance_config = create_test_quorum_governance_config();
+
+        let realm = create_test_realm();
+        let max_voter_weight = proposal
+            .get_max_voter_weight_from_mint_supply(&realm, 100)
+            .unwrap();
+
+        // Act
+        proposal
+            .finalize_vote(max_voter_weight, &governance_config, 16)
+            .unwrap();
+
+        // Assert
+        assert_eq!(proposal.state, ProposalState::Defeated);
+    }
+
+    #[test]
+    fn test_finalize_multi_choice_vote_with_quorum() {
+        // Arrange
+        let mut proposal = create_test_multi_option_proposal();
+        proposal.vote_type = VoteType::MultiChoice {
+            max_voter_options: 3,
+            max_winning_options: 3,
+        };
+        proposal.deny_vote_weight = None;
+
+        proposal.options[0].vote_weight = 20;
+        proposal.options[1].vote_weight = 10;
+        proposal.options[2].vote_weight = 0;
+
+        proposal.state = ProposalState::Voting;
+
+        let governance_config = create_test_quorum_governance_config();
+
+        let realm = create_test_realm();
+        let max_voter_weight = proposal
+            .get_max_voter_weight_from_mint_supply(&realm, 100)
+            .unwrap();
+
+        // Act
+        proposal
+            .finalize_vote(max_voter_weight, &governance_config, 16)
+            .unwrap();
+
+        // Assert
+        assert_eq!(proposal.state, ProposalState::Completed);
+        assert_eq!(proposal.options[0].vote_result, OptionVoteResult::Succeeded);
+        assert_eq!(proposal.options[1].vote_result, OptionVoteResult::Succeeded);
+        assert_eq!(proposal.options[2].vote_result, OptionVoteResult::Defeated);
+    }
+
+    #[test]
+    fn test_try_tip_vote_with_quorum_and_early_tipping() {
+        // Arrange
+        let mut proposal = create_test_proposal();
+
+        proposal.options[0].vote_weight = 15;
+        proposal.deny_vote_weight = Some(10);
+
+        proposal.state = ProposalState::Voting;
+
+        let mut governance_config = create_test_quorum_governance_config();
+        governance_config.vote_tipping = VoteTipping::Early;
+
+        let realm = create_test_realm();
+        let max_voter_weight = proposal
+            .get_max_voter_weight_from_mint_supply(&realm, 100)
+            .unwrap();
+
+        // Act
+        proposal
+            .try_tip_vote(max_voter_weight, &governance_config, 15)
+            .unwrap();
+
+        // Assert
+        assert_eq!(proposal.state, ProposalState::Succeeded);
+    }
+
+    #[test]
+    fn test_try_tip_vote_with_quorum_and_strict_tipping() {
+        // Arrange
+        let mut proposal = create_test_proposal();
+
+        proposal.options[0].vote_weight = 15;
+        proposal.deny_vote_weight = Some(10);
+
+        proposal.state = ProposalState::Voting;
+
+        let governance_config = create_test_quorum_governance_config();
+
+        let realm = create_test_realm();
+        let max_voter_weight = proposal
+            .get_max_voter_weight_from_mint_supply(&realm, 100)
+            .unwrap();
+
+        // Act
+        proposal
+            .try_tip_vote(max_voter_weight, &governance_config, 15)
+            .unwrap();
+
+        // Assert
+        assert_eq!(proposal.state, ProposalState::Voting);
+    }
+
+    #[test]
+    fn test_try_tip_vote_with_quorum_and_deny_majority() {
+        // Arrange
+        let mut proposal = create_test_proposal();
+
+        proposal.options[0].vote_weight = 0;
+        proposal.deny_vote_weight = Some(50);
+
+        proposal.state = ProposalState::Voting;
+
+        let governance_config = create_test_quorum_governance_config();
+
+        let realm = create_test_realm();
+        let max_voter_weight = proposal
+            .get_max_voter_weight_from_mint_supply(&realm, 100)
+            .unwrap();
+
+        // Act
+        proposal
+            .try_tip_vote(max_voter_weight, &governance_config, 15)
+            .unwrap();
+
+        // Assert
+        assert_eq!(proposal.state, ProposalState::Defeated);
+    }
+
+    fn create_test_proposal_transaction() -> ProposalTransactionV2 {
+        ProposalTransactionV2 {
+            account_type: GovernanceAccountType::ProposalTransactionV2,
+            proposal: Pubkey::new_unique(),
+            option_index: 0,
+            transaction_index: 0,
+            hold_up_time: 0,
+            instructions: vec![],
+            executed_at: None,
+            execution_status: TransactionExecutionStatus::None,
+            reserved_v2: [0; 8],
+        }
+    }
+
+    #[test]
+    fn test_assert_can_cancel_succeeded_proposal_within_cool_off_time() {
+        // Arrange
+        let mut proposal = create_test_proposal();
+        proposal.state = ProposalState::Succeeded;
+        proposal.voting_completed_at = Some(20);
+
+        let mut governance_config = create_test_governance_config();
+        governance_config.proposal_cool_off_time = 10;
+
+        // Act
+        let result = proposal.assert_can_cancel(&governance_config, 30);
+
+        // Assert
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn test_assert_can_cancel_succeeded_proposal_after_cool_off_time_error() {
+        // Arrange
+        let mut proposal = create_test_proposal();
+        proposal.state = ProposalState::Succeeded;
+        proposal.voting_completed_at = Some(20);
+
+        let mut governance_config = create_test_governance_config();
+        governance_config.proposal_cool_off_time = 10;
+
+        // Act
+        let err = proposal
+            .assert_can_cancel(&governance_config, 31)
+            .err()
+            .unwrap();
+
+        // Assert
+        assert_eq!(err, GovernanceError::InvalidStateCannotCancelProposal.into());
+    }
+
+    #[test]
+    fn test_assert_can_execute_transaction_within_cool_off_time_error() {
+        // Arrange
+        let mut proposal = create_test_proposal();
+        proposal.state = ProposalState::Succeeded;
+        proposal.voting_completed_at = Some(20);
+        proposal.options[0].vote_result = OptionVoteResult::Succeeded;
+
+        let mut governance_config = create_test_governance_config();
+        governance_config.proposal_cool_off_time = 10;
+
+        let proposal_transaction = create_test_proposal_transaction();
+
+        // Act
+        let err = proposal
+            .assert_can_execute_transaction(&governance_config, &proposal_transaction, 30)
+            .err()
+            .unwrap();
+
+        // Assert
+        assert_eq!(
+            err,
+            GovernanceError::CannotExecuteTransactionWithinCoolOffTime.into()
+        );
+    }
+
+    #[test]
+    fn test_assert_can_execute_transaction_after_cool_off_time() {
+        // Arrange
+        let mut proposal = create_test_proposal();
+        proposal.state = ProposalState::Succeeded;
+        proposal.voting_completed_at = Some(20);
+        proposal.options[0].vote_result = OptionVoteResult::Succeeded;
+
+        let mut governance_config = create_test_governance_config();
+        governance_config.proposal_cool_off_time = 10;
+
+        let proposal_transaction = create_test_proposal_transaction();
+
+        // Act
+        let result =
+            proposal.assert_can_execute_transaction(&governance_config, &proposal_transaction, 31);
+
+        // Assert
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn test_try_tip_veto_vote() {
+        // Arrange
+        let mut proposal = create_test_proposal();
+        proposal.state = ProposalState::Voting;
+        proposal.voting_completed_at = None;
+        proposal.options[0].vote_weight = 80;
+        proposal.veto_vote_weight = Some(6);
+
+        let governance_config = create_test_governance_config();
+
+        // Act
+        let is_vetoed = proposal
+            .try_tip_veto_vote(10, &governance_config, 15)
+            .unwrap();
+
+        // Assert
+        assert!(is_vetoed);
+        assert_eq!(proposal.state, ProposalState::Vetoed);
+        assert_eq!(proposal.voting_completed_at, Some(15));
+        assert_eq!(proposal.options[0].vote_result, OptionVoteResult::Defeated);
+    }
+
+    #[test]
+    fn test_try_tip_veto_vote_below_threshold() {
+        // Arrange
+        let mut proposal = create_test_proposal();
+        proposal.state = ProposalState::Voting;
+        proposal.veto_vote_weight = Some(5);
+
+        let governance_config = create_test_governance_config();
+
+        // Act
+        let is_vetoed = proposal
+            .try_tip_veto_vote(10, &governance_config, 15)
+            .unwrap();
+
+        // Assert
+        assert!(!is_vetoed);
+        assert_eq!(proposal.state, ProposalState::Voting);
+    }
+
+    #[test]
+    fn test_assert_can_cast_veto_vote_within_cool_off_time() {
+        // Arrange
+        let mut proposal = create_test_proposal();
+        proposal.state = ProposalState::Succeeded;
+        proposal.voting_completed_at = Some(20);
+
+        let mut governance_config = create_test_governance_config();
+        governance_config.proposal_cool_off_time = 10;
+
+        // Act
+        let result = proposal.assert_can_cast_veto_vote(&governance_config, 30);
+
+        // Assert
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn test_assert_can_cast_veto_vote_after_cool_off_time_error() {
+        // Arrange
+        let mut proposal = create_test_proposal();
+        proposal.state = ProposalState::Succeeded;
+        proposal.voting_completed_at = Some(20);
+
+        let mut governance_config = create_test_governance_config();
+        governance_config.proposal_cool_off_time = 10;
+
+        // Act
+        let err = proposal
+            .assert_can_cast_veto_vote(&governance_config, 31)
+            .err()
+            .unwrap();
+
+        // Assert
+        assert_eq!(err, GovernanceError::InvalidStateCannotVote.into());
+    }
+
+    #[test]
+    fn test_assert_can_cast_veto_vote_with_veto_disabled_error() {
+        // Arrange
+        let mut proposal = create_test_proposal();
+        proposal.state = ProposalState::Voting;
+
+        let mut governance_config = create_test_governance_config();
+        governance_config.council_veto_vote_threshold_percentage = None;
+
+        // Act
+        let err = proposal
+            .assert_can_cast_veto_vote(&governance_config, 10)
+            .err()
+            .unwrap();
+
+        // Assert
+        assert_eq!(err, GovernanceError::CouncilVetoNotEnabled.into());
+    }
+
+    #[test]
+    fn test_finalize_vote_with_abstain_votes_counted_towards_quorum() {
+        // Arrange
+        let mut proposal = create_test_proposal();
+
+        proposal.options[0].vote_weight = 10;
+        proposal.deny_vote_weight = Some(5);
+        proposal.abstain_vote_weight = Some(10);
+
+        proposal.state = ProposalState::Voting;
+
+        let governance_config = create_test_quorum_governance_config();
+
+        let realm = create_test_realm();
+        let max_voter_weight = proposal
+            .get_max_voter_weight_from_mint_supply(&realm, 100)
+            .unwrap();
+
+        // Act
+        proposal
+            .finalize_vote(max_voter_weight, &governance_config, 16)
+            .unwrap();
+
+        // Assert
+        assert_eq!(proposal.state, ProposalState::Succeeded);
+    }
+
+    #[test]
+    fn test_finalize_vote_with_abstain_votes_not_counted_towards_majority() {
+        // Arrange
+        let mut proposal = create_test_proposal();
+
+        proposal.options[0].vote_weight = 10;
+        proposal.deny_vote_weight = Some(10);
+        proposal.abstain_vote_weight = Some(30);
+
+        proposal.state = ProposalState::Voting;
+
+        let governance_config = create_test_quorum_governance_config();
+
+        let realm = create_test_realm();
+        let max_voter_weight = proposal
+            .get_max_voter_weight_from_mint_supply(&realm, 100)
+            .unwrap();
+
+        // Act
+        proposal
+            .finalize_vote(max_voter_weight, &governance_config, 16)
+            .unwrap();
+
+        // Assert
+        assert_eq!(proposal.state, ProposalState::Defeated);
+    }
+
+    #[test]
+    fn test_assert_valid_abstain_vote() {
+        // Arrange
+        let proposal = create_test_proposal();
+
+        // Act
+        let result = proposal.assert_valid_vote(&Vote::Abstain);
+
+        // Assert
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn test_assert_valid_abstain_vote_without_abstain_option_error() {
+        // Arrange
+        let mut proposal = create_test_proposal();
+        proposal.abstain_vote_weight = None;
+
+        // Act
+        let err = proposal.assert_valid_vote(&Vote::Abstain).err().unwrap();
+
+        // Assert
+        assert_eq!(err, GovernanceError::InvalidVote.into());
+    }
 }
@@ -15,7 +15,13 @@ use crate::{
     state::{
         enums::{GovernanceAccountType, MintMaxVoteWeightSource},
         legacy::RealmV1,
+        realm_config::get_realm_config_data_for_realm,
         token_owner_record::get_token_owner_record_data_for_realm,
+        vote_record::Vote,
+    },
+    tools::verification::{
+        assert_identity_verification, assert_permissioned_list_membership,
+        assert_permissioned_list_proof,
     },
     PROGRAM_AUTHORITY_SEED,
 };
@@ -69,8 +75,15 @@ pub struct RealmConfig {
     /// Indicates whether an external addin program should be used to provide max voter weight for the community mint
     pub use_max_community_voter_weight_addin: bool,
 
+    /// Indicates whether token owners must be members of the permissioned list set on RealmConfigAccount
+    pub use_permissioned_list: bool,
+
+    /// Indicates whether governing token owners must hold a valid identity record
+    /// issued by the identity verification program set on RealmConfigAccount
+    pub use_identity_verification: bool,
+
     /// Reserved space for future versions
-    pub reserved: [u8; 6],
+    pub reserved: [u8; 4],
 
     /// Min number of voter's community weight required to create a governance
     pub min_community_weight_to_create_governance: u64,
@@ -150,7 +163,11 @@ pub fn is_realm_account_type(account_type: &GovernanceAccountType) -> bool {
         | GovernanceAccountType::ProposalTransactionV2
         | GovernanceAccountType::VoteRecordV1
         | GovernanceAccountType::VoteRecordV2
-        | GovernanceAccountType::ProgramMetadata => false,
+        | GovernanceAccountType::ProgramMetadata
+        | GovernanceAccountType::CapitalRaise
+        | GovernanceAccountType::CapitalContributionRecord
+        | GovernanceAccountType::Distribution
+        | GovernanceAccountType::DistributionClaimRecord => false,
     }
 }
 
@@ -191,6 +208,25 @@ impl RealmV2 {
         Ok(())
     }
 
+    /// Returns the governing token mint of the Proposals the given vote can be cast for
+    /// Veto votes are cast with the council mint on community Proposals and other votes are cast with the Proposal governing token mint
+    pub fn get_proposal_governing_token_mint_for_vote(
+        &self,
+        vote_governing_token_mint: &Pubkey,
+        vote: &Vote,
+    ) -> Result<Pubkey, ProgramError> {
+        match vote {
+            Vote::Veto => {
+                if self.config.council_mint != Some(*vote_governing_token_mint) {
+                    return Err(GovernanceError::InvalidGoverningTokenMintForVetoVote.into());
+                }
+
+                Ok(self.community_mint)
+            }
+            Vote::Approve(_) | Vote::Deny | Vote::Abstain => Ok(*vote_governing_token_mint),
+        }
+    }
+
     /// Asserts the given governing token can be deposited into the realm
     pub fn asset_governing_tokens_deposits_allowed(
         &self,
@@ -247,6 +283,95 @@ impl RealmV2 {
         Ok(())
     }
 
+    /// Asserts the member is on the realm's permissioned list when the realm uses one
+    /// Membership is shown either with a merkle proof or with an active list entry account
+    /// The permissioned list and the entry accounts are taken from account_info_iter only when the list is used
+    /// and the entry account is only taken when no proof is given
+    pub fn assert_is_permissioned_list_member(
+        &self,
+        program_id: &Pubkey,
+        realm: &Pubkey,
+        realm_config_info: &AccountInfo,
+        account_info_iter: &mut Iter<AccountInfo>,
+        member: &Pubkey,
+        membership_proof: Option<&[[u8; 32]]>,
+    ) -> Result<(), ProgramError> {
+        if !self.config.use_permissioned_list {
+            return Ok(());
+        }
+
+        let permissioned_list_info = next_account_info(account_info_iter)?;
+
+        let realm_config_data =
+            get_realm_config_data_for_realm(program_id, realm_config_info, realm)?;
+
+        if realm_config_data.permissioned_list != Some(*permissioned_list_info.key) {
+            return Err(GovernanceError::InvalidPermissionedList.into());
+        }
+
+        if let Some(membership_proof) = membership_proof {
+            return assert_permissioned_list_proof(permissioned_list_info, member, membership_proof);
+        }
+
+        let list_entry_info = next_account_info(account_info_iter)?;
+
+        assert_permissioned_list_membership(permissioned_list_info, list_entry_info, member)
+    }
+
+    /// Asserts the governing token owner holds a valid identity record when the realm requires one
+    /// The identity record, the identity verification program and the frozen subject accounts
+    /// are taken from account_info_iter only when the realm uses identity verification
+    pub fn assert_governing_token_owner_identity(
+        &self,
+        program_id: &Pubkey,
+        realm: &Pubkey,
+        realm_config_info: &AccountInfo,
+        account_info_iter: &mut Iter<AccountInfo>,
+        governing_token_owner: &Pubkey,
+    ) -> Result<(), ProgramError> {
+        if !self.config.use_identity_verification {
+            return Ok(());
+        }
+
+        let identity_record_info = next_account_info(account_info_iter)?;
+        let identity_verification_program_info = next_account_info(account_info_iter)?;
+        let frozen_subject_info = next_account_info(account_info_iter)?;
+
+        let realm_config_data =
+            get_realm_config_data_for_realm(program_id, realm_config_info, realm)?;
+
+        let trusted_identity_verification_program = realm_config_data
+            .identity_verification_program
+            .ok_or(GovernanceError::IdentityVerificationProgramNotConfigured)?;
+
+        // Amount thresholds only relax capital deposits, governance actions require every check which isn't exempt
+        assert_identity_verification(
+            identity_record_info,
+            governing_token_owner,
+            realm,
+            identity_verification_program_info,
+            frozen_subject_info,
+            &trusted_identity_verification_program,
+            &realm_config_data.identity_policy,
+            u64::MAX,
+        )?;
+
+        Ok(())
+    }
+
+    /// Asserts the identity verification program can be changed to the given one
+    /// The program can't be removed while the realm uses identity verification because no identity record could be validated
+    pub fn assert_can_set_identity_verification_program(
+        &self,
+        identity_verification_program: &Option<Pubkey>,
+    ) -> Result<(), ProgramError> {
+        if self.config.use_identity_verification && identity_verification_program.is_none() {
+            return Err(GovernanceError::IdentityVerificationProgramInUse.into());
+        }
+
+        Ok(())
+    }
+
     /// Serializes account into the target buffer
     pub fn serialize<W: Write>(self, writer: &mut W) -> Result<(), ProgramError> {
         if self.account_type == GovernanceAccountType::RealmV2 {
@@ -386,8 +511,10 @@ pub fn assert_valid_realm_config_args(config_args: &RealmConfigArgs) -> Result<(
                 return Err(GovernanceError::InvalidMaxVoteWeightSupplyFraction.into());
             }
         }
-        MintMaxVoteWeightSource::Absolute(_) => {
-            return Err(GovernanceError::MintMaxVoteWeightSourceNotSupported.into());
+        MintMaxVoteWeightSource::Absolute(value) => {
+            if value == 0 {
+                return Err(GovernanceError::InvalidMaxVoteWeightAbsoluteValue.into());
+            }
         }
     }
 
@@ -414,7 +541,9 @@ mod test {
                 council_mint: Some(Pubkey::new_unique()),
                 use_community_voter_weight_addin: false,
                 use_max_community_voter_weight_addin: false,
-                reserved: [0; 6],
+                use_permissioned_list: false,
+                use_identity_verification: false,
+                reserved: [0; 4],
                 community_mint_max_vote_weight_source: MintMaxVoteWeightSource::Absolute(100),
                 min_community_weight_to_create_governance: 10,
             },
@@ -499,4 +628,105 @@ mod test {
             panic!("Can't deserialize v1 CreateRealm instruction from v2");
         }
     }
+
+    #[test]
+    fn test_assert_valid_realm_config_args_with_absolute_max_vote_weight() {
+        // Arrange
+        let config_args = RealmConfigArgs {
+            use_council_mint: true,
+            min_community_weight_to_create_governance: 10,
+            community_mint_max_vote_weight_source: MintMaxVoteWeightSource::Absolute(100),
+            use_community_voter_weight_addin: false,
+            use_max_community_voter_weight_addin: false,
+        };
+
+        // Act
+        let result = assert_valid_realm_config_args(&config_args);
+
+        // Assert
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn test_assert_valid_realm_config_args_with_zero_absolute_max_vote_weight_error() {
+        // Arrange
+        let config_args = RealmConfigArgs {
+            use_council_mint: true,
+            min_community_weight_to_create_governance: 10,
+            community_mint_max_vote_weight_source: MintMaxVoteWeightSource::Absolute(0),
+            use_community_voter_weight_addin: false,
+            use_max_community_voter_weight_addin: false,
+        };
+
+        // Act
+        let err = assert_valid_realm_config_args(&config_args)
+            .err()
+            .unwrap();
+
+        // Assert
+        assert_eq!(err, GovernanceError::InvalidMaxVoteWeightAbsoluteValue.into());
+    }
+
+    fn create_test_realm(use_identity_verification: bool) -> RealmV2 {
+        RealmV2 {
+            account_type: GovernanceAccountType::RealmV2,
+            community_mint: Pubkey::new_unique(),
+            reserved: [0; 6],
+
+            authority: Some(Pubkey::new_unique()),
+            name: "test-realm".to_string(),
+            config: RealmConfig {
+                council_mint: Some(Pubkey::new_unique()),
+                use_community_voter_weight_addin: false,
+                use_max_community_voter_weight_addin: false,
+                use_permissioned_list: false,
+                use_identity_verification,
+                reserved: [0; 4],
+                community_mint_max_vote_weight_source: MintMaxVoteWeightSource::FULL_SUPPLY_FRACTION,
+                min_community_weight_to_create_governance: 10,
+            },
+
+            voting_proposal_count: 0,
+            reserved_v2: [0; 128],
+        }
+    }
+
+    #[test]
+    fn test_remove_identity_verification_program_while_in_use_error() {
+        // Arrange
+        let realm = create_test_realm(true);
+
+        // Act
+        let err = realm
+            .assert_can_set_identity_verification_program(&None)
+            .err()
+            .unwrap();
+
+        // Assert
+        assert_eq!(err, GovernanceError::IdentityVerificationProgramInUse.into());
+    }
+
+    #[test]
+    fn test_change_identity_verification_program_while_in_use() {
+        // Arrange
+        let realm = create_test_realm(true);
+
+        // Act
+        let result = realm.assert_can_set_identity_verification_program(&Some(Pubkey::new_unique()));
+
+        // Assert
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn test_remove_identity_verification_program_when_not_in_use() {
+        // Arrange
+        let realm = create_test_realm(false);
+
+        // Act
+        let result = realm.assert_can_set_identity_verification_program(&None);
+
+        // Assert
+        assert_eq!(result, Ok(()));
+    }
 }
@@ -1,14 +1,146 @@
 //! RealmConfig account
 
 use solana_program::{
-    account_info::AccountInfo, program_error::ProgramError, program_pack::IsInitialized,
-    pubkey::Pubkey,
+    account_info::AccountInfo, clock::UnixTimestamp, program_error::ProgramError,
+    program_pack::IsInitialized, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
 };
 
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
-use spl_governance_tools::account::{get_account_data, AccountMaxSize};
+use spl_governance_tools::account::{
+    create_and_serialize_account_signed, get_account_data, AccountMaxSize,
+};
+
+use crate::{
+    error::GovernanceError,
+    state::{
+        enums::GovernanceAccountType,
+        identity::{identity_check, Identity, IdentityStatus},
+    },
+};
+
+/// Max number of jurisdictions which can be excluded from depositing capital into a realm
+pub const MAX_EXCLUDED_JURISDICTIONS: usize = 15;
+
+/// Investor requirements a realm enforces on DepositCapital
+#[derive(Clone, Debug, Default, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct InvestorRequirements {
+    /// Bitmask of investor_class flags the investor must hold, all of them must be present
+    pub required_investor_classes: u8,
+
+    /// ISO 3166-1 alpha-2 codes of jurisdictions which can't deposit, unused slots are [0, 0]
+    pub excluded_jurisdictions: [[u8; 2]; MAX_EXCLUDED_JURISDICTIONS],
+}
+
+impl InvestorRequirements {
+    /// Checks the requirements only reference well formed jurisdiction codes
+    pub fn assert_is_valid(&self) -> Result<(), ProgramError> {
+        let is_valid = self
+            .excluded_jurisdictions
+            .iter()
+            .all(|j| *j == [0, 0] || j.iter().all(|c| c.is_ascii_uppercase()));
+
+        if !is_valid {
+            return Err(GovernanceError::InvalidInvestorRequirements.into());
+        }
+
+        Ok(())
+    }
+
+    /// Checks the investor holds the required classes and isn't from an excluded jurisdiction
+    /// Investors with an unknown jurisdiction are rejected when any jurisdiction is excluded
+    pub fn assert_investor_is_eligible(&self, identity: &Identity) -> Result<(), ProgramError> {
+        if identity.investor_class & self.required_investor_classes
+            != self.required_investor_classes
+        {
+            return Err(GovernanceError::InvestorClassNotEligible.into());
+        }
+
+        let mut excluded_jurisdictions = self
+            .excluded_jurisdictions
+            .iter()
+            .filter(|j| **j != [0, 0])
+            .peekable();
+
+        if excluded_jurisdictions.peek().is_some()
+            && (identity.jurisdiction == [0, 0]
+                || excluded_jurisdictions.any(|j| *j == identity.jurisdiction))
+        {
+            return Err(GovernanceError::InvestorJurisdictionNotEligible.into());
+        }
+
+        Ok(())
+    }
+}
+
+/// Identity checks a realm requires from investors
+/// The zeroed policy requires every check for any amount
+#[derive(Clone, Debug, Default, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct IdentityPolicy {
+    /// Bitmask of identity_check flags which are never required
+    pub exempt_checks: u8,
+
+    /// Min amount from which the aml check is required, 0 to always require it
+    pub aml_min_amount: u64,
+
+    /// Min amount from which the kyc check is required, 0 to always require it
+    pub kyc_min_amount: u64,
+
+    /// Min amount from which the accreditation check is required, 0 to always require it
+    pub ia_min_amount: u64,
+}
+
+impl IdentityPolicy {
+    /// Checks the policy only references known identity checks
+    pub fn assert_is_valid(&self) -> Result<(), ProgramError> {
+        if self.exempt_checks & !identity_check::ALL != 0 {
+            return Err(GovernanceError::InvalidIdentityPolicy.into());
+        }
+
+        Ok(())
+    }
+
+    /// Returns true when the identity_check flag is required for the given amount
+    pub fn is_required(&self, check: u8, amount: u64) -> bool {
+        let min_amount = match check {
+            identity_check::AML => self.aml_min_amount,
+            identity_check::KYC => self.kyc_min_amount,
+            _ => self.ia_min_amount,
+        };
+
+        self.exempt_checks & check == 0 && amount >= min_amount
+    }
 
-use crate::{error::GovernanceError, state::enums::GovernanceAccountType};
+    /// Checks every identity check required for the amount is approved and hasn't lapsed
+    pub fn assert_identity_is_verified(
+        &self,
+        identity: &Identity,
+        amount: u64,
+        now: UnixTimestamp,
+    ) -> Result<(), ProgramError> {
+        let required_checks = [
+            identity_check::AML,
+            identity_check::KYC,
+            identity_check::ACCREDITATION,
+        ]
+        .iter()
+        .copied()
+        .filter(|check| self.is_required(*check, amount));
+
+        for check in required_checks {
+            let (status, _) = identity.get_check_status(check);
+
+            if status != IdentityStatus::Approved {
+                return Err(GovernanceError::UserIdentityNotKnown.into());
+            }
+
+            if !identity.is_check_verified(check, now) {
+                return Err(GovernanceError::UserIdentityVerificationExpired.into());
+            }
+        }
+
+        Ok(())
+    }
+}
 
 /// RealmConfig account
 /// The account is an optional extension to RealmConfig stored on Realm account
@@ -35,8 +167,20 @@ pub struct RealmConfigAccount {
     /// Note: This field is not implemented in the current version
     pub council_max_vote_weight_addin: Option<Pubkey>,
 
+    /// Investor requirements enforced on DepositCapital
+    pub investor_requirements: InvestorRequirements,
+
+    /// Permissioned list which must admit token owners to deposit governing tokens, create proposals and vote
+    pub permissioned_list: Option<Pubkey>,
+
+    /// Identity verification program trusted to own the identity records of investors
+    pub identity_verification_program: Option<Pubkey>,
+
+    /// Identity checks required from investors
+    pub identity_policy: IdentityPolicy,
+
     /// Reserved
-    pub reserved: [u8; 128],
+    pub reserved: [u8; 6],
 }
 
 impl AccountMaxSize for RealmConfigAccount {
@@ -74,6 +218,46 @@ pub fn get_realm_config_data_for_realm(
     Ok(realm_config_data)
 }
 
+/// Deserializes RealmConfig account for the given Realm
+/// If the account doesn't exist yet then it's created with the default config paid by the payer
+pub fn get_or_create_realm_config_data<'a>(
+    program_id: &Pubkey,
+    realm_config_info: &AccountInfo<'a>,
+    realm: &Pubkey,
+    payer_info: &AccountInfo<'a>,
+    system_info: &AccountInfo<'a>,
+) -> Result<RealmConfigAccount, ProgramError> {
+    if !realm_config_info.data_is_empty() {
+        return get_realm_config_data_for_realm(program_id, realm_config_info, realm);
+    }
+
+    let realm_config_data = RealmConfigAccount {
+        account_type: GovernanceAccountType::RealmConfig,
+        realm: *realm,
+        community_voter_weight_addin: None,
+        max_community_voter_weight_addin: None,
+        council_voter_weight_addin: None,
+        council_max_vote_weight_addin: None,
+        investor_requirements: InvestorRequirements::default(),
+        permissioned_list: None,
+        identity_verification_program: None,
+        identity_policy: IdentityPolicy::default(),
+        reserved: [0; 6],
+    };
+
+    create_and_serialize_account_signed::<RealmConfigAccount>(
+        payer_info,
+        realm_config_info,
+        &realm_config_data,
+        &get_realm_config_address_seeds(realm),
+        program_id,
+        system_info,
+        &Rent::get()?,
+    )?;
+
+    Ok(realm_config_data)
+}
+
 /// Returns RealmConfig PDA seeds
 pub fn get_realm_config_address_seeds(realm: &Pubkey) -> [&[u8]; 2] {
     [b"realm-config", realm.as_ref()]
@@ -87,7 +271,11 @@ pub fn get_realm_config_address(program_id: &Pubkey, realm: &Pubkey) -> Pubkey {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::state::{enums::GovernanceAccountType, realm_config::RealmConfigAccount};
+    use crate::state::{
+        enums::GovernanceAccountType,
+        identity::{identity_check, investor_class, IdentityStatus},
+        realm_config::RealmConfigAccount,
+    };
 
     #[test]
     fn test_max_size() {
@@ -98,11 +286,230 @@ mod test {
             max_community_voter_weight_addin: Some(Pubkey::new_unique()),
             council_voter_weight_addin: Some(Pubkey::new_unique()),
             council_max_vote_weight_addin: Some(Pubkey::new_unique()),
-            reserved: [0; 128],
+            investor_requirements: InvestorRequirements::default(),
+            permissioned_list: Some(Pubkey::new_unique()),
+            identity_verification_program: Some(Pubkey::new_unique()),
+            identity_policy: IdentityPolicy::default(),
+            reserved: [0; 6],
         };
 
         let size = realm_config.try_to_vec().unwrap().len();
 
         assert_eq!(realm_config.get_max_size(), Some(size));
     }
+
+    fn create_test_identity(jurisdiction: [u8; 2], investor_class: u8) -> Identity {
+        Identity {
+            discriminator: [0; 8],
+            bump: 255,
+            ia_status: IdentityStatus::Approved,
+            aml_status: IdentityStatus::Approved,
+            kyc_status: IdentityStatus::Approved,
+            authority: Pubkey::new_unique(),
+            ia_approved_at: 0,
+            ia_expires_at: 100,
+            aml_approved_at: 0,
+            aml_expires_at: 100,
+            kyc_approved_at: 0,
+            kyc_expires_at: 100,
+            jurisdiction,
+            investor_class,
+            attested_at: 0,
+        }
+    }
+
+    fn create_test_requirements(
+        required_investor_classes: u8,
+        excluded_jurisdictions: &[[u8; 2]],
+    ) -> InvestorRequirements {
+        let mut requirements = InvestorRequirements {
+            required_investor_classes,
+            ..InvestorRequirements::default()
+        };
+
+        requirements.excluded_jurisdictions[..excluded_jurisdictions.len()]
+            .copy_from_slice(excluded_jurisdictions);
+
+        requirements
+    }
+
+    #[test]
+    fn test_default_requirements_accept_any_investor() {
+        // Arrange
+        let requirements = InvestorRequirements::default();
+        let identity = create_test_identity([0, 0], 0);
+
+        // Act
+        let result = requirements.assert_investor_is_eligible(&identity);
+
+        // Assert
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn test_investor_without_required_class_is_rejected() {
+        // Arrange
+        let requirements = create_test_requirements(
+            investor_class::ACCREDITED | investor_class::QUALIFIED_PURCHASER,
+            &[],
+        );
+        let identity = create_test_identity(*b"US", investor_class::ACCREDITED);
+
+        // Act
+        let result = requirements.assert_investor_is_eligible(&identity);
+
+        // Assert
+        assert_eq!(
+            result,
+            Err(GovernanceError::InvestorClassNotEligible.into())
+        );
+    }
+
+    #[test]
+    fn test_investor_from_excluded_jurisdiction_is_rejected() {
+        // Arrange
+        let requirements = create_test_requirements(investor_class::NON_US_PERSON, &[*b"US"]);
+        let identity = create_test_identity(*b"US", investor_class::NON_US_PERSON);
+
+        // Act
+        let result = requirements.assert_investor_is_eligible(&identity);
+
+        // Assert
+        assert_eq!(
+            result,
+            Err(GovernanceError::InvestorJurisdictionNotEligible.into())
+        );
+    }
+
+    #[test]
+    fn test_investor_with_unknown_jurisdiction_is_rejected_when_jurisdictions_are_excluded() {
+        // Arrange
+        let requirements = create_test_requirements(0, &[*b"US"]);
+        let identity = create_test_identity([0, 0], 0);
+
+        // Act
+        let result = requirements.assert_investor_is_eligible(&identity);
+
+        // Assert
+        assert_eq!(
+            result,
+            Err(GovernanceError::InvestorJurisdictionNotEligible.into())
+        );
+    }
+
+    #[test]
+    fn test_eligible_investor_is_accepted() {
+        // Arrange
+        let requirements = create_test_requirements(investor_class::NON_US_PERSON, &[*b"US"]);
+        let identity = create_test_identity(
+            *b"DE",
+            investor_class::NON_US_PERSON | investor_class::ACCREDITED,
+        );
+
+        // Act
+        let result = requirements.assert_investor_is_eligible(&identity);
+
+        // Assert
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn test_malformed_jurisdiction_is_invalid() {
+        // Arrange
+        let requirements = create_test_requirements(0, &[*b"us"]);
+
+        // Act
+        let result = requirements.assert_is_valid();
+
+        // Assert
+        assert_eq!(
+            result,
+            Err(GovernanceError::InvalidInvestorRequirements.into())
+        );
+    }
+
+    #[test]
+    fn test_default_identity_policy_requires_every_check() {
+        // Arrange
+        let policy = IdentityPolicy::default();
+        let mut identity = create_test_identity(*b"US", 0);
+        identity.ia_status = IdentityStatus::Started;
+
+        // Act
+        let result = policy.assert_identity_is_verified(&identity, 0, 50);
+
+        // Assert
+        assert_eq!(result, Err(GovernanceError::UserIdentityNotKnown.into()));
+    }
+
+    #[test]
+    fn test_exempt_check_is_not_required() {
+        // Arrange
+        let policy = IdentityPolicy {
+            exempt_checks: identity_check::ACCREDITATION,
+            ..IdentityPolicy::default()
+        };
+        let mut identity = create_test_identity(*b"US", 0);
+        identity.ia_status = IdentityStatus::Initial;
+
+        // Act
+        let result = policy.assert_identity_is_verified(&identity, u64::MAX, 50);
+
+        // Assert
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn test_check_is_required_from_min_amount() {
+        // Arrange
+        let policy = IdentityPolicy {
+            ia_min_amount: 1_000,
+            ..IdentityPolicy::default()
+        };
+        let mut identity = create_test_identity(*b"US", 0);
+        identity.ia_status = IdentityStatus::Initial;
+
+        // Act
+        let below_result = policy.assert_identity_is_verified(&identity, 999, 50);
+        let above_result = policy.assert_identity_is_verified(&identity, 1_000, 50);
+
+        // Assert
+        assert_eq!(below_result, Ok(()));
+        assert_eq!(
+            above_result,
+            Err(GovernanceError::UserIdentityNotKnown.into())
+        );
+    }
+
+    #[test]
+    fn test_lapsed_required_check_is_rejected() {
+        // Arrange
+        let policy = IdentityPolicy::default();
+        let mut identity = create_test_identity(*b"US", 0);
+        identity.kyc_expires_at = 50;
+
+        // Act
+        let result = policy.assert_identity_is_verified(&identity, 0, 50);
+
+        // Assert
+        assert_eq!(
+            result,
+            Err(GovernanceError::UserIdentityVerificationExpired.into())
+        );
+    }
+
+    #[test]
+    fn test_unknown_exempt_check_is_invalid() {
+        // Arrange
+        let policy = IdentityPolicy {
+            exempt_checks: 1 << 7,
+            ..IdentityPolicy::default()
+        };
+
+        // Act
+        let result = policy.assert_is_valid();
+
+        // Assert
+        assert_eq!(result, Err(GovernanceError::InvalidIdentityPolicy.into()));
+    }
 }
@@ -53,11 +53,11 @@ pub enum Vote {
     Deny,
 
     /// Declare indifference to proposal
-    /// Note: Not supported in the current version
+    /// Abstain votes count towards quorum but not towards the vote outcome
     Abstain,
 
     /// Veto proposal
-    /// Note: Not supported in the current version
+    /// Cast by council members on community proposals
     Veto,
 }
 
@@ -244,6 +244,17 @@ pub fn get_spl_token_owner(token_account_info: &AccountInfo) -> Result<Pubkey, P
     Ok(Pubkey::new_from_array(*owner_data))
 }
 
+/// Computationally cheap method to get amount from a token account
+/// It reads amount without deserializing full account data
+pub fn get_spl_token_amount(token_account_info: &AccountInfo) -> Result<u64, ProgramError> {
+    assert_is_valid_spl_token_account(token_account_info)?;
+
+    // TokeAccount layout:   mint(32), owner(32), amount(8)
+    let data = token_account_info.try_borrow_data()?;
+    let amount_data = array_ref![data, 64, 8];
+    Ok(u64::from_le_bytes(*amount_data))
+}
+
 /// Computationally cheap method to just get supply from a mint without unpacking the whole object
 pub fn get_spl_token_mint_supply(mint_info: &AccountInfo) -> Result<u64, ProgramError> {
     assert_is_valid_spl_token_mint(mint_info)?;
@@ -2,41 +2,151 @@
 
 use solana_program::account_info::AccountInfo;
 use solana_program::borsh::try_from_slice_unchecked;
+use solana_program::clock::Clock;
 use solana_program::msg;
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
 use crate::error::GovernanceError;
-use crate::state::identity::Identity;
+use crate::state::identity::{get_identity_record_discriminator, Identity};
+use crate::state::realm_config::IdentityPolicy;
+use crate::state::permissioned_list::{get_list_entry_address, ListEntry, ListType, PermissionedList};
 
-/// helper to assert user identity verification
+/// helper to assert user identity verification, returns the verified identity
+/// The record must be an identity record of the subject owned by the identity verification program the realm trusts
+/// and pass every check the identity policy of the realm requires for the amount
+/// A subject frozen by the identity verification program is never verified, whatever its record says
 pub fn assert_identity_verification(
     record: &AccountInfo,
-    subject: &AccountInfo,
-    group: &AccountInfo,
-    program: &AccountInfo
-) -> Result<bool, ProgramError> {
+    subject: &Pubkey,
+    group: &Pubkey,
+    program: &AccountInfo,
+    frozen_subject: &AccountInfo,
+    trusted_program: &Pubkey,
+    identity_policy: &IdentityPolicy,
+    amount: u64,
+) -> Result<Identity, ProgramError> {
 
     msg!("Verifying user identity...");
 
+    if program.key != trusted_program {
+        return Err(GovernanceError::InvalidIdentityVerificationProgram.into());
+    }
+
     let identity_verification_account_address = Pubkey::find_program_address(
         &[
             b"identity",
-            group.key.as_ref(),
-            subject.key.as_ref()
+            group.as_ref(),
+            subject.as_ref()
+        ],
+        trusted_program,
+    ).0;
+
+    if record.key.as_ref() != identity_verification_account_address.as_ref() {
+        return Err(GovernanceError::InvalidIdentityRecordAddress.into());
+    }
+
+    let frozen_subject_address = Pubkey::find_program_address(
+        &[
+            b"freeze",
+            subject.as_ref()
         ],
-        program.key,
+        trusted_program,
     ).0;
 
-    let idv: Identity = try_from_slice_unchecked(&record.data.borrow())?;
+    if frozen_subject.key.as_ref() != frozen_subject_address.as_ref() {
+        return Err(GovernanceError::InvalidFrozenSubjectAccount.into());
+    }
 
-    let is_verified = idv.aml_status == 2 && idv.kyc_status == 2 && idv.ia_status == 2;
+    if frozen_subject.owner == trusted_program && !frozen_subject.data_is_empty() {
+        return Err(GovernanceError::UserIdentityFrozen.into());
+    }
 
-    if record.key.as_ref() != identity_verification_account_address.as_ref() || !is_verified {
+    if record.data_is_empty() {
         return Err(GovernanceError::UserIdentityNotKnown.into());
     }
 
+    if record.owner != trusted_program {
+        return Err(GovernanceError::InvalidIdentityRecordOwner.into());
+    }
+
+    let idv: Identity = try_from_slice_unchecked(&record.data.borrow())
+        .map_err(|_| GovernanceError::InvalidIdentityRecordDiscriminator)?;
+
+    if idv.discriminator != get_identity_record_discriminator() {
+        return Err(GovernanceError::InvalidIdentityRecordDiscriminator.into());
+    }
+
+    identity_policy.assert_identity_is_verified(&idv, amount, Clock::get()?.unix_timestamp)?;
+
     msg!("User is known and in good standing.");
 
-    Ok(false)
+    Ok(idv)
+
+}
+
+/// helper to assert the permissioned list admits the member
+/// Allow lists admit members holding an active entry, deny lists admit everyone else
+/// The entry must be the PDA of the member under the program owning the list, but may not exist
+pub fn assert_permissioned_list_membership(
+    list: &AccountInfo,
+    entry: &AccountInfo,
+    member: &Pubkey,
+) -> Result<(), ProgramError> {
+
+    msg!("Verifying permissioned list membership...");
+
+    let entry_address = get_list_entry_address(list.owner, list.key, member);
+
+    if entry.key.as_ref() != entry_address.as_ref() {
+        return Err(GovernanceError::InvalidPermissionedListEntry.into());
+    }
+
+    let is_member = if entry.owner != list.owner || entry.data_is_empty() {
+        false
+    } else {
+        let list_entry: ListEntry = try_from_slice_unchecked(&entry.data.borrow())?;
+
+        list_entry.list == *list.key
+            && list_entry.user == *member
+            && list_entry.is_active(Clock::get()?.unix_timestamp)
+    };
+
+    let permissioned_list: PermissionedList = try_from_slice_unchecked(&list.data.borrow())?;
+
+    match (permissioned_list.list_type, is_member) {
+        (ListType::Allow, false) => Err(GovernanceError::NotPermissionedListMember.into()),
+        (ListType::Deny, true) => Err(GovernanceError::DeniedByPermissionedList.into()),
+        _ => {
+            msg!("User is admitted by the permissioned list.");
+            Ok(())
+        }
+    }
+
+}
+
+/// helper to assert the merkle proof shows the member is on the permissioned list
+/// Proofs can only show membership so they are only accepted for allow lists
+pub fn assert_permissioned_list_proof(
+    list: &AccountInfo,
+    member: &Pubkey,
+    proof: &[[u8; 32]],
+) -> Result<(), ProgramError> {
+
+    msg!("Verifying permissioned list membership proof...");
+
+    let permissioned_list: PermissionedList = try_from_slice_unchecked(&list.data.borrow())?;
+
+    if permissioned_list.list_type != ListType::Allow {
+        return Err(GovernanceError::InvalidPermissionedList.into());
+    }
+
+    if !permissioned_list.is_member_by_proof(member, proof) {
+        return Err(GovernanceError::NotPermissionedListMember.into());
+    }
+
+    msg!("User is a member of the permissioned list.");
+
+    Ok(())
 
 }
\ No newline at end of file
@@ -7,15 +7,302 @@ mod program_test;
 
 use program_test::*;
 use solana_sdk::signature::{Keypair, Signer};
-use spl_governance::{error::GovernanceError, instruction::deposit_governing_tokens};
+use spl_governance::{
+    error::GovernanceError,
+    state::capital_contribution_record::get_capital_contribution_record_address,
+};
+use spl_token::error::TokenError;
 
 #[tokio::test]
 async fn test_deposit_capital() {
+    // Arrange
+    let mut governance_test = GovernanceProgramTest::start_new().await;
+
+    let realm_cookie = governance_test.with_realm().await;
+
+    let identity_verification_program = governance_test
+        .with_identity_verification_program(&realm_cookie)
+        .await;
+
+    let capital_raise_cookie = governance_test
+        .with_capital_raise(&realm_cookie)
+        .await
+        .unwrap();
+
+    let investor_cookie = governance_test
+        .with_investor(
+            &realm_cookie,
+            &capital_raise_cookie,
+            &identity_verification_program,
+            1_000,
+        )
+        .await;
+
+    let amount = 400;
+
+    // Act
+    governance_test
+        .deposit_capital(
+            &realm_cookie,
+            &capital_raise_cookie,
+            &investor_cookie,
+            &identity_verification_program,
+            amount,
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    let capital_token_account = governance_test
+        .get_token_account(&investor_cookie.capital_token_account)
+        .await;
+
+    assert_eq!(capital_token_account.amount, 600);
+
+    let capital_holding_account = governance_test
+        .get_token_account(&capital_raise_cookie.account.capital_holding_account)
+        .await;
+
+    assert_eq!(capital_holding_account.amount, amount);
+
+    // The capital raise price is 2 LP tokens for 1 capital token
+    let lp_token_account = governance_test
+        .get_token_account(&investor_cookie.lp_token_account)
+        .await;
+
+    assert_eq!(lp_token_account.amount, 2 * amount);
+
+    let capital_raise_account = governance_test
+        .get_capital_raise_account(&capital_raise_cookie.address)
+        .await;
+
+    assert_eq!(capital_raise_account.total_raised, amount);
+    assert_eq!(capital_raise_account.total_lp_issued, 2 * amount);
+
+    let capital_contribution_record_address = get_capital_contribution_record_address(
+        &governance_test.program_id,
+        &realm_cookie.address,
+        &investor_cookie.investor.pubkey(),
+    );
 
+    let capital_contribution_record = governance_test
+        .get_capital_contribution_record_account(&capital_contribution_record_address)
+        .await;
+
+    assert_eq!(
+        capital_contribution_record.investor,
+        investor_cookie.investor.pubkey()
+    );
+    assert_eq!(capital_contribution_record.total_contributed, amount);
+    assert_eq!(capital_contribution_record.lp_received, 2 * amount);
+    assert_eq!(capital_contribution_record.deposit_count, 1);
+}
+
+#[tokio::test]
+async fn test_deposit_capital_with_capital_raise_of_other_realm_error() {
+    // Arrange
+    let mut governance_test = GovernanceProgramTest::start_new().await;
+
+    let realm_cookie = governance_test.with_realm().await;
+
+    let identity_verification_program = governance_test
+        .with_identity_verification_program(&realm_cookie)
+        .await;
+
+    let capital_raise_cookie = governance_test
+        .with_capital_raise(&realm_cookie)
+        .await
+        .unwrap();
+
+    let investor_cookie = governance_test
+        .with_investor(
+            &realm_cookie,
+            &capital_raise_cookie,
+            &identity_verification_program,
+            1_000,
+        )
+        .await;
+
+    // Try to deposit into the capital raise of another realm
+    let realm_cookie2 = governance_test.with_realm().await;
+
+    let capital_raise_cookie2 = governance_test
+        .with_capital_raise(&realm_cookie2)
+        .await
+        .unwrap();
+
+    // Act
+    let err = governance_test
+        .deposit_capital_using_instruction(
+            &realm_cookie,
+            &capital_raise_cookie,
+            &investor_cookie,
+            &identity_verification_program,
+            400,
+            |i| i.accounts[8] = AccountMeta::new(capital_raise_cookie2.address, false), // capital_raise
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(err, GovernanceError::InvalidRealmForCapitalRaise.into());
+}
+
+#[tokio::test]
+async fn test_deposit_capital_with_invalid_lp_mint_error() {
     // Arrange
-    // let mut governance_test = GovernanceProgramTest::start_new().await;
-    // let realm_cookie = governance_test.with_realm().await;
+    let mut governance_test = GovernanceProgramTest::start_new().await;
+
+    let realm_cookie = governance_test.with_realm().await;
+
+    let identity_verification_program = governance_test
+        .with_identity_verification_program(&realm_cookie)
+        .await;
+
+    let capital_raise_cookie = governance_test
+        .with_capital_raise(&realm_cookie)
+        .await
+        .unwrap();
+
+    let investor_cookie = governance_test
+        .with_investor(
+            &realm_cookie,
+            &capital_raise_cookie,
+            &identity_verification_program,
+            1_000,
+        )
+        .await;
+
+    let lp_mint_keypair = Keypair::new();
+
+    governance_test
+        .bench
+        .create_mint(&lp_mint_keypair, &lp_mint_keypair.pubkey(), None)
+        .await;
+
+    // Act
+    let err = governance_test
+        .deposit_capital_using_instruction(
+            &realm_cookie,
+            &capital_raise_cookie,
+            &investor_cookie,
+            &identity_verification_program,
+            400,
+            |i| i.accounts[7] = AccountMeta::new_readonly(lp_mint_keypair.pubkey(), false), // lp_token_mint
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(err, GovernanceError::InvalidCapitalRaiseAccounts.into());
+}
+
+#[tokio::test]
+async fn test_deposit_capital_with_invalid_capital_mint_error() {
+    // Arrange
+    let mut governance_test = GovernanceProgramTest::start_new().await;
+
+    let realm_cookie = governance_test.with_realm().await;
+
+    let identity_verification_program = governance_test
+        .with_identity_verification_program(&realm_cookie)
+        .await;
+
+    let capital_raise_cookie = governance_test
+        .with_capital_raise(&realm_cookie)
+        .await
+        .unwrap();
+
+    let mut investor_cookie = governance_test
+        .with_investor(
+            &realm_cookie,
+            &capital_raise_cookie,
+            &identity_verification_program,
+            1_000,
+        )
+        .await;
+
+    // Fund the investor with tokens of a mint other than the capital raise mint
+    let capital_mint_keypair = Keypair::new();
+
+    governance_test
+        .bench
+        .create_mint(&capital_mint_keypair, &capital_mint_keypair.pubkey(), None)
+        .await;
+
+    let capital_token_account_cookie = governance_test
+        .bench
+        .with_token_account(
+            &capital_mint_keypair.pubkey(),
+            &investor_cookie.investor.pubkey(),
+            &capital_mint_keypair,
+            1_000,
+        )
+        .await;
+
+    investor_cookie.capital_token_account = capital_token_account_cookie.address;
+
+    // Act
+    let err = governance_test
+        .deposit_capital(
+            &realm_cookie,
+            &capital_raise_cookie,
+            &investor_cookie,
+            &identity_verification_program,
+            400,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_eq!(err, TokenError::MintMismatch.into());
+}
+
+#[tokio::test]
+async fn test_deposit_capital_after_capital_raise_closed_error() {
+    // Arrange
+    let mut governance_test = GovernanceProgramTest::start_new().await;
+
+    let realm_cookie = governance_test.with_realm().await;
+
+    let identity_verification_program = governance_test
+        .with_identity_verification_program(&realm_cookie)
+        .await;
+
+    let capital_raise_cookie = governance_test
+        .with_capital_raise(&realm_cookie)
+        .await
+        .unwrap();
+
+    let investor_cookie = governance_test
+        .with_investor(
+            &realm_cookie,
+            &capital_raise_cookie,
+            &identity_verification_program,
+            1_000,
+        )
+        .await;
+
+    governance_test
+        .advance_clock_past_timestamp(capital_raise_cookie.account.config.close_at)
+        .await;
 
-    assert_eq!(1, 2);
+    // Act
+    let err = governance_test
+        .deposit_capital(
+            &realm_cookie,
+            &capital_raise_cookie,
+            &investor_cookie,
+            &identity_verification_program,
+            400,
+        )
+        .await
+        .err()
+        .unwrap();
 
+    // Assert
+    assert_eq!(err, GovernanceError::CapitalRaiseNotOpen.into());
 }
@@ -1,10 +1,11 @@
 use solana_program::{instruction::Instruction, pubkey::Pubkey};
 use solana_sdk::signature::Keypair;
 use spl_governance::state::{
-    governance::GovernanceV2, native_treasury::NativeTreasury, program_metadata::ProgramMetadata,
-    proposal::ProposalV2, proposal_transaction::ProposalTransactionV2, realm::RealmV2,
-    realm_config::RealmConfigAccount, signatory_record::SignatoryRecordV2,
-    token_owner_record::TokenOwnerRecordV2, vote_record::VoteRecordV2,
+    capital_raise::CapitalRaise, governance::GovernanceV2, native_treasury::NativeTreasury,
+    program_metadata::ProgramMetadata, proposal::ProposalV2,
+    proposal_transaction::ProposalTransactionV2, realm::RealmV2, realm_config::RealmConfigAccount,
+    signatory_record::SignatoryRecordV2, token_owner_record::TokenOwnerRecordV2,
+    vote_record::VoteRecordV2,
 };
 
 use spl_governance_addin_api::{
@@ -187,3 +188,18 @@ pub struct NativeTreasuryCookie {
     pub address: Pubkey,
     pub account: NativeTreasury,
 }
+
+#[derive(Debug)]
+pub struct CapitalRaiseCookie {
+    pub address: Pubkey,
+    pub account: CapitalRaise,
+    pub capital_mint_authority: Keypair,
+}
+
+#[derive(Debug)]
+pub struct InvestorCookie {
+    pub investor: Keypair,
+    pub capital_token_account: Pubkey,
+    pub lp_token_account: Pubkey,
+    pub identity_verification_record: Pubkey,
+}
@@ -1,3 +1,4 @@
+use borsh::BorshSerialize;
 use std::str::FromStr;
 
 use solana_program::{
@@ -12,28 +13,36 @@ use solana_program::{
 
 use solana_program_test::*;
 
-use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::{
+    account::{Account, AccountSharedData},
+    signature::{Keypair, Signer},
+};
 
 use spl_governance::{
     instruction::{
-        add_signatory, cancel_proposal, cast_vote, create_governance, create_mint_governance,
-        create_native_treasury, create_program_governance, create_proposal, create_realm,
-        create_token_governance, create_token_owner_record, deposit_governing_tokens,
-        execute_transaction, finalize_vote, flag_transaction_error, insert_transaction,
-        relinquish_vote, remove_signatory, remove_transaction, set_governance_config,
-        set_governance_delegate, set_realm_authority, set_realm_config, sign_off_proposal,
-        upgrade_program_metadata, withdraw_governing_tokens,
+        add_signatory, cancel_proposal, cast_vote, create_capital_raise, create_governance,
+        create_mint_governance, create_native_treasury, create_program_governance, create_proposal,
+        create_realm, create_token_governance, create_token_owner_record, deposit_capital,
+        deposit_governing_tokens, execute_transaction, finalize_vote, flag_transaction_error,
+        insert_transaction, relinquish_vote, remove_signatory, remove_transaction,
+        set_governance_config, set_governance_delegate, set_realm_authority, set_realm_config,
+        set_realm_identity_verification_program, sign_off_proposal, upgrade_program_metadata,
+        withdraw_governing_tokens,
     },
     processor::process_instruction,
     state::{
+        capital_contribution_record::CapitalContributionRecord,
+        capital_raise::{get_capital_raise_address, CapitalRaise, CapitalRaiseConfig},
         enums::{
-            GovernanceAccountType, InstructionExecutionFlags, MintMaxVoteWeightSource,
-            ProposalState, TransactionExecutionStatus, VoteThresholdPercentage,
+            CapitalRaiseState, GovernanceAccountType, InstructionExecutionFlags,
+            MintMaxVoteWeightSource, ProposalState, TransactionExecutionStatus,
+            VoteThresholdPercentage,
         },
         governance::{
             get_governance_address, get_mint_governance_address, get_program_governance_address,
-            get_token_governance_address, GovernanceConfig, GovernanceV2,
+            get_token_governance_address, GovernanceConfig, GovernanceV2, Reserved126,
         },
+        identity::{get_identity_record_discriminator, Identity, IdentityStatus},
         native_treasury::{get_native_treasury_address, NativeTreasury},
         program_metadata::{get_program_metadata_address, ProgramMetadata},
         proposal::{get_proposal_address, OptionVoteResult, ProposalOption, ProposalV2, VoteType},
@@ -44,7 +53,9 @@ use spl_governance::{
             get_governing_token_holding_address, get_realm_address, RealmConfig, RealmConfigArgs,
             RealmV2, SetRealmAuthorityAction,
         },
-        realm_config::{get_realm_config_address, RealmConfigAccount},
+        realm_config::{
+            get_realm_config_address, IdentityPolicy, InvestorRequirements, RealmConfigAccount,
+        },
         signatory_record::{get_signatory_record_address, SignatoryRecordV2},
         token_owner_record::{get_token_owner_record_address, TokenOwnerRecordV2},
         vote_record::{get_vote_record_address, Vote, VoteChoice, VoteRecordV2},
@@ -76,10 +87,10 @@ use spl_governance_test_sdk::{
 use self::{
     args::SetRealmConfigArgs,
     cookies::{
-        GovernanceCookie, GovernedAccountCookie, GovernedMintCookie, GovernedProgramCookie,
-        GovernedTokenCookie, MaxVoterWeightRecordCookie, NativeTreasuryCookie,
-        ProgramMetadataCookie, ProposalCookie, ProposalTransactionCookie, RealmCookie,
-        TokenOwnerRecordCookie, VoteRecordCookie,
+        CapitalRaiseCookie, GovernanceCookie, GovernedAccountCookie, GovernedMintCookie,
+        GovernedProgramCookie, GovernedTokenCookie, InvestorCookie, MaxVoterWeightRecordCookie,
+        NativeTreasuryCookie, ProgramMetadataCookie, ProposalCookie, ProposalTransactionCookie,
+        RealmCookie, TokenOwnerRecordCookie, VoteRecordCookie,
     },
 };
 
@@ -307,7 +318,7 @@ impl GovernanceProgramTest {
             authority: Some(realm_authority.pubkey()),
             config: RealmConfig {
                 council_mint: council_token_mint_pubkey,
-                reserved: [0; 6],
+                reserved: [0; 4],
 
                 min_community_weight_to_create_governance: set_realm_config_args
                     .realm_config_args
@@ -318,6 +329,8 @@ impl GovernanceProgramTest {
                     .clone(),
                 use_community_voter_weight_addin: false,
                 use_max_community_voter_weight_addin: false,
+                use_permissioned_list: false,
+                use_identity_verification: false,
             },
             voting_proposal_count: 0,
             reserved_v2: [0; 128],
@@ -339,7 +352,11 @@ impl GovernanceProgramTest {
                         .max_community_voter_weight_addin,
                     council_voter_weight_addin: None,
                     council_max_vote_weight_addin: None,
-                    reserved: [0; 128],
+                    investor_requirements: InvestorRequirements::default(),
+                    permissioned_list: None,
+                    identity_verification_program: None,
+                    identity_policy: IdentityPolicy::default(),
+                    reserved: [0; 6],
                 },
             })
         } else {
@@ -400,13 +417,15 @@ impl GovernanceProgramTest {
             authority: Some(realm_authority.pubkey()),
             config: RealmConfig {
                 council_mint: Some(council_mint),
-                reserved: [0; 6],
+                reserved: [0; 4],
 
                 community_mint_max_vote_weight_source:
                     MintMaxVoteWeightSource::FULL_SUPPLY_FRACTION,
                 min_community_weight_to_create_governance,
                 use_community_voter_weight_addin: false,
                 use_max_community_voter_weight_addin: false,
+                use_permissioned_list: false,
+                use_identity_verification: false,
             },
             voting_proposal_count: 0,
             reserved_v2: [0; 128],
@@ -674,6 +693,8 @@ impl GovernanceProgramTest {
             &token_owner.pubkey(),
             &token_owner.pubkey(),
             &self.bench.payer.pubkey(),
+            None,
+            None,
             amount,
             governing_mint,
         );
@@ -766,6 +787,8 @@ impl GovernanceProgramTest {
             &token_owner_record_cookie.token_owner.pubkey(),
             &token_owner_record_cookie.token_owner.pubkey(),
             &self.bench.payer.pubkey(),
+            None,
+            None,
             amount,
             governing_token_mint,
         );
@@ -1019,7 +1042,11 @@ impl GovernanceProgramTest {
                     max_community_voter_weight_addin,
                     council_voter_weight_addin: None,
                     council_max_vote_weight_addin: None,
-                    reserved: [0; 128],
+                    investor_requirements: InvestorRequirements::default(),
+                    permissioned_list: None,
+                    identity_verification_program: None,
+                    identity_policy: IdentityPolicy::default(),
+                    reserved: [0; 6],
                 },
             })
         }
@@ -1171,6 +1198,7 @@ impl GovernanceProgramTest {
             vote_threshold_percentage: VoteThresholdPercentage::YesVote(60),
             vote_tipping: spl_governance::state::enums::VoteTipping::Strict,
             proposal_cool_off_time: 0,
+            council_veto_vote_threshold_percentage: None,
         }
     }
 
@@ -1246,9 +1274,9 @@ impl GovernanceProgramTest {
             governed_account: governed_account_cookie.address,
             config: governance_config.clone(),
             proposals_count: 0,
-            reserved: [0; 6],
+            reserved: [0; 5],
             voting_proposal_count: 0,
-            reserved_v2: [0; 128],
+            reserved_v2: Reserved126::default(),
         };
 
         let default_signers = &[create_authority];
@@ -1415,9 +1443,9 @@ impl GovernanceProgramTest {
             governed_account: governed_program_cookie.address,
             config,
             proposals_count: 0,
-            reserved: [0; 6],
+            reserved: [0; 5],
             voting_proposal_count: 0,
-            reserved_v2: [0; 128],
+            reserved_v2: Reserved126::default(),
         };
 
         let program_governance_address = get_program_governance_address(
@@ -1539,9 +1567,9 @@ impl GovernanceProgramTest {
             governed_account: governed_mint_cookie.address,
             config: governance_config.clone(),
             proposals_count: 0,
-            reserved: [0; 6],
+            reserved: [0; 5],
             voting_proposal_count: 0,
-            reserved_v2: [0; 128],
+            reserved_v2: Reserved126::default(),
         };
 
         let mint_governance_address = get_mint_governance_address(
@@ -1623,9 +1651,9 @@ impl GovernanceProgramTest {
             governed_account: governed_token_cookie.address,
             config,
             proposals_count: 0,
-            reserved: [0; 6],
+            reserved: [0; 5],
             voting_proposal_count: 0,
-            reserved_v2: [0; 128],
+            reserved_v2: Reserved126::default(),
         };
 
         let token_governance_address = get_token_governance_address(
@@ -1748,6 +1776,8 @@ impl GovernanceProgramTest {
             &governance_authority.pubkey(),
             &self.bench.payer.pubkey(),
             voter_weight_record,
+            None,
+            None,
             &governance_cookie.account.realm,
             name.clone(),
             description_link.clone(),
@@ -2077,6 +2107,7 @@ impl GovernanceProgramTest {
             &proposal_cookie.address,
             &token_owner_record_cookie.address,
             &token_owner_record_cookie.token_owner.pubkey(),
+            None,
         );
 
         self.bench
@@ -2089,6 +2120,33 @@ impl GovernanceProgramTest {
         Ok(())
     }
 
+    #[allow(dead_code)]
+    pub async fn cancel_proposal_by_council_member(
+        &mut self,
+        proposal_cookie: &ProposalCookie,
+        proposal_owner_record_cookie: &TokenOwnerRecordCookie,
+        council_token_owner_record_cookie: &TokenOwnerRecordCookie,
+    ) -> Result<(), ProgramError> {
+        let cancel_proposal_transaction = cancel_proposal(
+            &self.program_id,
+            &proposal_cookie.realm,
+            &proposal_cookie.account.governance,
+            &proposal_cookie.address,
+            &proposal_owner_record_cookie.address,
+            &council_token_owner_record_cookie.token_owner.pubkey(),
+            Some(council_token_owner_record_cookie.address),
+        );
+
+        self.bench
+            .process_transaction(
+                &[cancel_proposal_transaction],
+                Some(&[&council_token_owner_record_cookie.token_owner]),
+            )
+            .await?;
+
+        Ok(())
+    }
+
     #[allow(dead_code)]
     pub async fn with_cast_vote(
         &mut self,
@@ -2142,6 +2200,8 @@ impl GovernanceProgramTest {
             &self.bench.payer.pubkey(),
             voter_weight_record,
             max_voter_weight_record,
+            None,
+            None,
             vote.clone(),
         );
 
@@ -2534,6 +2594,7 @@ impl GovernanceProgramTest {
             &proposal_cookie.account.token_owner_record,
             &governance_authority.pubkey(),
             &proposal_transaction_cookie.address,
+            &proposal_cookie.account.governance,
         );
 
         self.bench
@@ -2686,6 +2747,324 @@ impl GovernanceProgramTest {
         self.get_packed_account(address).await
     }
 
+    /// ----------- Capital Raise -----------------------------
+
+    #[allow(dead_code)]
+    pub async fn with_identity_verification_program(
+        &mut self,
+        realm_cookie: &RealmCookie,
+    ) -> Pubkey {
+        // Identity records are owned by the program but it's never invoked so it doesn't have to be deployed
+        let identity_verification_program = Pubkey::new_unique();
+
+        let set_realm_identity_verification_program_ix = set_realm_identity_verification_program(
+            &self.program_id,
+            &realm_cookie.address,
+            &realm_cookie.account.authority.unwrap(),
+            &self.bench.payer.pubkey(),
+            Some(identity_verification_program),
+        );
+
+        self.bench
+            .process_transaction(
+                &[set_realm_identity_verification_program_ix],
+                Some(&[realm_cookie.realm_authority.as_ref().unwrap()]),
+            )
+            .await
+            .unwrap();
+
+        identity_verification_program
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_capital_raise(
+        &mut self,
+        realm_cookie: &RealmCookie,
+    ) -> Result<CapitalRaiseCookie, ProgramError> {
+        let token_owner_record_cookie = self.with_community_token_deposit(realm_cookie).await?;
+
+        let lp_governed_account_cookie = self.with_governed_account().await;
+        let lp_governance_cookie = self
+            .with_governance(
+                realm_cookie,
+                &lp_governed_account_cookie,
+                &token_owner_record_cookie,
+            )
+            .await?;
+
+        let capital_governed_account_cookie = self.with_governed_account().await;
+        let capital_governance_cookie = self
+            .with_governance(
+                realm_cookie,
+                &capital_governed_account_cookie,
+                &token_owner_record_cookie,
+            )
+            .await?;
+
+        let capital_mint_keypair = Keypair::new();
+        let capital_mint_authority = Keypair::new();
+
+        self.bench
+            .create_mint(
+                &capital_mint_keypair,
+                &capital_mint_authority.pubkey(),
+                None,
+            )
+            .await;
+
+        let lp_mint_keypair = Keypair::new();
+        let lp_mint_authority = Keypair::new();
+
+        self.bench
+            .create_mint(&lp_mint_keypair, &lp_mint_authority.pubkey(), None)
+            .await;
+
+        let capital_holding_keypair = Keypair::new();
+
+        self.bench
+            .create_empty_token_account(
+                &capital_holding_keypair,
+                &capital_mint_keypair.pubkey(),
+                &capital_governance_cookie.address,
+            )
+            .await;
+
+        let clock = self.bench.get_clock().await;
+
+        let config = CapitalRaiseConfig {
+            price_numerator: 2,
+            price_denominator: 1,
+            min_raise: 100,
+            max_raise: 1_000_000,
+            min_ticket: 10,
+            max_ticket: 100_000,
+            open_at: clock.unix_timestamp,
+            close_at: clock.unix_timestamp + 3600,
+        };
+
+        let lp_holding_cookie = self
+            .bench
+            .with_token_account(
+                &lp_mint_keypair.pubkey(),
+                &lp_governance_cookie.address,
+                &lp_mint_authority,
+                config.max_raise * config.price_numerator / config.price_denominator,
+            )
+            .await;
+
+        let realm_authority = realm_cookie.realm_authority.as_ref().unwrap();
+
+        let create_capital_raise_ix = create_capital_raise(
+            &self.program_id,
+            &realm_cookie.address,
+            &realm_authority.pubkey(),
+            &capital_mint_keypair.pubkey(),
+            &lp_mint_keypair.pubkey(),
+            &lp_governance_cookie.address,
+            &capital_governance_cookie.address,
+            &capital_holding_keypair.pubkey(),
+            &lp_holding_cookie.address,
+            &self.bench.payer.pubkey(),
+            config.clone(),
+        );
+
+        self.bench
+            .process_transaction(&[create_capital_raise_ix], Some(&[realm_authority]))
+            .await?;
+
+        let account = CapitalRaise {
+            account_type: GovernanceAccountType::CapitalRaise,
+            realm: realm_cookie.address,
+            capital_mint: capital_mint_keypair.pubkey(),
+            lp_mint: lp_mint_keypair.pubkey(),
+            lp_governance: lp_governance_cookie.address,
+            capital_governance: capital_governance_cookie.address,
+            capital_holding_account: capital_holding_keypair.pubkey(),
+            lp_holding_account: lp_holding_cookie.address,
+            state: CapitalRaiseState::Open,
+            config,
+            total_raised: 0,
+            total_lp_issued: 0,
+        };
+
+        Ok(CapitalRaiseCookie {
+            address: get_capital_raise_address(&self.program_id, &realm_cookie.address),
+            account,
+            capital_mint_authority,
+        })
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_investor(
+        &mut self,
+        realm_cookie: &RealmCookie,
+        capital_raise_cookie: &CapitalRaiseCookie,
+        identity_verification_program: &Pubkey,
+        capital_amount: u64,
+    ) -> InvestorCookie {
+        let investor = Keypair::new();
+
+        // The investor pays for its CapitalContributionRecord
+        self.bench
+            .transfer_sol(&investor.pubkey(), 1_000_000_000)
+            .await;
+
+        let capital_token_account_cookie = self
+            .bench
+            .with_token_account(
+                &capital_raise_cookie.account.capital_mint,
+                &investor.pubkey(),
+                &capital_raise_cookie.capital_mint_authority,
+                capital_amount,
+            )
+            .await;
+
+        let lp_token_account_keypair = Keypair::new();
+
+        self.bench
+            .create_empty_token_account(
+                &lp_token_account_keypair,
+                &capital_raise_cookie.account.lp_mint,
+                &investor.pubkey(),
+            )
+            .await;
+
+        let identity_verification_record = self
+            .with_identity_verification_record(
+                realm_cookie,
+                identity_verification_program,
+                &investor.pubkey(),
+            )
+            .await;
+
+        InvestorCookie {
+            investor,
+            capital_token_account: capital_token_account_cookie.address,
+            lp_token_account: lp_token_account_keypair.pubkey(),
+            identity_verification_record,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_identity_verification_record(
+        &mut self,
+        realm_cookie: &RealmCookie,
+        identity_verification_program: &Pubkey,
+        subject: &Pubkey,
+    ) -> Pubkey {
+        let identity_verification_record = Pubkey::find_program_address(
+            &[b"identity", realm_cookie.address.as_ref(), subject.as_ref()],
+            identity_verification_program,
+        )
+        .0;
+
+        let clock = self.bench.get_clock().await;
+        let expires_at = clock.unix_timestamp + 365 * 24 * 3600;
+
+        let identity = Identity {
+            discriminator: get_identity_record_discriminator(),
+            bump: 0,
+            ia_status: IdentityStatus::Approved,
+            aml_status: IdentityStatus::Approved,
+            kyc_status: IdentityStatus::Approved,
+            authority: Pubkey::new_unique(),
+            ia_approved_at: clock.unix_timestamp,
+            ia_expires_at: expires_at,
+            aml_approved_at: clock.unix_timestamp,
+            aml_expires_at: expires_at,
+            kyc_approved_at: clock.unix_timestamp,
+            kyc_expires_at: expires_at,
+            jurisdiction: [0, 0],
+            investor_class: 0,
+            attested_at: 0,
+        };
+
+        let data = identity.try_to_vec().unwrap();
+
+        // The record is written directly because the identity verification program isn't deployed
+        let account = Account {
+            lamports: self.bench.rent.minimum_balance(data.len()),
+            data,
+            owner: *identity_verification_program,
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        self.bench.context.set_account(
+            &identity_verification_record,
+            &AccountSharedData::from(account),
+        );
+
+        identity_verification_record
+    }
+
+    #[allow(dead_code)]
+    pub async fn deposit_capital(
+        &mut self,
+        realm_cookie: &RealmCookie,
+        capital_raise_cookie: &CapitalRaiseCookie,
+        investor_cookie: &InvestorCookie,
+        identity_verification_program: &Pubkey,
+        amount: u64,
+    ) -> Result<(), ProgramError> {
+        self.deposit_capital_using_instruction(
+            realm_cookie,
+            capital_raise_cookie,
+            investor_cookie,
+            identity_verification_program,
+            amount,
+            NopOverride,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn deposit_capital_using_instruction<F: Fn(&mut Instruction)>(
+        &mut self,
+        realm_cookie: &RealmCookie,
+        capital_raise_cookie: &CapitalRaiseCookie,
+        investor_cookie: &InvestorCookie,
+        identity_verification_program: &Pubkey,
+        amount: u64,
+        instruction_override: F,
+    ) -> Result<(), ProgramError> {
+        let mut deposit_capital_ix = deposit_capital(
+            &self.program_id,
+            &realm_cookie.address,
+            &capital_raise_cookie.account.lp_governance,
+            &investor_cookie.investor.pubkey(),
+            &investor_cookie.capital_token_account,
+            &capital_raise_cookie.account.capital_holding_account,
+            &investor_cookie.lp_token_account,
+            &capital_raise_cookie.account.lp_holding_account,
+            &capital_raise_cookie.account.lp_mint,
+            &investor_cookie.identity_verification_record,
+            identity_verification_program,
+            amount,
+        );
+
+        instruction_override(&mut deposit_capital_ix);
+
+        self.bench
+            .process_transaction(&[deposit_capital_ix], Some(&[&investor_cookie.investor]))
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_capital_raise_account(&mut self, address: &Pubkey) -> CapitalRaise {
+        self.bench.get_borsh_account::<CapitalRaise>(address).await
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_capital_contribution_record_account(
+        &mut self,
+        address: &Pubkey,
+    ) -> CapitalContributionRecord {
+        self.bench
+            .get_borsh_account::<CapitalContributionRecord>(address)
+            .await
+    }
+
     /// ----------- VoterWeight Addin -----------------------------
 
     #[allow(dead_code)]
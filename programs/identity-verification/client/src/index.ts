@@ -1,6 +1,7 @@
 import {IdentityVerificationService} from "./services/identity-verification-service";
 import {Connection, PublicKey, TransactionInstruction} from "@solana/web3.js";
 import {IdentityRecord} from "./models/identity-record";
+import {ClosePolicy} from "./models/close-policy";
 
 /**
  * Creates and identity verification record
@@ -9,6 +10,8 @@ import {IdentityRecord} from "./models/identity-record";
  * @param signer The signer of the transaction and of whom the record will be created.
  * @param group The public key of the group this record belongs to.
  * @param authority The public key of the account who will have write access to the record.
+ * @param closePolicy Who has to sign to delete the record.
+ * @param payer The account paying the rent of the record, it gets the rent back when the record is deleted.
  */
 export async function createIdentityRecordInstruction(
     connection: Connection,
@@ -16,10 +19,12 @@ export async function createIdentityRecordInstruction(
     signer: PublicKey,
     group: PublicKey,
     authority: PublicKey,
+    closePolicy: ClosePolicy = ClosePolicy.authority,
+    payer: PublicKey = signer,
 ): Promise<TransactionInstruction> {
 
     const service = new IdentityVerificationService(connection, programId);
-    return service.createRecordInstruction(signer, group, authority);
+    return service.createRecordInstruction(signer, group, authority, closePolicy, payer);
 
 }
 
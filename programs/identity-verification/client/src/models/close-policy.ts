@@ -0,0 +1,19 @@
+/**
+ * Who has to sign to delete a record
+ */
+export enum ClosePolicy {
+    /// only the authority of the record
+    authority,
+    /// only the subject of the record
+    subject,
+    /// both the authority and the subject of the record
+    authorityAndSubject
+}
+
+/**
+ * Encodes a close policy to pass it as an instruction argument, e.g. `{ authority: {} }`
+ * @param closePolicy close policy to encode
+ */
+export function toClosePolicyVariant(closePolicy: ClosePolicy): { [variant: string]: {} } {
+    return {[ClosePolicy[closePolicy]]: {}}
+}
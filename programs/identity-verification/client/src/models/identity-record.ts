@@ -1,14 +1,18 @@
 import {PublicKey} from "@solana/web3.js";
-import {IdentityStatus} from "./identity-status";
+import {BN} from "@project-serum/anchor";
+import {fromIdentityStatusVariant, IdentityStatus, IdentityStatusVariant} from "./identity-status";
 
 export type BumpSeed = number
 
 export interface IdentityRecordParams {
     bump: BumpSeed,
-    iaStatus: IdentityStatus,
-    amlStatus: IdentityStatus,
-    kycStatus: IdentityStatus,
-    authority: PublicKey
+    iaStatus: IdentityStatusVariant,
+    amlStatus: IdentityStatusVariant,
+    kycStatus: IdentityStatusVariant,
+    authority: PublicKey,
+    iaExpiresAt: BN,
+    amlExpiresAt: BN,
+    kycExpiresAt: BN
 }
 
 export class IdentityRecord {
@@ -26,10 +30,13 @@ export class IdentityRecord {
 
         return new IdentityRecord(
             info.bump,
-            info.iaStatus as IdentityStatus,
-            info.amlStatus as IdentityStatus,
-            info.kycStatus as IdentityStatus,
+            fromIdentityStatusVariant(info.iaStatus),
+            fromIdentityStatusVariant(info.amlStatus),
+            fromIdentityStatusVariant(info.kycStatus),
             info.authority,
+            info.iaExpiresAt.toNumber(),
+            info.amlExpiresAt.toNumber(),
+            info.kycExpiresAt.toNumber(),
             address
         )
 
@@ -56,14 +63,24 @@ export class IdentityRecord {
     /// Account who has update authority over the account
     readonly authority: PublicKey
 
+    /// Unix timestamp the accreditation approval lapses at, 0 when not approved
+    readonly iaExpiresAt: number
+
+    /// Unix timestamp the AML approval lapses at, 0 when not approved
+    readonly amlExpiresAt: number
+
+    /// Unix timestamp the KYC approval lapses at, 0 when not approved
+    readonly kycExpiresAt: number
+
     /// Address of the record
     readonly address: PublicKey
 
-    /// derived property that checks that all statuses are approved
+    /// derived property that checks that all statuses are approved and none of the approvals has lapsed
     get isVerified(): boolean {
-        return this.amlStatus == IdentityStatus.approved &&
-            this.iaStatus == IdentityStatus.approved &&
-            this.kycStatus == IdentityStatus.approved
+        const now = Date.now() / 1000
+        return this.amlStatus == IdentityStatus.approved && now < this.amlExpiresAt &&
+            this.iaStatus == IdentityStatus.approved && now < this.iaExpiresAt &&
+            this.kycStatus == IdentityStatus.approved && now < this.kycExpiresAt
     }
 
     /// get the combined status of aml, kyc and ia
@@ -122,6 +139,9 @@ export class IdentityRecord {
      * @param amlStatus AML status
      * @param kycStatus KYC status
      * @param authority Account that has the authority to update the idv record
+     * @param iaExpiresAt Unix timestamp the accreditation approval lapses at
+     * @param amlExpiresAt Unix timestamp the AML approval lapses at
+     * @param kycExpiresAt Unix timestamp the KYC approval lapses at
      * @param address Address of the account the record is about
      */
     private constructor(
@@ -130,6 +150,9 @@ export class IdentityRecord {
         amlStatus: IdentityStatus,
         kycStatus: IdentityStatus,
         authority: PublicKey,
+        iaExpiresAt: number,
+        amlExpiresAt: number,
+        kycExpiresAt: number,
         address: PublicKey
     ) {
         this.bump = bump
@@ -137,6 +160,9 @@ export class IdentityRecord {
         this.amlStatus = amlStatus
         this.kycStatus = kycStatus
         this.authority = authority
+        this.iaExpiresAt = iaExpiresAt
+        this.amlExpiresAt = amlExpiresAt
+        this.kycExpiresAt = kycExpiresAt
         this.address = address;
     }
 
@@ -10,4 +10,33 @@ export enum IdentityStatus {
     approved,
     /// the user has failed identity verification for the related piece of information (kyc, aml, accreditation)
     denied
-}
\ No newline at end of file
+}
+
+/**
+ * Status as it is encoded by anchor for the on-chain program, e.g. `{ approved: {} }`
+ */
+export type IdentityStatusVariant = { [variant: string]: {} }
+
+/**
+ * Encodes a status to pass it as an instruction argument
+ * @param status status to encode
+ */
+export function toIdentityStatusVariant(status: IdentityStatus): IdentityStatusVariant {
+    return {[IdentityStatus[status]]: {}}
+}
+
+/**
+ * Decodes a status read from an on-chain account
+ * @param variant status as decoded by anchor
+ */
+export function fromIdentityStatusVariant(variant: IdentityStatusVariant): IdentityStatus {
+
+    const status = IdentityStatus[Object.keys(variant)[0] as keyof typeof IdentityStatus];
+
+    if (status === undefined) {
+        throw new Error(`Unknown identity status ${JSON.stringify(variant)}`)
+    }
+
+    return status
+
+}
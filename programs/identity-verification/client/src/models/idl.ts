@@ -7,6 +7,11 @@ export type IdentityVerification = {
       "accounts": [
         {
           "name": "signer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
           "isMut": true,
           "isSigner": true
         },
@@ -34,6 +39,12 @@ export type IdentityVerification = {
         {
           "name": "group",
           "type": "publicKey"
+        },
+        {
+          "name": "closePolicy",
+          "type": {
+            "defined": "ClosePolicy"
+          }
         }
       ]
     },
@@ -45,11 +56,21 @@ export type IdentityVerification = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "groupAccount",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "subject",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "frozenSubject",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "authority",
           "isMut": false,
@@ -64,6 +85,10 @@ export type IdentityVerification = {
         {
           "name": "group",
           "type": "publicKey"
+        },
+        {
+          "name": "expiresAt",
+          "type": "i64"
         }
       ]
     },
@@ -75,11 +100,21 @@ export type IdentityVerification = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "groupAccount",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "subject",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "frozenSubject",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "authority",
           "isMut": false,
@@ -105,11 +140,21 @@ export type IdentityVerification = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "groupAccount",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "subject",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "frozenSubject",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "authority",
           "isMut": false,
@@ -127,7 +172,13 @@ export type IdentityVerification = {
         },
         {
           "name": "status",
-          "type": "u8"
+          "type": {
+            "defined": "IdentityStatus"
+          }
+        },
+        {
+          "name": "expiresAt",
+          "type": "i64"
         }
       ]
     },
@@ -139,11 +190,21 @@ export type IdentityVerification = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "groupAccount",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "subject",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "frozenSubject",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "authority",
           "isMut": false,
@@ -161,7 +222,13 @@ export type IdentityVerification = {
         },
         {
           "name": "status",
-          "type": "u8"
+          "type": {
+            "defined": "IdentityStatus"
+          }
+        },
+        {
+          "name": "expiresAt",
+          "type": "i64"
         }
       ]
     },
@@ -173,11 +240,21 @@ export type IdentityVerification = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "groupAccount",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "subject",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "frozenSubject",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "authority",
           "isMut": false,
@@ -195,30 +272,41 @@ export type IdentityVerification = {
         },
         {
           "name": "status",
-          "type": "u8"
+          "type": {
+            "defined": "IdentityStatus"
+          }
+        },
+        {
+          "name": "expiresAt",
+          "type": "i64"
         }
       ]
     },
     {
-      "name": "transferAuthority",
+      "name": "updateJurisdiction",
       "accounts": [
         {
           "name": "record",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "groupAccount",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "subject",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "transferTo",
+          "name": "frozenSubject",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "transferFrom",
+          "name": "authority",
           "isMut": false,
           "isSigner": true
         }
@@ -231,11 +319,20 @@ export type IdentityVerification = {
         {
           "name": "group",
           "type": "publicKey"
+        },
+        {
+          "name": "jurisdiction",
+          "type": {
+            "array": [
+              "u8",
+              2
+            ]
+          }
         }
       ]
     },
     {
-      "name": "deleteRecord",
+      "name": "updateInvestorClass",
       "accounts": [
         {
           "name": "record",
@@ -243,19 +340,24 @@ export type IdentityVerification = {
           "isSigner": false
         },
         {
-          "name": "signer",
-          "isMut": true,
-          "isSigner": true
+          "name": "groupAccount",
+          "isMut": false,
+          "isSigner": false
         },
         {
           "name": "subject",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "frozenSubject",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
         }
       ],
       "args": [
@@ -266,80 +368,35 @@ export type IdentityVerification = {
         {
           "name": "group",
           "type": "publicKey"
+        },
+        {
+          "name": "investorClass",
+          "type": "u8"
         }
       ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "identityRecord",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "iaStatus",
-            "type": "u8"
-          },
-          {
-            "name": "amlStatus",
-            "type": "u8"
-          },
-          {
-            "name": "kycStatus",
-            "type": "u8"
-          },
-          {
-            "name": "authority",
-            "type": "publicKey"
-          }
-        ]
-      }
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "UnknownStatus",
-      "msg": "Unexpected Status"
     },
     {
-      "code": 6001,
-      "name": "NotAuthorized",
-      "msg": "Not Authorized"
-    }
-  ]
-};
-
-export const IDL: IdentityVerification = {
-  "version": "0.2.0",
-  "name": "identity_verification",
-  "instructions": [
-    {
-      "name": "createRecord",
+      "name": "transferAuthority",
       "accounts": [
-        {
-          "name": "signer",
-          "isMut": true,
-          "isSigner": true
-        },
         {
           "name": "record",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "subject",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "authority",
+          "name": "transferTo",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "transferFrom",
+          "isMut": false,
+          "isSigner": true
         }
       ],
       "args": [
@@ -354,22 +411,32 @@ export const IDL: IdentityVerification = {
       ]
     },
     {
-      "name": "approve",
+      "name": "deleteRecord",
       "accounts": [
         {
           "name": "record",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "subject",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "authority",
+          "name": "payer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         }
       ],
       "args": [
@@ -384,7 +451,7 @@ export const IDL: IdentityVerification = {
       ]
     },
     {
-      "name": "deny",
+      "name": "withdrawConsent",
       "accounts": [
         {
           "name": "record",
@@ -394,12 +461,17 @@ export const IDL: IdentityVerification = {
         {
           "name": "subject",
           "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "authority",
+          "name": "systemProgram",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         }
       ],
       "args": [
@@ -414,13 +486,18 @@ export const IDL: IdentityVerification = {
       ]
     },
     {
-      "name": "updateIaStatus",
+      "name": "migrateRecord",
       "accounts": [
         {
           "name": "record",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "groupAccount",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "subject",
           "isMut": false,
@@ -428,8 +505,13 @@ export const IDL: IdentityVerification = {
         },
         {
           "name": "authority",
-          "isMut": false,
+          "isMut": true,
           "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
@@ -442,28 +524,33 @@ export const IDL: IdentityVerification = {
           "type": "publicKey"
         },
         {
-          "name": "status",
-          "type": "u8"
+          "name": "expiresAt",
+          "type": "i64"
         }
       ]
     },
     {
-      "name": "updateAmlStatus",
+      "name": "createGroup",
       "accounts": [
         {
-          "name": "record",
+          "name": "authority",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "subject",
+          "name": "groupKey",
           "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "groupAccount",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "authority",
+          "name": "systemProgram",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         }
       ],
       "args": [
@@ -474,26 +561,17 @@ export const IDL: IdentityVerification = {
         {
           "name": "group",
           "type": "publicKey"
-        },
-        {
-          "name": "status",
-          "type": "u8"
         }
       ]
     },
     {
-      "name": "updateKycStatus",
+      "name": "addVerifier",
       "accounts": [
         {
-          "name": "record",
+          "name": "groupAccount",
           "isMut": true,
           "isSigner": false
         },
-        {
-          "name": "subject",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "authority",
           "isMut": false,
@@ -510,31 +588,27 @@ export const IDL: IdentityVerification = {
           "type": "publicKey"
         },
         {
-          "name": "status",
-          "type": "u8"
+          "name": "verifier",
+          "type": "publicKey"
+        },
+        {
+          "name": "role",
+          "type": {
+            "defined": "VerifierRole"
+          }
         }
       ]
     },
     {
-      "name": "transferAuthority",
+      "name": "removeVerifier",
       "accounts": [
         {
-          "name": "record",
+          "name": "groupAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "subject",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "transferTo",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "transferFrom",
+          "name": "authority",
           "isMut": false,
           "isSigner": true
         }
@@ -547,11 +621,21 @@ export const IDL: IdentityVerification = {
         {
           "name": "group",
           "type": "publicKey"
+        },
+        {
+          "name": "verifier",
+          "type": "publicKey"
+        },
+        {
+          "name": "role",
+          "type": {
+            "defined": "VerifierRole"
+          }
         }
       ]
     },
     {
-      "name": "deleteRecord",
+      "name": "importAttestation",
       "accounts": [
         {
           "name": "record",
@@ -559,17 +643,22 @@ export const IDL: IdentityVerification = {
           "isSigner": false
         },
         {
-          "name": "signer",
-          "isMut": true,
-          "isSigner": true
+          "name": "groupAccount",
+          "isMut": false,
+          "isSigner": false
         },
         {
           "name": "subject",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "frozenSubject",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
           "isMut": false,
           "isSigner": false
         }
@@ -582,11 +671,208 @@ export const IDL: IdentityVerification = {
         {
           "name": "group",
           "type": "publicKey"
-        }
-      ]
+        },
+        {
+          "name": "attestation",
+          "type": {
+            "defined": "Attestation"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createFreezeConfig",
+      "accounts": [
+        {
+          "name": "freezeConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "upgradeAuthority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "authority",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "setFreezeAuthority",
+      "accounts": [
+        {
+          "name": "freezeConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "authority",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "freezeSubject",
+      "accounts": [
+        {
+          "name": "freezeConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "frozenSubject",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "subject",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "unfreezeSubject",
+      "accounts": [
+        {
+          "name": "freezeConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "frozenSubject",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "subject",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
     }
   ],
   "accounts": [
+    {
+      "name": "freezeConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "frozenSubject",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "subject",
+            "type": "publicKey"
+          },
+          {
+            "name": "frozenBy",
+            "type": "publicKey"
+          },
+          {
+            "name": "frozenAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "group",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "group",
+            "type": "publicKey"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "verifiers",
+            "type": {
+              "vec": {
+                "defined": "Verifier"
+              }
+            }
+          }
+        ]
+      }
+    },
     {
       "name": "identityRecord",
       "type": {
@@ -598,34 +884,2286 @@ export const IDL: IdentityVerification = {
           },
           {
             "name": "iaStatus",
-            "type": "u8"
+            "type": {
+              "defined": "IdentityStatus"
+            }
           },
           {
             "name": "amlStatus",
-            "type": "u8"
+            "type": {
+              "defined": "IdentityStatus"
+            }
           },
           {
             "name": "kycStatus",
-            "type": "u8"
+            "type": {
+              "defined": "IdentityStatus"
+            }
           },
           {
             "name": "authority",
             "type": "publicKey"
+          },
+          {
+            "name": "iaApprovedAt",
+            "type": "i64"
+          },
+          {
+            "name": "iaExpiresAt",
+            "type": "i64"
+          },
+          {
+            "name": "amlApprovedAt",
+            "type": "i64"
+          },
+          {
+            "name": "amlExpiresAt",
+            "type": "i64"
+          },
+          {
+            "name": "kycApprovedAt",
+            "type": "i64"
+          },
+          {
+            "name": "kycExpiresAt",
+            "type": "i64"
+          },
+          {
+            "name": "jurisdiction",
+            "type": {
+              "array": [
+                "u8",
+                2
+              ]
+            }
+          },
+          {
+            "name": "investorClass",
+            "type": "u8"
+          },
+          {
+            "name": "attestedAt",
+            "type": "i64"
+          },
+          {
+            "name": "historyIndex",
+            "type": "u8"
+          },
+          {
+            "name": "history",
+            "type": {
+              "array": [
+                {
+                  "defined": "StatusChange"
+                },
+                8
+              ]
+            }
+          },
+          {
+            "name": "payer",
+            "type": "publicKey"
+          },
+          {
+            "name": "closePolicy",
+            "type": {
+              "defined": "ClosePolicy"
+            }
           }
         ]
       }
     }
   ],
-  "errors": [
+  "types": [
     {
-      "code": 6000,
-      "name": "UnknownStatus",
-      "msg": "Unexpected Status"
+      "name": "Attestation",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "subject",
+            "type": "publicKey"
+          },
+          {
+            "name": "group",
+            "type": "publicKey"
+          },
+          {
+            "name": "iaStatus",
+            "type": {
+              "option": {
+                "defined": "IdentityStatus"
+              }
+            }
+          },
+          {
+            "name": "amlStatus",
+            "type": {
+              "option": {
+                "defined": "IdentityStatus"
+              }
+            }
+          },
+          {
+            "name": "kycStatus",
+            "type": {
+              "option": {
+                "defined": "IdentityStatus"
+              }
+            }
+          },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          },
+          {
+            "name": "issuedAt",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6001,
-      "name": "NotAuthorized",
-      "msg": "Not Authorized"
+      "name": "Verifier",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "key",
+            "type": "publicKey"
+          },
+          {
+            "name": "role",
+            "type": {
+              "defined": "VerifierRole"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "StatusChange",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "check",
+            "type": {
+              "defined": "IdentityCheck"
+            }
+          },
+          {
+            "name": "previousStatus",
+            "type": {
+              "defined": "IdentityStatus"
+            }
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": "IdentityStatus"
+            }
+          },
+          {
+            "name": "verifier",
+            "type": "publicKey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyIdentityRecord",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "iaStatus",
+            "type": {
+              "defined": "IdentityStatus"
+            }
+          },
+          {
+            "name": "amlStatus",
+            "type": {
+              "defined": "IdentityStatus"
+            }
+          },
+          {
+            "name": "kycStatus",
+            "type": {
+              "defined": "IdentityStatus"
+            }
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "VerifierRole",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "KycProvider"
+          },
+          {
+            "name": "AmlProvider"
+          },
+          {
+            "name": "AccreditationReviewer"
+          }
+        ]
+      }
+    },
+    {
+      "name": "IdentityStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Initial"
+          },
+          {
+            "name": "Started"
+          },
+          {
+            "name": "Approved"
+          },
+          {
+            "name": "Denied"
+          }
+        ]
+      }
+    },
+    {
+      "name": "IdentityCheck",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Accreditation"
+          },
+          {
+            "name": "Aml"
+          },
+          {
+            "name": "Kyc"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ClosePolicy",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Authority"
+          },
+          {
+            "name": "Subject"
+          },
+          {
+            "name": "AuthorityAndSubject"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "RecordCreated",
+      "fields": [
+        {
+          "name": "record",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "subject",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RecordApproved",
+      "fields": [
+        {
+          "name": "record",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "subject",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "verifier",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "expiresAt",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RecordDenied",
+      "fields": [
+        {
+          "name": "record",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "subject",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "verifier",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "StatusUpdated",
+      "fields": [
+        {
+          "name": "record",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "subject",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "check",
+          "type": {
+            "defined": "IdentityCheck"
+          },
+          "index": false
+        },
+        {
+          "name": "previousStatus",
+          "type": {
+            "defined": "IdentityStatus"
+          },
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "IdentityStatus"
+          },
+          "index": false
+        },
+        {
+          "name": "verifier",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "expiresAt",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InvestorProfileUpdated",
+      "fields": [
+        {
+          "name": "record",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "subject",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "jurisdiction",
+          "type": {
+            "array": [
+              "u8",
+              2
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "investorClass",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "verifier",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AuthorityTransferred",
+      "fields": [
+        {
+          "name": "record",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "subject",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RecordDeleted",
+      "fields": [
+        {
+          "name": "record",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "subject",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "deletedBy",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ConsentWithdrawn",
+      "fields": [
+        {
+          "name": "record",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "subject",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SubjectFrozen",
+      "fields": [
+        {
+          "name": "subject",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "frozenBy",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SubjectUnfrozen",
+      "fields": [
+        {
+          "name": "subject",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "unfrozenBy",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "NotAuthorized",
+      "msg": "Not Authorized"
+    },
+    {
+      "code": 6001,
+      "name": "InvalidExpiry",
+      "msg": "Approval expiry must be in the future"
+    },
+    {
+      "code": 6002,
+      "name": "RecordAlreadyMigrated",
+      "msg": "Record has already been migrated"
+    },
+    {
+      "code": 6003,
+      "name": "InvalidRecord",
+      "msg": "Account is not an identity record"
+    },
+    {
+      "code": 6004,
+      "name": "VerifierAlreadyRegistered",
+      "msg": "Verifier is already registered with this role"
+    },
+    {
+      "code": 6005,
+      "name": "VerifierNotFound",
+      "msg": "Verifier is not registered with this role"
+    },
+    {
+      "code": 6006,
+      "name": "TooManyVerifiers",
+      "msg": "Group has reached the maximum number of verifiers"
+    },
+    {
+      "code": 6007,
+      "name": "InvalidJurisdiction",
+      "msg": "Jurisdiction must be an ISO 3166-1 alpha-2 code"
+    },
+    {
+      "code": 6008,
+      "name": "InvalidInvestorClass",
+      "msg": "Unknown investor class flag"
+    },
+    {
+      "code": 6009,
+      "name": "MissingSignatureVerification",
+      "msg": "Expected an ed25519 signature verification instruction"
+    },
+    {
+      "code": 6010,
+      "name": "InvalidAttestation",
+      "msg": "Attestation does not match the signed message"
+    },
+    {
+      "code": 6011,
+      "name": "StaleAttestation",
+      "msg": "Attestation is older than the last imported attestation"
+    },
+    {
+      "code": 6012,
+      "name": "SubjectFrozen",
+      "msg": "Subject is frozen"
+    },
+    {
+      "code": 6013,
+      "name": "EmptyAttestation",
+      "msg": "Attestation must carry at least one status"
+    }
+  ]
+};
+
+export const IDL: IdentityVerification = {
+  "version": "0.2.0",
+  "name": "identity_verification",
+  "instructions": [
+    {
+      "name": "createRecord",
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "record",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "group",
+          "type": "publicKey"
+        },
+        {
+          "name": "closePolicy",
+          "type": {
+            "defined": "ClosePolicy"
+          }
+        }
+      ]
+    },
+    {
+      "name": "approve",
+      "accounts": [
+        {
+          "name": "record",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "groupAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "subject",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "frozenSubject",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "group",
+          "type": "publicKey"
+        },
+        {
+          "name": "expiresAt",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "deny",
+      "accounts": [
+        {
+          "name": "record",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "groupAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "subject",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "frozenSubject",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "group",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "updateIaStatus",
+      "accounts": [
+        {
+          "name": "record",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "groupAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "subject",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "frozenSubject",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "group",
+          "type": "publicKey"
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "IdentityStatus"
+          }
+        },
+        {
+          "name": "expiresAt",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "updateAmlStatus",
+      "accounts": [
+        {
+          "name": "record",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "groupAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "subject",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "frozenSubject",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "group",
+          "type": "publicKey"
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "IdentityStatus"
+          }
+        },
+        {
+          "name": "expiresAt",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "updateKycStatus",
+      "accounts": [
+        {
+          "name": "record",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "groupAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "subject",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "frozenSubject",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "group",
+          "type": "publicKey"
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "IdentityStatus"
+          }
+        },
+        {
+          "name": "expiresAt",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "updateJurisdiction",
+      "accounts": [
+        {
+          "name": "record",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "groupAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "subject",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "frozenSubject",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "group",
+          "type": "publicKey"
+        },
+        {
+          "name": "jurisdiction",
+          "type": {
+            "array": [
+              "u8",
+              2
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateInvestorClass",
+      "accounts": [
+        {
+          "name": "record",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "groupAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "subject",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "frozenSubject",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "group",
+          "type": "publicKey"
+        },
+        {
+          "name": "investorClass",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "transferAuthority",
+      "accounts": [
+        {
+          "name": "record",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "subject",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transferTo",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transferFrom",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "group",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "deleteRecord",
+      "accounts": [
+        {
+          "name": "record",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "subject",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "group",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "withdrawConsent",
+      "accounts": [
+        {
+          "name": "record",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "subject",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "group",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "migrateRecord",
+      "accounts": [
+        {
+          "name": "record",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "groupAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "subject",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "group",
+          "type": "publicKey"
+        },
+        {
+          "name": "expiresAt",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "createGroup",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "groupKey",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "groupAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "group",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "addVerifier",
+      "accounts": [
+        {
+          "name": "groupAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "group",
+          "type": "publicKey"
+        },
+        {
+          "name": "verifier",
+          "type": "publicKey"
+        },
+        {
+          "name": "role",
+          "type": {
+            "defined": "VerifierRole"
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeVerifier",
+      "accounts": [
+        {
+          "name": "groupAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "group",
+          "type": "publicKey"
+        },
+        {
+          "name": "verifier",
+          "type": "publicKey"
+        },
+        {
+          "name": "role",
+          "type": {
+            "defined": "VerifierRole"
+          }
+        }
+      ]
+    },
+    {
+      "name": "importAttestation",
+      "accounts": [
+        {
+          "name": "record",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "groupAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "subject",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "frozenSubject",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "group",
+          "type": "publicKey"
+        },
+        {
+          "name": "attestation",
+          "type": {
+            "defined": "Attestation"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createFreezeConfig",
+      "accounts": [
+        {
+          "name": "freezeConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "upgradeAuthority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "authority",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "setFreezeAuthority",
+      "accounts": [
+        {
+          "name": "freezeConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "authority",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "freezeSubject",
+      "accounts": [
+        {
+          "name": "freezeConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "frozenSubject",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "subject",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "unfreezeSubject",
+      "accounts": [
+        {
+          "name": "freezeConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "frozenSubject",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "subject",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "freezeConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "frozenSubject",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "subject",
+            "type": "publicKey"
+          },
+          {
+            "name": "frozenBy",
+            "type": "publicKey"
+          },
+          {
+            "name": "frozenAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "group",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "group",
+            "type": "publicKey"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "verifiers",
+            "type": {
+              "vec": {
+                "defined": "Verifier"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "identityRecord",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "iaStatus",
+            "type": {
+              "defined": "IdentityStatus"
+            }
+          },
+          {
+            "name": "amlStatus",
+            "type": {
+              "defined": "IdentityStatus"
+            }
+          },
+          {
+            "name": "kycStatus",
+            "type": {
+              "defined": "IdentityStatus"
+            }
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "iaApprovedAt",
+            "type": "i64"
+          },
+          {
+            "name": "iaExpiresAt",
+            "type": "i64"
+          },
+          {
+            "name": "amlApprovedAt",
+            "type": "i64"
+          },
+          {
+            "name": "amlExpiresAt",
+            "type": "i64"
+          },
+          {
+            "name": "kycApprovedAt",
+            "type": "i64"
+          },
+          {
+            "name": "kycExpiresAt",
+            "type": "i64"
+          },
+          {
+            "name": "jurisdiction",
+            "type": {
+              "array": [
+                "u8",
+                2
+              ]
+            }
+          },
+          {
+            "name": "investorClass",
+            "type": "u8"
+          },
+          {
+            "name": "attestedAt",
+            "type": "i64"
+          },
+          {
+            "name": "historyIndex",
+            "type": "u8"
+          },
+          {
+            "name": "history",
+            "type": {
+              "array": [
+                {
+                  "defined": "StatusChange"
+                },
+                8
+              ]
+            }
+          },
+          {
+            "name": "payer",
+            "type": "publicKey"
+          },
+          {
+            "name": "closePolicy",
+            "type": {
+              "defined": "ClosePolicy"
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "Attestation",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "subject",
+            "type": "publicKey"
+          },
+          {
+            "name": "group",
+            "type": "publicKey"
+          },
+          {
+            "name": "iaStatus",
+            "type": {
+              "option": {
+                "defined": "IdentityStatus"
+              }
+            }
+          },
+          {
+            "name": "amlStatus",
+            "type": {
+              "option": {
+                "defined": "IdentityStatus"
+              }
+            }
+          },
+          {
+            "name": "kycStatus",
+            "type": {
+              "option": {
+                "defined": "IdentityStatus"
+              }
+            }
+          },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          },
+          {
+            "name": "issuedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Verifier",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "key",
+            "type": "publicKey"
+          },
+          {
+            "name": "role",
+            "type": {
+              "defined": "VerifierRole"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "StatusChange",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "check",
+            "type": {
+              "defined": "IdentityCheck"
+            }
+          },
+          {
+            "name": "previousStatus",
+            "type": {
+              "defined": "IdentityStatus"
+            }
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": "IdentityStatus"
+            }
+          },
+          {
+            "name": "verifier",
+            "type": "publicKey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyIdentityRecord",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "iaStatus",
+            "type": {
+              "defined": "IdentityStatus"
+            }
+          },
+          {
+            "name": "amlStatus",
+            "type": {
+              "defined": "IdentityStatus"
+            }
+          },
+          {
+            "name": "kycStatus",
+            "type": {
+              "defined": "IdentityStatus"
+            }
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "VerifierRole",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "KycProvider"
+          },
+          {
+            "name": "AmlProvider"
+          },
+          {
+            "name": "AccreditationReviewer"
+          }
+        ]
+      }
+    },
+    {
+      "name": "IdentityStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Initial"
+          },
+          {
+            "name": "Started"
+          },
+          {
+            "name": "Approved"
+          },
+          {
+            "name": "Denied"
+          }
+        ]
+      }
+    },
+    {
+      "name": "IdentityCheck",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Accreditation"
+          },
+          {
+            "name": "Aml"
+          },
+          {
+            "name": "Kyc"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ClosePolicy",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Authority"
+          },
+          {
+            "name": "Subject"
+          },
+          {
+            "name": "AuthorityAndSubject"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "RecordCreated",
+      "fields": [
+        {
+          "name": "record",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "subject",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RecordApproved",
+      "fields": [
+        {
+          "name": "record",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "subject",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "verifier",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "expiresAt",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RecordDenied",
+      "fields": [
+        {
+          "name": "record",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "subject",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "verifier",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "StatusUpdated",
+      "fields": [
+        {
+          "name": "record",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "subject",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "check",
+          "type": {
+            "defined": "IdentityCheck"
+          },
+          "index": false
+        },
+        {
+          "name": "previousStatus",
+          "type": {
+            "defined": "IdentityStatus"
+          },
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "IdentityStatus"
+          },
+          "index": false
+        },
+        {
+          "name": "verifier",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "expiresAt",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InvestorProfileUpdated",
+      "fields": [
+        {
+          "name": "record",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "subject",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "jurisdiction",
+          "type": {
+            "array": [
+              "u8",
+              2
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "investorClass",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "verifier",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AuthorityTransferred",
+      "fields": [
+        {
+          "name": "record",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "subject",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RecordDeleted",
+      "fields": [
+        {
+          "name": "record",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "subject",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "deletedBy",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ConsentWithdrawn",
+      "fields": [
+        {
+          "name": "record",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "group",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "subject",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SubjectFrozen",
+      "fields": [
+        {
+          "name": "subject",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "frozenBy",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SubjectUnfrozen",
+      "fields": [
+        {
+          "name": "subject",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "unfrozenBy",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "NotAuthorized",
+      "msg": "Not Authorized"
+    },
+    {
+      "code": 6001,
+      "name": "InvalidExpiry",
+      "msg": "Approval expiry must be in the future"
+    },
+    {
+      "code": 6002,
+      "name": "RecordAlreadyMigrated",
+      "msg": "Record has already been migrated"
+    },
+    {
+      "code": 6003,
+      "name": "InvalidRecord",
+      "msg": "Account is not an identity record"
+    },
+    {
+      "code": 6004,
+      "name": "VerifierAlreadyRegistered",
+      "msg": "Verifier is already registered with this role"
+    },
+    {
+      "code": 6005,
+      "name": "VerifierNotFound",
+      "msg": "Verifier is not registered with this role"
+    },
+    {
+      "code": 6006,
+      "name": "TooManyVerifiers",
+      "msg": "Group has reached the maximum number of verifiers"
+    },
+    {
+      "code": 6007,
+      "name": "InvalidJurisdiction",
+      "msg": "Jurisdiction must be an ISO 3166-1 alpha-2 code"
+    },
+    {
+      "code": 6008,
+      "name": "InvalidInvestorClass",
+      "msg": "Unknown investor class flag"
+    },
+    {
+      "code": 6009,
+      "name": "MissingSignatureVerification",
+      "msg": "Expected an ed25519 signature verification instruction"
+    },
+    {
+      "code": 6010,
+      "name": "InvalidAttestation",
+      "msg": "Attestation does not match the signed message"
+    },
+    {
+      "code": 6011,
+      "name": "StaleAttestation",
+      "msg": "Attestation is older than the last imported attestation"
+    },
+    {
+      "code": 6012,
+      "name": "SubjectFrozen",
+      "msg": "Subject is frozen"
+    },
+    {
+      "code": 6013,
+      "name": "EmptyAttestation",
+      "msg": "Attestation must carry at least one status"
     }
   ]
 };
@@ -0,0 +1,19 @@
+/**
+ * Roles a verifier can perform for a group
+ */
+export enum VerifierRole {
+    /// may update the kyc status of records in the group
+    kycProvider,
+    /// may update the aml status of records in the group
+    amlProvider,
+    /// may update the accreditation status of records in the group
+    accreditationReviewer
+}
+
+/**
+ * Encodes a role to pass it as an instruction argument, e.g. `{ kycProvider: {} }`
+ * @param role role to encode
+ */
+export function toVerifierRoleVariant(role: VerifierRole): { [variant: string]: {} } {
+    return {[VerifierRole[role]]: {}}
+}
@@ -9,10 +9,12 @@ import {
     TransactionInstruction,
     TransactionSignature
 } from "@solana/web3.js";
-import {Program, web3} from "@project-serum/anchor";
-import {IdentityStatus} from "../models/identity-status";
+import {BN, Program, web3} from "@project-serum/anchor";
+import {IdentityStatus, toIdentityStatusVariant} from "../models/identity-status";
 import {IdentityVerification, IDL} from "../models/idl";
 import {IdentityRecord} from "../models/identity-record";
+import {toVerifierRoleVariant, VerifierRole} from "../models/verifier-role";
+import {ClosePolicy, toClosePolicyVariant} from "../models/close-policy";
 
 export class IdentityVerificationService {
 
@@ -51,11 +53,15 @@ export class IdentityVerificationService {
      * @param signer The signer of the transaction and of whom the record will be created.
      * @param group The public key of the group this record belongs to.
      * @param authority The public key of the account who will have write access to the record.
+     * @param closePolicy Who has to sign to delete the record.
+     * @param payer The account paying the rent of the record, it gets the rent back when the record is deleted.
      */
     async createRecordInstruction(
         signer: PublicKey,
         group: PublicKey,
         authority: PublicKey,
+        closePolicy: ClosePolicy = ClosePolicy.authority,
+        payer: PublicKey = signer,
     ): Promise<TransactionInstruction> {
 
         const [record, bump] = await PublicKey.findProgramAddress(
@@ -67,9 +73,10 @@ export class IdentityVerificationService {
             this.programId
         );
 
-        return await this.program.methods.createRecord(bump, group)
+        return await this.program.methods.createRecord(bump, group, toClosePolicyVariant(closePolicy))
             .accounts({
                 signer: signer,
+                payer: payer,
                 record: record,
                 systemProgram: SystemProgram.programId,
                 authority: authority
@@ -78,6 +85,76 @@ export class IdentityVerificationService {
 
     }
 
+    /**
+     * Creates the verifier registry of a group, the group key has to sign so only its owner can create it
+     * @param group The keypair of the group.
+     * @param authority The account who will be able to add and remove verifiers, it pays for the registry.
+     */
+    async createGroup(
+        group: Keypair,
+        authority: Keypair
+    ): Promise<TransactionSignature> {
+
+        // this method is not available in the browser
+
+        this.checkEnvironment()
+
+        const [groupAccount, bump] = await PublicKey.findProgramAddress(
+            [
+                Buffer.from("group"),
+                group.publicKey.toBuffer(),
+            ],
+            this.programId
+        );
+
+        return await this.program.methods.createGroup(bump, group.publicKey)
+            .accounts({
+                authority: authority.publicKey,
+                groupKey: group.publicKey,
+                groupAccount: groupAccount,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([authority, group])
+            .rpc()
+
+    }
+
+    /**
+     * Registers a verifier with the given role for the group
+     * @param group The public key of the group.
+     * @param authority The authority of the group that will also sign the transaction
+     * @param verifier The public key of the verifier.
+     * @param role The role the verifier will be able to act in.
+     */
+    async addVerifier(
+        group: PublicKey,
+        authority: Keypair,
+        verifier: PublicKey,
+        role: VerifierRole
+    ): Promise<TransactionSignature> {
+
+        // this method is not available in the browser
+
+        this.checkEnvironment()
+
+        const [groupAccount, bump] = await PublicKey.findProgramAddress(
+            [
+                Buffer.from("group"),
+                group.toBuffer(),
+            ],
+            this.programId
+        );
+
+        return await this.program.methods.addVerifier(bump, group, verifier, toVerifierRoleVariant(role))
+            .accounts({
+                groupAccount: groupAccount,
+                authority: authority.publicKey
+            })
+            .signers([authority])
+            .rpc()
+
+    }
+
     /**
      * Retrieves an identity verification record
      * @param user The user of whom the record is about.
@@ -107,12 +184,14 @@ export class IdentityVerificationService {
      * Approve user's identity
      * @param user The user of whom the record is about.
      * @param group The public key of the group this record belongs to.
-     * @param signer The authority over the record that will also sign the transaction
+     * @param signer A verifier of the group with the required role that will also sign the transaction
+     * @param expiresAt Unix timestamp the approval lapses at, must be in the future
      */
     async approve(
         user: PublicKey,
         group: PublicKey,
-        signer: Keypair
+        signer: Keypair,
+        expiresAt: number
     ): Promise<TransactionSignature> {
 
         // this method is not available in the browser
@@ -128,10 +207,12 @@ export class IdentityVerificationService {
             this.programId
         );
 
-        return await this.program.methods.approve(bump, group)
+        return await this.program.methods.approve(bump, group, new BN(expiresAt))
             .accounts({
                 record: record,
+                groupAccount: await this.getGroupAddress(group),
                 subject: user,
+                frozenSubject: await this.getFrozenSubjectAddress(user),
                 authority: signer.publicKey
             })
             .signers([signer])
@@ -143,7 +224,7 @@ export class IdentityVerificationService {
      * Deny user's identity
      * @param user The user of whom the record is about.
      * @param group The public key of the group this record belongs to.
-     * @param signer The authority over the record that will also sign the transaction
+     * @param signer A verifier of the group holding every role that will also sign the transaction
      */
     async deny(
         user: PublicKey,
@@ -167,7 +248,9 @@ export class IdentityVerificationService {
         return await this.program.methods.deny(bump, group)
             .accounts({
                 record: record,
+                groupAccount: await this.getGroupAddress(group),
                 subject: user,
+                frozenSubject: await this.getFrozenSubjectAddress(user),
                 authority: signer.publicKey
             })
             .signers([signer])
@@ -179,14 +262,16 @@ export class IdentityVerificationService {
      * Updates the investor accreditation status for the record
      * @param user The user of whom the record is about.
      * @param group The public key of the group this record belongs to.
-     * @param signer The authority over the record that will also sign the transaction
+     * @param signer A verifier of the group with the required role that will also sign the transaction
      * @param status investor accreditation status
+     * @param expiresAt Unix timestamp an approval lapses at, ignored for other statuses
      */
     async updateIaStatus(
         user: PublicKey,
         group: PublicKey,
         signer: Keypair,
         status: IdentityStatus,
+        expiresAt: number = 0
     ): Promise<TransactionSignature> {
 
         // this method is not available in the browser
@@ -202,10 +287,12 @@ export class IdentityVerificationService {
             this.programId
         );
 
-        return await this.program.methods.updateIaStatus(bump, group, status)
+        return await this.program.methods.updateIaStatus(bump, group, toIdentityStatusVariant(status), new BN(expiresAt))
             .accounts({
                 record: record,
+                groupAccount: await this.getGroupAddress(group),
                 subject: user,
+                frozenSubject: await this.getFrozenSubjectAddress(user),
                 authority: signer.publicKey
             })
             .signers([signer])
@@ -217,14 +304,16 @@ export class IdentityVerificationService {
      * Updates the investor kyc status for the record
      * @param user The user of whom the record is about.
      * @param group The public key of the group this record belongs to.
-     * @param signer The authority over the record that will also sign the transaction
+     * @param signer A verifier of the group with the required role that will also sign the transaction
      * @param status kyc status
+     * @param expiresAt Unix timestamp an approval lapses at, ignored for other statuses
      */
     async updateKycStatus(
         user: PublicKey,
         group: PublicKey,
         signer: Keypair,
-        status: IdentityStatus
+        status: IdentityStatus,
+        expiresAt: number = 0
     ): Promise<TransactionSignature> {
 
         // this method is not available in the browser
@@ -240,10 +329,12 @@ export class IdentityVerificationService {
             this.programId
         );
 
-        return await this.program.methods.updateKycStatus(bump, group, status)
+        return await this.program.methods.updateKycStatus(bump, group, toIdentityStatusVariant(status), new BN(expiresAt))
             .accounts({
                 record: record,
+                groupAccount: await this.getGroupAddress(group),
                 subject: user,
+                frozenSubject: await this.getFrozenSubjectAddress(user),
                 authority: signer.publicKey
             })
             .signers([signer])
@@ -255,14 +346,16 @@ export class IdentityVerificationService {
      * Updates the investor aml status for the record
      * @param user The user of whom the record is about.
      * @param group The public key of the group this record belongs to.
-     * @param signer The authority over the record that will also sign the transaction
+     * @param signer A verifier of the group with the required role that will also sign the transaction
      * @param status aml status
+     * @param expiresAt Unix timestamp an approval lapses at, ignored for other statuses
      */
     async updateAmlStatus(
         user: PublicKey,
         group: PublicKey,
         signer: Keypair,
         status: IdentityStatus,
+        expiresAt: number = 0
     ): Promise<TransactionSignature> {
 
         // this method is not available in the browser
@@ -278,10 +371,12 @@ export class IdentityVerificationService {
             this.programId
         );
 
-        return await this.program.methods.updateAmlStatus(bump, group, status)
+        return await this.program.methods.updateAmlStatus(bump, group, toIdentityStatusVariant(status), new BN(expiresAt))
             .accounts({
                 record: record,
+                groupAccount: await this.getGroupAddress(group),
                 subject: user,
+                frozenSubject: await this.getFrozenSubjectAddress(user),
                 authority: signer.publicKey
             })
             .signers([signer])
@@ -329,15 +424,17 @@ export class IdentityVerificationService {
     }
 
     /**
-     * Deletes a record of the passed user account. It also returns the rent to the user whom it's about.
+     * Deletes a record of the passed user account. It also returns the rent to the payer of the record.
      * @param user The user of whom the record is about.
      * @param group The public key of the group this record belongs to.
      * @param signer The keypair of the account that currently has update authority.
+     * @param subject The keypair of the user, required when the close policy of the record needs the subject to sign.
      */
     async deleteRecord(
         user: PublicKey,
         group: PublicKey,
-        signer: Keypair
+        signer: Keypair,
+        subject?: Keypair
     ): Promise<TransactionSignature> {
 
         // this method is not available in the browser
@@ -353,13 +450,55 @@ export class IdentityVerificationService {
             this.programId
         );
 
+        const info = await this.program.account.identityRecord.fetch(record);
+
         return await this.program.methods.deleteRecord(bump, group)
             .accounts({
                 record: record,
-                signer: signer.publicKey,
-                subject: user
+                authority: signer.publicKey,
+                subject: user,
+                payer: info.payer,
+                systemProgram: SystemProgram.programId
             })
-            .signers([signer])
+            .signers(subject ? [signer, subject] : [signer])
+            .rpc()
+
+    }
+
+    /**
+     * Deletes the record of the user on their behalf, regardless of its close policy.
+     * The rent is returned to the payer of the record.
+     * @param user The keypair of the user of whom the record is about.
+     * @param group The public key of the group this record belongs to.
+     */
+    async withdrawConsent(
+        user: Keypair,
+        group: PublicKey
+    ): Promise<TransactionSignature> {
+
+        // this method is not available in the browser
+
+        this.checkEnvironment()
+
+        const [record, bump] = await PublicKey.findProgramAddress(
+            [
+                Buffer.from("identity"),
+                group.toBuffer(),
+                user.publicKey.toBuffer(),
+            ],
+            this.programId
+        );
+
+        const info = await this.program.account.identityRecord.fetch(record);
+
+        return await this.program.methods.withdrawConsent(bump, group)
+            .accounts({
+                record: record,
+                subject: user.publicKey,
+                payer: info.payer,
+                systemProgram: SystemProgram.programId
+            })
+            .signers([user])
             .rpc()
 
     }
@@ -389,6 +528,44 @@ export class IdentityVerificationService {
 
     // Private Methods
 
+    /**
+     * Derives the address of the verifier registry of a group
+     * @param group The public key of the group.
+     * @private
+     */
+    private async getGroupAddress(group: PublicKey): Promise<PublicKey> {
+
+        const [groupAccount] = await PublicKey.findProgramAddress(
+            [
+                Buffer.from("group"),
+                group.toBuffer(),
+            ],
+            this.programId
+        );
+
+        return groupAccount
+
+    }
+
+    /**
+     * Derives the address of the freeze marker of a subject, it only exists while the subject is frozen
+     * @param user The user of whom the record is about.
+     * @private
+     */
+    private async getFrozenSubjectAddress(user: PublicKey): Promise<PublicKey> {
+
+        const [frozenSubject] = await PublicKey.findProgramAddress(
+            [
+                Buffer.from("freeze"),
+                user.toBuffer(),
+            ],
+            this.programId
+        );
+
+        return frozenSubject
+
+    }
+
     /**
      * Checks the current environment, only certain methods are callable from the browser
      * @private
@@ -2,8 +2,32 @@ use anchor_lang::prelude::*;
 
 #[error_code]
 pub enum IdentityVerificationErrorCode {
-    #[msg("Unexpected Status")]
-    UnknownStatus,
     #[msg("Not Authorized")]
     NotAuthorized,
+    #[msg("Approval expiry must be in the future")]
+    InvalidExpiry,
+    #[msg("Record has already been migrated")]
+    RecordAlreadyMigrated,
+    #[msg("Account is not an identity record")]
+    InvalidRecord,
+    #[msg("Verifier is already registered with this role")]
+    VerifierAlreadyRegistered,
+    #[msg("Verifier is not registered with this role")]
+    VerifierNotFound,
+    #[msg("Group has reached the maximum number of verifiers")]
+    TooManyVerifiers,
+    #[msg("Jurisdiction must be an ISO 3166-1 alpha-2 code")]
+    InvalidJurisdiction,
+    #[msg("Unknown investor class flag")]
+    InvalidInvestorClass,
+    #[msg("Expected an ed25519 signature verification instruction")]
+    MissingSignatureVerification,
+    #[msg("Attestation does not match the signed message")]
+    InvalidAttestation,
+    #[msg("Attestation is older than the last imported attestation")]
+    StaleAttestation,
+    #[msg("Subject is frozen")]
+    SubjectFrozen,
+    #[msg("Attestation must carry at least one status")]
+    EmptyAttestation,
 }
\ No newline at end of file
@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+
+use crate::state::identity_record::*;
+
+/// Emitted when a record is created
+#[event]
+pub struct RecordCreated {
+    pub record: Pubkey,
+    pub group: Pubkey,
+    pub subject: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when every status of a record is approved
+#[event]
+pub struct RecordApproved {
+    pub record: Pubkey,
+    pub group: Pubkey,
+    pub subject: Pubkey,
+    pub verifier: Pubkey,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when every status of a record is denied
+#[event]
+pub struct RecordDenied {
+    pub record: Pubkey,
+    pub group: Pubkey,
+    pub subject: Pubkey,
+    pub verifier: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted for every change of a single status
+#[event]
+pub struct StatusUpdated {
+    pub record: Pubkey,
+    pub group: Pubkey,
+    pub subject: Pubkey,
+    pub check: IdentityCheck,
+    pub previous_status: IdentityStatus,
+    pub status: IdentityStatus,
+    pub verifier: Pubkey,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the jurisdiction or investor class of a record is updated
+#[event]
+pub struct InvestorProfileUpdated {
+    pub record: Pubkey,
+    pub group: Pubkey,
+    pub subject: Pubkey,
+    pub jurisdiction: [u8; 2],
+    pub investor_class: u8,
+    pub verifier: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when the authority of a record is transferred
+#[event]
+pub struct AuthorityTransferred {
+    pub record: Pubkey,
+    pub group: Pubkey,
+    pub subject: Pubkey,
+    pub previous_authority: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a record is deleted
+#[event]
+pub struct RecordDeleted {
+    pub record: Pubkey,
+    pub group: Pubkey,
+    pub subject: Pubkey,
+    pub deleted_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when the subject of a record withdraws consent and the record is deleted
+#[event]
+pub struct ConsentWithdrawn {
+    pub record: Pubkey,
+    pub group: Pubkey,
+    pub subject: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a subject is frozen across every group
+#[event]
+pub struct SubjectFrozen {
+    pub subject: Pubkey,
+    pub frozen_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when the freeze of a subject is lifted
+#[event]
+pub struct SubjectUnfrozen {
+    pub subject: Pubkey,
+    pub unfrozen_by: Pubkey,
+    pub timestamp: i64,
+}
\ No newline at end of file
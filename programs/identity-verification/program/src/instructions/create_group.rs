@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::IdentityVerificationErrorCode;
+use crate::state::group::*;
+
+/// Create the verifier registry for a group, the signer becomes its authority.
+/// The group key must sign so nobody can claim the registry of a group they don't control.
+pub fn create_group(
+    ctx: Context<CreateGroup>,
+    bump: u8,
+    group: Pubkey,
+) -> Result<()> {
+    ctx.accounts.group_account.bump = bump;
+    ctx.accounts.group_account.group = group;
+    ctx.accounts.group_account.authority = ctx.accounts.authority.key.clone();
+    ctx.accounts.group_account.verifiers = vec![];
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(bump: u8, group: Pubkey)]
+pub struct CreateGroup<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(address = group @ IdentityVerificationErrorCode::NotAuthorized)]
+    pub group_key: Signer<'info>,
+    #[account(init, seeds = [b"group", group.as_ref()], bump, payer = authority, space = Group::LEN)]
+    pub group_account: Account<'info, Group>,
+    pub system_program: Program<'info, System>,
+}
\ No newline at end of file
@@ -1,18 +1,31 @@
 use anchor_lang::prelude::*;
 
+use crate::events::*;
 use crate::state::identity_record::*;
 
 /// Create a record of kyc/aml metadata for the user.
+/// The rent may be paid by a third party, it is returned to the payer when the record is closed.
 pub fn create_record(
     ctx: Context<CreateRecord>,
     bump: u8,
-    _group: Pubkey,
+    group: Pubkey,
+    close_policy: ClosePolicy,
 ) -> Result<()> {
-    ctx.accounts.record.aml_status = 0;
-    ctx.accounts.record.ia_status = 0;
-    ctx.accounts.record.kyc_status = 0;
+    ctx.accounts.record.aml_status = IdentityStatus::Initial;
+    ctx.accounts.record.ia_status = IdentityStatus::Initial;
+    ctx.accounts.record.kyc_status = IdentityStatus::Initial;
     ctx.accounts.record.bump = bump;
     ctx.accounts.record.authority = ctx.accounts.authority.key.clone();
+    ctx.accounts.record.payer = ctx.accounts.payer.key.clone();
+    ctx.accounts.record.close_policy = close_policy;
+
+    emit!(RecordCreated {
+        record: ctx.accounts.record.key(),
+        group,
+        subject: ctx.accounts.signer.key(),
+        authority: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
 
     Ok(())
 }
@@ -20,9 +33,10 @@ pub fn create_record(
 #[derive(Accounts)]
 #[instruction(bump: u8, group: Pubkey)]
 pub struct CreateRecord<'info> {
-    #[account(mut)]
     pub signer: Signer<'info>,
-    #[account(init, seeds = [b"identity", group.as_ref(), signer.key.as_ref()], bump, payer = signer, space = IdentityRecord::LEN)]
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(init, seeds = [b"identity", group.as_ref(), signer.key.as_ref()], bump, payer = payer, space = IdentityRecord::LEN)]
     pub record: Account<'info, IdentityRecord>,
     pub system_program: Program<'info, System>,
     pub authority: SystemAccount<'info>,
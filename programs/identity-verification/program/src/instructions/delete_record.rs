@@ -2,17 +2,49 @@ use anchor_lang::prelude::*;
 
 use crate::state::identity_record::*;
 use crate::errors::IdentityVerificationErrorCode;
+use crate::events::*;
 
-/// Delete a record and return funds to the subject of the record
+/// Delete a record and return the rent to the payer of the record.
+/// The authority and/or the subject have to sign depending on the close policy of the record.
 pub fn delete_record(
     ctx: Context<DeleteRecord>,
     _bump: u8,
-    _group: Pubkey,
+    group: Pubkey,
 ) -> Result<()> {
+    let record = &ctx.accounts.record;
+    let authority = &ctx.accounts.authority;
+    let subject = &ctx.accounts.subject;
 
-    let signer = &mut ctx.accounts.signer;
+    let authority_signed = authority.is_signer && record.authority == authority.key();
 
-    require!(ctx.accounts.record.authority.key() == signer.key.clone(), IdentityVerificationErrorCode::NotAuthorized);
+    require!(record.can_close(authority_signed, subject.is_signer), IdentityVerificationErrorCode::NotAuthorized);
+
+    let deleted_by = if authority_signed { authority.key() } else { subject.key() };
+
+    emit!(RecordDeleted {
+        record: record.key(),
+        group,
+        subject: subject.key(),
+        deleted_by,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Delete a record on behalf of its subject, regardless of the close policy.
+/// The rent is returned to the payer of the record.
+pub fn withdraw_consent(
+    ctx: Context<WithdrawConsent>,
+    _bump: u8,
+    group: Pubkey,
+) -> Result<()> {
+    emit!(ConsentWithdrawn {
+        record: ctx.accounts.record.key(),
+        group,
+        subject: ctx.accounts.subject.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
 
     Ok(())
 }
@@ -20,11 +52,24 @@ pub fn delete_record(
 #[derive(Accounts)]
 #[instruction(bump: u8, group: Pubkey)]
 pub struct DeleteRecord<'info> {
-    #[account(mut, close = subject, seeds = [b"identity", group.as_ref(), subject.key.as_ref()], bump)]
+    #[account(mut, close = payer, seeds = [b"identity", group.as_ref(), subject.key.as_ref()], bump)]
+    pub record: Account<'info, IdentityRecord>,
+    /// CHECK: signature is required depending on the close policy, checked against the record in the handler
+    pub authority: UncheckedAccount<'info>,
+    /// CHECK: signature is required depending on the close policy, the address is bound by the record seeds
+    pub subject: UncheckedAccount<'info>,
+    #[account(mut, address = record.payer)]
+    pub payer: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(bump: u8, group: Pubkey)]
+pub struct WithdrawConsent<'info> {
+    #[account(mut, close = payer, seeds = [b"identity", group.as_ref(), subject.key.as_ref()], bump)]
     pub record: Account<'info, IdentityRecord>,
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    #[account(mut)]
-    pub subject: SystemAccount<'info>,
+    pub subject: Signer<'info>,
+    #[account(mut, address = record.payer)]
+    pub payer: SystemAccount<'info>,
     pub system_program: Program<'info, System>,
 }
\ No newline at end of file
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::IdentityVerificationErrorCode;
+use crate::events::*;
+use crate::program::IdentityVerification;
+use crate::state::freeze::*;
+
+/// Create the freeze configuration, only the upgrade authority of the program can do this
+pub fn create_freeze_config(
+    ctx: Context<CreateFreezeConfig>,
+    bump: u8,
+    authority: Pubkey,
+) -> Result<()> {
+    ctx.accounts.freeze_config.bump = bump;
+    ctx.accounts.freeze_config.authority = authority;
+
+    Ok(())
+}
+
+/// Hand the freeze authority over to another account
+pub fn set_freeze_authority(
+    ctx: Context<SetFreezeAuthority>,
+    authority: Pubkey,
+) -> Result<()> {
+    require!(ctx.accounts.freeze_config.authority == ctx.accounts.authority.key(), IdentityVerificationErrorCode::NotAuthorized);
+
+    ctx.accounts.freeze_config.authority = authority;
+
+    Ok(())
+}
+
+/// Freeze the subject across every group
+pub fn freeze_subject(
+    ctx: Context<FreezeSubject>,
+    bump: u8,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(ctx.accounts.freeze_config.authority == ctx.accounts.authority.key(), IdentityVerificationErrorCode::NotAuthorized);
+
+    ctx.accounts.frozen_subject.bump = bump;
+    ctx.accounts.frozen_subject.subject = ctx.accounts.subject.key();
+    ctx.accounts.frozen_subject.frozen_by = ctx.accounts.authority.key();
+    ctx.accounts.frozen_subject.frozen_at = now;
+
+    emit!(SubjectFrozen {
+        subject: ctx.accounts.subject.key(),
+        frozen_by: ctx.accounts.authority.key(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+/// Lift the freeze of the subject, rent is returned to the freeze authority
+pub fn unfreeze_subject(
+    ctx: Context<UnfreezeSubject>,
+) -> Result<()> {
+    require!(ctx.accounts.freeze_config.authority == ctx.accounts.authority.key(), IdentityVerificationErrorCode::NotAuthorized);
+
+    emit!(SubjectUnfrozen {
+        subject: ctx.accounts.subject.key(),
+        unfrozen_by: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(bump: u8)]
+pub struct CreateFreezeConfig<'info> {
+    #[account(init, seeds = [b"freeze-config"], bump, payer = upgrade_authority, space = FreezeConfig::LEN)]
+    pub freeze_config: Account<'info, FreezeConfig>,
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, IdentityVerification>,
+    #[account(constraint = program_data.upgrade_authority_address == Some(upgrade_authority.key()) @ IdentityVerificationErrorCode::NotAuthorized)]
+    pub program_data: Account<'info, ProgramData>,
+    #[account(mut)]
+    pub upgrade_authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetFreezeAuthority<'info> {
+    #[account(mut, seeds = [b"freeze-config"], bump = freeze_config.bump)]
+    pub freeze_config: Account<'info, FreezeConfig>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(bump: u8)]
+pub struct FreezeSubject<'info> {
+    #[account(seeds = [b"freeze-config"], bump = freeze_config.bump)]
+    pub freeze_config: Account<'info, FreezeConfig>,
+    #[account(init, seeds = [b"freeze", subject.key.as_ref()], bump, payer = authority, space = FrozenSubject::LEN)]
+    pub frozen_subject: Account<'info, FrozenSubject>,
+    pub subject: SystemAccount<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UnfreezeSubject<'info> {
+    #[account(seeds = [b"freeze-config"], bump = freeze_config.bump)]
+    pub freeze_config: Account<'info, FreezeConfig>,
+    #[account(mut, close = authority, seeds = [b"freeze", subject.key.as_ref()], bump = frozen_subject.bump)]
+    pub frozen_subject: Account<'info, FrozenSubject>,
+    pub subject: SystemAccount<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
\ No newline at end of file
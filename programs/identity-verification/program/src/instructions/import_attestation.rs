@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions::{load_current_index_checked, load_instruction_at_checked};
+
+use crate::errors::IdentityVerificationErrorCode;
+use crate::events::*;
+use crate::state::attestation::*;
+use crate::state::freeze::*;
+use crate::state::group::*;
+use crate::state::identity_record::*;
+
+/// Size of the ed25519 instruction header (signature count and padding)
+const ED25519_HEADER_LEN: usize = 2;
+
+/// Size of the ed25519 signature offsets entry
+const ED25519_OFFSETS_LEN: usize = 14;
+
+/// Import an attestation signed off-chain by a verifier of the group.
+/// The signature must be checked by an ed25519 program instruction placed right before this one,
+/// so the transaction can be posted by any relayer.
+pub fn import_attestation(
+    ctx: Context<ImportAttestation>,
+    _bump: u8,
+    group: Pubkey,
+    attestation: Attestation,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    let (signer, message) = load_verified_signature(&ctx.accounts.instructions)?;
+
+    require!(message == attestation.to_message()?, IdentityVerificationErrorCode::InvalidAttestation);
+    require!(attestation.subject == ctx.accounts.subject.key() && attestation.group == group, IdentityVerificationErrorCode::InvalidAttestation);
+    require!(attestation.issued_at > ctx.accounts.record.attested_at && attestation.issued_at <= now, IdentityVerificationErrorCode::StaleAttestation);
+
+    let group_account = &ctx.accounts.group_account;
+    let record = &mut ctx.accounts.record;
+
+    require!(group_account.is_verifier(&signer), IdentityVerificationErrorCode::NotAuthorized);
+    require!(!attestation.is_empty(), IdentityVerificationErrorCode::EmptyAttestation);
+
+    let updates = [
+        (IdentityCheck::Accreditation, VerifierRole::AccreditationReviewer, attestation.ia_status),
+        (IdentityCheck::Aml, VerifierRole::AmlProvider, attestation.aml_status),
+        (IdentityCheck::Kyc, VerifierRole::KycProvider, attestation.kyc_status),
+    ];
+
+    for (check, role, status) in updates {
+        let status = match status {
+            Some(status) => status,
+            None => continue,
+        };
+
+        require!(group_account.has_role(&signer, role), IdentityVerificationErrorCode::NotAuthorized);
+        require!(status != IdentityStatus::Approved || attestation.expires_at > now, IdentityVerificationErrorCode::InvalidExpiry);
+        require!(status != IdentityStatus::Approved || !is_frozen(&ctx.accounts.frozen_subject), IdentityVerificationErrorCode::SubjectFrozen);
+
+        let change = record.update_status(check, status, attestation.expires_at, signer, now);
+
+        emit!(StatusUpdated {
+            record: record.key(),
+            group,
+            subject: attestation.subject,
+            check,
+            previous_status: change.previous_status,
+            status,
+            verifier: signer,
+            expires_at: attestation.expires_at,
+            timestamp: now,
+        });
+    }
+
+    record.attested_at = attestation.issued_at;
+
+    Ok(())
+}
+
+/// Returns the public key and message of the ed25519 instruction preceding the current one
+fn load_verified_signature(instructions: &AccountInfo) -> Result<(Pubkey, Vec<u8>)> {
+    let current_index = load_current_index_checked(instructions)? as usize;
+
+    require!(current_index > 0, IdentityVerificationErrorCode::MissingSignatureVerification);
+
+    let ix = load_instruction_at_checked(current_index - 1, instructions)?;
+
+    require!(ix.program_id == ed25519_program::ID, IdentityVerificationErrorCode::MissingSignatureVerification);
+    require!(ix.data.len() >= ED25519_HEADER_LEN + ED25519_OFFSETS_LEN && ix.data[0] == 1, IdentityVerificationErrorCode::MissingSignatureVerification);
+
+    let offsets = &ix.data[ED25519_HEADER_LEN..ED25519_HEADER_LEN + ED25519_OFFSETS_LEN];
+    let read_u16 = |at: usize| u16::from_le_bytes([offsets[at], offsets[at + 1]]);
+
+    // signature, public key and message must all live in the ed25519 instruction itself
+    require!(
+        read_u16(2) == u16::MAX && read_u16(6) == u16::MAX && read_u16(12) == u16::MAX,
+        IdentityVerificationErrorCode::MissingSignatureVerification
+    );
+
+    let public_key_offset = read_u16(4) as usize;
+    let message_offset = read_u16(8) as usize;
+    let message_size = read_u16(10) as usize;
+
+    let public_key = ix.data
+        .get(public_key_offset..public_key_offset + 32)
+        .ok_or(IdentityVerificationErrorCode::MissingSignatureVerification)?;
+    let message = ix.data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(IdentityVerificationErrorCode::MissingSignatureVerification)?;
+
+    let public_key = <[u8; 32]>::try_from(public_key)
+        .map(Pubkey::new_from_array)
+        .map_err(|_| IdentityVerificationErrorCode::MissingSignatureVerification)?;
+
+    Ok((public_key, message.to_vec()))
+}
+
+#[derive(Accounts)]
+#[instruction(bump: u8, group: Pubkey)]
+pub struct ImportAttestation<'info> {
+    #[account(mut, seeds = [b"identity", group.as_ref(), subject.key.as_ref()], bump)]
+    pub record: Account<'info, IdentityRecord>,
+    #[account(seeds = [b"group", group.as_ref()], bump = group_account.bump)]
+    pub group_account: Account<'info, Group>,
+    pub subject: SystemAccount<'info>,
+    /// CHECK: the subject is frozen when this pda has been created by the program
+    #[account(seeds = [b"freeze", subject.key.as_ref()], bump)]
+    pub frozen_subject: UncheckedAccount<'info>,
+    /// CHECK: address is checked against the instructions sysvar id
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
\ No newline at end of file
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+
+use crate::errors::IdentityVerificationErrorCode;
+use crate::state::group::*;
+use crate::state::identity_record::*;
+
+/// Grow a legacy record to the current layout, existing approvals are given the supplied expiry.
+/// The signer must hold every verifier role of the group and pays for the additional rent.
+pub fn migrate_record(
+    ctx: Context<MigrateRecord>,
+    _bump: u8,
+    _group: Pubkey,
+    expires_at: i64,
+) -> Result<()> {
+    let record = &ctx.accounts.record;
+    let authority = &ctx.accounts.authority;
+    let now = Clock::get()?.unix_timestamp;
+
+    let legacy = {
+        let data = record.try_borrow_data()?;
+
+        require!(data.len() == IdentityRecord::LEGACY_LEN, IdentityVerificationErrorCode::RecordAlreadyMigrated);
+        require!(data[..8] == IdentityRecord::discriminator(), IdentityVerificationErrorCode::InvalidRecord);
+
+        LegacyIdentityRecord::deserialize(&mut &data[8..])?
+    };
+
+    require!(ctx.accounts.group_account.has_all_roles(authority.key), IdentityVerificationErrorCode::NotAuthorized);
+    require!(expires_at > now, IdentityVerificationErrorCode::InvalidExpiry);
+
+    // top up rent for the larger account
+
+    let rent_due = Rent::get()?
+        .minimum_balance(IdentityRecord::LEN)
+        .saturating_sub(record.lamports());
+
+    if rent_due > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: authority.to_account_info(),
+                    to: record.to_account_info(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+
+    record.realloc(IdentityRecord::LEN, true)?;
+
+    let mut migrated = IdentityRecord {
+        bump: legacy.bump,
+        authority: legacy.authority,
+        // legacy records were always paid for by their subject
+        payer: ctx.accounts.subject.key(),
+        ..IdentityRecord::default()
+    };
+
+    migrated.set_ia_status(legacy.ia_status, expires_at, now);
+    migrated.set_aml_status(legacy.aml_status, expires_at, now);
+    migrated.set_kyc_status(legacy.kyc_status, expires_at, now);
+
+    migrated.try_serialize(&mut &mut record.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(bump: u8, group: Pubkey)]
+pub struct MigrateRecord<'info> {
+    /// CHECK: legacy records no longer deserialize as an IdentityRecord, the layout is validated in the handler
+    #[account(mut, owner = crate::ID, seeds = [b"identity", group.as_ref(), subject.key.as_ref()], bump)]
+    pub record: UncheckedAccount<'info>,
+    #[account(seeds = [b"group", group.as_ref()], bump = group_account.bump)]
+    pub group_account: Account<'info, Group>,
+    pub subject: SystemAccount<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
\ No newline at end of file
@@ -2,8 +2,18 @@ pub use create_record::*;
 pub use transfer_authority::*;
 pub use update_record::*;
 pub use delete_record::*;
+pub use migrate_record::*;
+pub use create_group::*;
+pub use update_group::*;
+pub use import_attestation::*;
+pub use freeze::*;
 
 pub mod create_record;
 pub mod transfer_authority;
 pub mod update_record;
-pub mod delete_record;
\ No newline at end of file
+pub mod delete_record;
+pub mod migrate_record;
+pub mod create_group;
+pub mod update_group;
+pub mod import_attestation;
+pub mod freeze;
\ No newline at end of file
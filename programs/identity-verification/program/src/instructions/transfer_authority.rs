@@ -1,12 +1,13 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::IdentityVerificationErrorCode;
+use crate::events::*;
 use crate::state::identity_record::*;
 
 pub fn transfer_authority(
     ctx: Context<TransferAuthority>,
     _bump: u8,
-    _group: Pubkey,
+    group: Pubkey,
 ) -> Result<()> {
     let transfer_from = &mut ctx.accounts.transfer_from;
     let transfer_to = &mut ctx.accounts.transfer_to;
@@ -14,6 +15,15 @@ pub fn transfer_authority(
     require!(ctx.accounts.record.authority.key() == transfer_from.key.clone(), IdentityVerificationErrorCode::NotAuthorized);
     ctx.accounts.record.authority = transfer_to.key.clone();
 
+    emit!(AuthorityTransferred {
+        record: ctx.accounts.record.key(),
+        group,
+        subject: ctx.accounts.subject.key(),
+        previous_authority: transfer_from.key(),
+        authority: transfer_to.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     Ok(())
 }
 
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::IdentityVerificationErrorCode;
+use crate::state::group::*;
+
+/// Register a verifier with the given role for the group
+pub fn add_verifier(
+    ctx: Context<UpdateGroup>,
+    _bump: u8,
+    _group: Pubkey,
+    verifier: Pubkey,
+    role: VerifierRole,
+) -> Result<()> {
+    let group_account = &mut ctx.accounts.group_account;
+
+    require!(group_account.authority == ctx.accounts.authority.key(), IdentityVerificationErrorCode::NotAuthorized);
+    require!(!group_account.has_role(&verifier, role), IdentityVerificationErrorCode::VerifierAlreadyRegistered);
+    require!(group_account.verifiers.len() < Group::MAX_VERIFIERS, IdentityVerificationErrorCode::TooManyVerifiers);
+
+    group_account.verifiers.push(Verifier { key: verifier, role });
+
+    Ok(())
+}
+
+/// Remove a verifier role from the group
+pub fn remove_verifier(
+    ctx: Context<UpdateGroup>,
+    _bump: u8,
+    _group: Pubkey,
+    verifier: Pubkey,
+    role: VerifierRole,
+) -> Result<()> {
+    let group_account = &mut ctx.accounts.group_account;
+
+    require!(group_account.authority == ctx.accounts.authority.key(), IdentityVerificationErrorCode::NotAuthorized);
+    require!(group_account.has_role(&verifier, role), IdentityVerificationErrorCode::VerifierNotFound);
+
+    group_account.verifiers.retain(|v| !(v.key == verifier && v.role == role));
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(bump: u8, group: Pubkey)]
+pub struct UpdateGroup<'info> {
+    #[account(mut, seeds = [b"group", group.as_ref()], bump)]
+    pub group_account: Account<'info, Group>,
+    pub authority: Signer<'info>,
+}
\ No newline at end of file
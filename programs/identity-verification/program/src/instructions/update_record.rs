@@ -1,88 +1,176 @@
 use anchor_lang::prelude::*;
 use crate::errors::IdentityVerificationErrorCode;
+use crate::events::*;
+use crate::state::freeze::*;
+use crate::state::group::*;
 use crate::state::identity_record::*;
 
-/// Update all statuses to approved
+/// Update all statuses to approved until the given expiry, the signer must hold every verifier role
+/// and the subject must not be frozen
 pub fn approve(
     ctx: Context<UpdateRecord>,
     _bump: u8,
-    _group: Pubkey
+    group: Pubkey,
+    expires_at: i64,
 ) -> Result<()> {
     let authority = &mut ctx.accounts.authority;
+    let now = Clock::get()?.unix_timestamp;
 
-    require!(ctx.accounts.record.authority.key() == authority.key.clone(), IdentityVerificationErrorCode::NotAuthorized);
+    require!(ctx.accounts.group_account.has_all_roles(authority.key), IdentityVerificationErrorCode::NotAuthorized);
+    require!(expires_at > now, IdentityVerificationErrorCode::InvalidExpiry);
+    require!(!is_frozen(&ctx.accounts.frozen_subject), IdentityVerificationErrorCode::SubjectFrozen);
 
-    ctx.accounts.record.aml_status = 2;
-    ctx.accounts.record.kyc_status = 2;
-    ctx.accounts.record.ia_status = 2;
+    ctx.accounts.record.update_status(IdentityCheck::Aml, IdentityStatus::Approved, expires_at, authority.key(), now);
+    ctx.accounts.record.update_status(IdentityCheck::Kyc, IdentityStatus::Approved, expires_at, authority.key(), now);
+    ctx.accounts.record.update_status(IdentityCheck::Accreditation, IdentityStatus::Approved, expires_at, authority.key(), now);
+
+    emit!(RecordApproved {
+        record: ctx.accounts.record.key(),
+        group,
+        subject: ctx.accounts.subject.key(),
+        verifier: authority.key(),
+        expires_at,
+        timestamp: now,
+    });
 
     Ok(())
 }
 
-/// Update all statuses to denied
+/// Update all statuses to denied, the signer must hold every verifier role
 pub fn deny(
     ctx: Context<UpdateRecord>,
     _bump: u8,
-    _group: Pubkey
+    group: Pubkey
 ) -> Result<()> {
     let authority = &mut ctx.accounts.authority;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(ctx.accounts.group_account.has_all_roles(authority.key), IdentityVerificationErrorCode::NotAuthorized);
 
-    require!(ctx.accounts.record.authority.key() == authority.key.clone(), IdentityVerificationErrorCode::NotAuthorized);
+    ctx.accounts.record.update_status(IdentityCheck::Aml, IdentityStatus::Denied, 0, authority.key(), now);
+    ctx.accounts.record.update_status(IdentityCheck::Kyc, IdentityStatus::Denied, 0, authority.key(), now);
+    ctx.accounts.record.update_status(IdentityCheck::Accreditation, IdentityStatus::Denied, 0, authority.key(), now);
 
-    ctx.accounts.record.aml_status = 3;
-    ctx.accounts.record.kyc_status = 3;
-    ctx.accounts.record.ia_status = 3;
+    emit!(RecordDenied {
+        record: ctx.accounts.record.key(),
+        group,
+        subject: ctx.accounts.subject.key(),
+        verifier: authority.key(),
+        timestamp: now,
+    });
 
     Ok(())
 }
 
-/// Update aml status of account
+/// Update aml status of account, expires_at is only used when approving
 pub fn update_aml_status(
     ctx: Context<UpdateRecord>,
     _bump: u8,
-    _group: Pubkey,
-    status: u8,
+    group: Pubkey,
+    status: IdentityStatus,
+    expires_at: i64,
+) -> Result<()> {
+    update_status(ctx, group, IdentityCheck::Aml, VerifierRole::AmlProvider, status, expires_at)
+}
+
+/// Update ia status, expires_at is only used when approving
+pub fn update_ia_status(
+    ctx: Context<UpdateRecord>,
+    _bump: u8,
+    group: Pubkey,
+    status: IdentityStatus,
+    expires_at: i64,
+) -> Result<()> {
+    update_status(ctx, group, IdentityCheck::Accreditation, VerifierRole::AccreditationReviewer, status, expires_at)
+}
+
+/// Update kyc status of account, expires_at is only used when approving
+pub fn update_kyc_status(
+    ctx: Context<UpdateRecord>,
+    _bump: u8,
+    group: Pubkey,
+    status: IdentityStatus,
+    expires_at: i64,
+) -> Result<()> {
+    update_status(ctx, group, IdentityCheck::Kyc, VerifierRole::KycProvider, status, expires_at)
+}
+
+/// Update the jurisdiction of the subject
+pub fn update_jurisdiction(
+    ctx: Context<UpdateRecord>,
+    _bump: u8,
+    group: Pubkey,
+    jurisdiction: [u8; 2],
 ) -> Result<()> {
     let authority = &mut ctx.accounts.authority;
 
-    require!(ctx.accounts.record.authority.key() == authority.key.clone(), IdentityVerificationErrorCode::NotAuthorized);
-    require!(status <= 3, IdentityVerificationErrorCode::UnknownStatus);
+    require!(ctx.accounts.group_account.has_role(authority.key, VerifierRole::KycProvider), IdentityVerificationErrorCode::NotAuthorized);
+    require!(is_valid_jurisdiction(&jurisdiction), IdentityVerificationErrorCode::InvalidJurisdiction);
 
-    ctx.accounts.record.aml_status = status;
+    ctx.accounts.record.jurisdiction = jurisdiction;
 
-    Ok(())
+    emit_investor_profile_updated(&ctx, group)
 }
 
-/// Update ia status
-pub fn update_ia_status(
+/// Update the investor classes the subject qualifies for
+pub fn update_investor_class(
     ctx: Context<UpdateRecord>,
     _bump: u8,
-    _group: Pubkey,
-    status: u8,
+    group: Pubkey,
+    investor_class: u8,
 ) -> Result<()> {
     let authority = &mut ctx.accounts.authority;
 
-    require!(ctx.accounts.record.authority.key() == authority.key.clone(), IdentityVerificationErrorCode::NotAuthorized);
-    require!(status <= 3, IdentityVerificationErrorCode::UnknownStatus);
+    require!(ctx.accounts.group_account.has_role(authority.key, VerifierRole::AccreditationReviewer), IdentityVerificationErrorCode::NotAuthorized);
+    require!(investor_class & !investor_class::ALL == 0, IdentityVerificationErrorCode::InvalidInvestorClass);
 
-    ctx.accounts.record.ia_status = status;
+    ctx.accounts.record.investor_class = investor_class;
 
-    Ok(())
+    emit_investor_profile_updated(&ctx, group)
 }
 
-/// Update kyc status of account
-pub fn update_kyc_status(
+fn update_status(
     ctx: Context<UpdateRecord>,
-    _bump: u8,
-    _group: Pubkey,
-    status: u8,
+    group: Pubkey,
+    check: IdentityCheck,
+    role: VerifierRole,
+    status: IdentityStatus,
+    expires_at: i64,
 ) -> Result<()> {
     let authority = &mut ctx.accounts.authority;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(ctx.accounts.group_account.has_role(authority.key, role), IdentityVerificationErrorCode::NotAuthorized);
+    require!(status != IdentityStatus::Approved || expires_at > now, IdentityVerificationErrorCode::InvalidExpiry);
+    require!(status != IdentityStatus::Approved || !is_frozen(&ctx.accounts.frozen_subject), IdentityVerificationErrorCode::SubjectFrozen);
+
+    let change = ctx.accounts.record.update_status(check, status, expires_at, authority.key(), now);
+
+    emit!(StatusUpdated {
+        record: ctx.accounts.record.key(),
+        group,
+        subject: ctx.accounts.subject.key(),
+        check,
+        previous_status: change.previous_status,
+        status,
+        verifier: authority.key(),
+        expires_at,
+        timestamp: now,
+    });
 
-    require!(ctx.accounts.record.authority.key() == authority.key.clone(), IdentityVerificationErrorCode::NotAuthorized);
-    require!(status <= 3, IdentityVerificationErrorCode::UnknownStatus);
+    Ok(())
+}
 
-    ctx.accounts.record.kyc_status = status;
+fn emit_investor_profile_updated(ctx: &Context<UpdateRecord>, group: Pubkey) -> Result<()> {
+    emit!(InvestorProfileUpdated {
+        record: ctx.accounts.record.key(),
+        group,
+        subject: ctx.accounts.subject.key(),
+        jurisdiction: ctx.accounts.record.jurisdiction,
+        investor_class: ctx.accounts.record.investor_class,
+        verifier: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
 
     Ok(())
 }
@@ -92,6 +180,11 @@ pub fn update_kyc_status(
 pub struct UpdateRecord<'info> {
     #[account(mut, seeds = [b"identity", group.as_ref(), subject.key.as_ref()], bump)]
     pub record: Account<'info, IdentityRecord>,
+    #[account(seeds = [b"group", group.as_ref()], bump = group_account.bump)]
+    pub group_account: Account<'info, Group>,
     pub subject: SystemAccount<'info>,
+    /// CHECK: the subject is frozen when this pda has been created by the program
+    #[account(seeds = [b"freeze", subject.key.as_ref()], bump)]
+    pub frozen_subject: UncheckedAccount<'info>,
     pub authority: Signer<'info>,
 }
\ No newline at end of file
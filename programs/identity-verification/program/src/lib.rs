@@ -1,8 +1,12 @@
 use anchor_lang::prelude::*;
 
 use instructions::*;
+use state::attestation::Attestation;
+use state::group::VerifierRole;
+use state::identity_record::{ClosePolicy, IdentityStatus};
 
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
 
@@ -20,17 +24,19 @@ mod identity_verification {
         ctx: Context<CreateRecord>,
         bump: u8,
         group: Pubkey,
+        close_policy: ClosePolicy,
     ) -> Result<()> {
-        instructions::create_record::create_record(ctx, bump, group)
+        instructions::create_record::create_record(ctx, bump, group, close_policy)
     }
 
-    /// Approve identity verification for the record
+    /// Approve identity verification for the record until the given unix timestamp
     pub fn approve(
         ctx: Context<UpdateRecord>,
         bump: u8,
-        group: Pubkey
+        group: Pubkey,
+        expires_at: i64,
     ) -> Result<()> {
-        instructions::update_record::approve(ctx, bump, group)
+        instructions::update_record::approve(ctx, bump, group, expires_at)
     }
 
     /// Deny identity verification for the record
@@ -47,9 +53,10 @@ mod identity_verification {
         ctx: Context<UpdateRecord>,
         bump: u8,
         group: Pubkey,
-        status: u8,
+        status: IdentityStatus,
+        expires_at: i64,
     ) -> Result<()> {
-        instructions::update_record::update_ia_status(ctx, bump, group, status)
+        instructions::update_record::update_ia_status(ctx, bump, group, status, expires_at)
     }
 
     /// Update aml status of account
@@ -57,9 +64,10 @@ mod identity_verification {
         ctx: Context<UpdateRecord>,
         bump: u8,
         group: Pubkey,
-        status: u8,
+        status: IdentityStatus,
+        expires_at: i64,
     ) -> Result<()> {
-        instructions::update_record::update_aml_status(ctx, bump, group, status)
+        instructions::update_record::update_aml_status(ctx, bump, group, status, expires_at)
     }
 
     /// Update kyc status of account
@@ -67,9 +75,30 @@ mod identity_verification {
         ctx: Context<UpdateRecord>,
         bump: u8,
         group: Pubkey,
-        status: u8,
+        status: IdentityStatus,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::update_record::update_kyc_status(ctx, bump, group, status, expires_at)
+    }
+
+    /// Update the jurisdiction (ISO 3166-1 alpha-2) of the subject
+    pub fn update_jurisdiction(
+        ctx: Context<UpdateRecord>,
+        bump: u8,
+        group: Pubkey,
+        jurisdiction: [u8; 2],
     ) -> Result<()> {
-        instructions::update_record::update_kyc_status(ctx, bump, group, status)
+        instructions::update_record::update_jurisdiction(ctx, bump, group, jurisdiction)
+    }
+
+    /// Update the investor class flags of the subject
+    pub fn update_investor_class(
+        ctx: Context<UpdateRecord>,
+        bump: u8,
+        group: Pubkey,
+        investor_class: u8,
+    ) -> Result<()> {
+        instructions::update_record::update_investor_class(ctx, bump, group, investor_class)
     }
 
     /// transfer the authority of a record to another account
@@ -81,7 +110,7 @@ mod identity_verification {
         instructions::transfer_authority::transfer_authority(ctx, bump, group)
     }
 
-    /// deletes the record and transfers the rent back to the original signer
+    /// deletes the record according to its close policy and transfers the rent back to the payer
     pub fn delete_record(
         ctx: Context<DeleteRecord>,
         bump: u8,
@@ -90,4 +119,96 @@ mod identity_verification {
         instructions::delete_record::delete_record(ctx, bump, group)
     }
 
+    /// deletes the record on behalf of the subject and transfers the rent back to the payer
+    pub fn withdraw_consent(
+        ctx: Context<WithdrawConsent>,
+        bump: u8,
+        group: Pubkey,
+    ) -> Result<()> {
+        instructions::delete_record::withdraw_consent(ctx, bump, group)
+    }
+
+    /// migrates a record created before approval expiry was tracked to the current layout
+    pub fn migrate_record(
+        ctx: Context<MigrateRecord>,
+        bump: u8,
+        group: Pubkey,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::migrate_record::migrate_record(ctx, bump, group, expires_at)
+    }
+
+    /// Create the verifier registry for a group
+    pub fn create_group(
+        ctx: Context<CreateGroup>,
+        bump: u8,
+        group: Pubkey,
+    ) -> Result<()> {
+        instructions::create_group::create_group(ctx, bump, group)
+    }
+
+    /// Register a verifier with the given role for the group
+    pub fn add_verifier(
+        ctx: Context<UpdateGroup>,
+        bump: u8,
+        group: Pubkey,
+        verifier: Pubkey,
+        role: VerifierRole,
+    ) -> Result<()> {
+        instructions::update_group::add_verifier(ctx, bump, group, verifier, role)
+    }
+
+    /// Remove a verifier role from the group
+    pub fn remove_verifier(
+        ctx: Context<UpdateGroup>,
+        bump: u8,
+        group: Pubkey,
+        verifier: Pubkey,
+        role: VerifierRole,
+    ) -> Result<()> {
+        instructions::update_group::remove_verifier(ctx, bump, group, verifier, role)
+    }
+
+    /// Import statuses from an attestation signed by a verifier of the group
+    pub fn import_attestation(
+        ctx: Context<ImportAttestation>,
+        bump: u8,
+        group: Pubkey,
+        attestation: Attestation,
+    ) -> Result<()> {
+        instructions::import_attestation::import_attestation(ctx, bump, group, attestation)
+    }
+
+    /// Create the freeze configuration, signed by the upgrade authority of the program
+    pub fn create_freeze_config(
+        ctx: Context<CreateFreezeConfig>,
+        bump: u8,
+        authority: Pubkey,
+    ) -> Result<()> {
+        instructions::freeze::create_freeze_config(ctx, bump, authority)
+    }
+
+    /// Hand the freeze authority over to another account
+    pub fn set_freeze_authority(
+        ctx: Context<SetFreezeAuthority>,
+        authority: Pubkey,
+    ) -> Result<()> {
+        instructions::freeze::set_freeze_authority(ctx, authority)
+    }
+
+    /// Freeze a subject across every group
+    pub fn freeze_subject(
+        ctx: Context<FreezeSubject>,
+        bump: u8,
+    ) -> Result<()> {
+        instructions::freeze::freeze_subject(ctx, bump)
+    }
+
+    /// Lift the freeze of a subject
+    pub fn unfreeze_subject(
+        ctx: Context<UnfreezeSubject>,
+    ) -> Result<()> {
+        instructions::freeze::unfreeze_subject(ctx)
+    }
+
 }
\ No newline at end of file
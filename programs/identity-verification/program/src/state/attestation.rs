@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::state::identity_record::IdentityStatus;
+
+/// Off-chain attestation of identity statuses signed by a verifier of the group
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct Attestation {
+    /// Subject the attestation is about
+    pub subject: Pubkey,
+    /// Group the attestation was issued for
+    pub group: Pubkey,
+    /// Accreditation status, None leaves the current status untouched
+    pub ia_status: Option<IdentityStatus>,
+    /// AML status, None leaves the current status untouched
+    pub aml_status: Option<IdentityStatus>,
+    /// KYC status, None leaves the current status untouched
+    pub kyc_status: Option<IdentityStatus>,
+    /// Unix timestamp approvals in the attestation lapse at
+    pub expires_at: i64,
+    /// Unix timestamp the attestation was issued at, must be newer than the last imported attestation
+    pub issued_at: i64,
+}
+
+impl Attestation {
+    /// Domain separator prepended to the serialized attestation before signing
+    pub const PREFIX: &'static [u8] = b"tokr-identity-attestation";
+
+    /// Returns the message the verifier signs
+    pub fn to_message(&self) -> Result<Vec<u8>> {
+        let mut message = Attestation::PREFIX.to_vec();
+        self.serialize(&mut message)?;
+
+        Ok(message)
+    }
+
+    /// Returns true when the attestation doesn't carry any status
+    pub fn is_empty(&self) -> bool {
+        self.ia_status.is_none() && self.aml_status.is_none() && self.kyc_status.is_none()
+    }
+}
\ No newline at end of file
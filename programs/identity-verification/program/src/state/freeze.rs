@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+/// Program wide configuration of who may freeze subjects
+#[account]
+pub struct FreezeConfig {
+    /// bump seed used in deriving the pda for the config account
+    pub bump: u8,
+    /// Account who can freeze and unfreeze subjects across every group
+    pub authority: Pubkey,
+}
+
+impl FreezeConfig {
+    pub const LEN: usize = 8 + // discriminator
+        1 + // bump
+        32; // authority pubkey
+}
+
+/// Marks a subject as frozen across every group, the subject is frozen for as long as the account exists
+#[account]
+pub struct FrozenSubject {
+    /// bump seed used in deriving the pda for the frozen subject account
+    pub bump: u8,
+    /// Wallet which is frozen
+    pub subject: Pubkey,
+    /// Account who froze the subject
+    pub frozen_by: Pubkey,
+    /// Unix timestamp the subject was frozen at
+    pub frozen_at: i64,
+}
+
+impl FrozenSubject {
+    pub const LEN: usize = 8 + // discriminator
+        1 + // bump
+        32 + // subject pubkey
+        32 + // frozen by pubkey
+        8; // frozen at
+}
+
+/// Returns true when the account at the frozen subject pda has been created by this program
+pub fn is_frozen(frozen_subject: &AccountInfo) -> bool {
+    *frozen_subject.owner == crate::ID && !frozen_subject.data_is_empty()
+}
\ No newline at end of file
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+/// Role a verifier performs for a group
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VerifierRole {
+    /// May update the kyc status of records in the group
+    KycProvider,
+    /// May update the aml status of records in the group
+    AmlProvider,
+    /// May update the accreditation status of records in the group
+    AccreditationReviewer,
+}
+
+/// A key authorized to verify records of a group in a given role
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Verifier {
+    /// Key of the verifier
+    pub key: Pubkey,
+    /// Role the key may act in
+    pub role: VerifierRole,
+}
+
+#[account]
+pub struct Group {
+    /// bump seed used in deriving the pda for the group account
+    pub bump: u8,
+    /// Group the verifiers are registered for
+    pub group: Pubkey,
+    /// Account who can add and remove verifiers
+    pub authority: Pubkey,
+    /// Keys authorized to update records of the group, either directly or by signing attestations
+    pub verifiers: Vec<Verifier>,
+}
+
+impl Group {
+    pub const MAX_VERIFIERS: usize = 16;
+
+    pub const LEN: usize = 8 + // discriminator
+        1 + // bump
+        32 + // group pubkey
+        32 + // authority pubkey
+        4 + (Group::MAX_VERIFIERS * (32 + 1)); // verifiers
+
+    /// Returns true when the key is registered with the given role
+    pub fn has_role(&self, key: &Pubkey, role: VerifierRole) -> bool {
+        self.verifiers.iter().any(|v| v.key == *key && v.role == role)
+    }
+
+    /// Returns true when the key is registered with every role
+    pub fn has_all_roles(&self, key: &Pubkey) -> bool {
+        self.has_role(key, VerifierRole::KycProvider)
+            && self.has_role(key, VerifierRole::AmlProvider)
+            && self.has_role(key, VerifierRole::AccreditationReviewer)
+    }
+
+    /// Returns true when the key is registered with any role
+    pub fn is_verifier(&self, key: &Pubkey) -> bool {
+        self.verifiers.iter().any(|v| v.key == *key)
+    }
+}
\ No newline at end of file
@@ -1,23 +1,257 @@
 use anchor_lang::prelude::*;
 
+/// Status of a single identity check (kyc, aml or accreditation)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IdentityStatus {
+    /// Record has been created, but the status has not been updated yet
+    Initial,
+    /// The vendors have started to process the identity of the subject
+    Started,
+    /// The subject has passed the check
+    Approved,
+    /// The subject has failed the check
+    Denied,
+}
+
+impl Default for IdentityStatus {
+    fn default() -> Self {
+        IdentityStatus::Initial
+    }
+}
+
+/// Identity check a status belongs to
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IdentityCheck {
+    /// Investor accreditation
+    Accreditation,
+    /// Anti money laundering
+    Aml,
+    /// Know your customer
+    Kyc,
+}
+
+impl Default for IdentityCheck {
+    fn default() -> Self {
+        IdentityCheck::Accreditation
+    }
+}
+
+/// Who has to sign to close a record
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClosePolicy {
+    /// Only the authority of the record
+    Authority,
+    /// Only the subject of the record
+    Subject,
+    /// Both the authority and the subject of the record
+    AuthorityAndSubject,
+}
+
+impl Default for ClosePolicy {
+    fn default() -> Self {
+        ClosePolicy::Authority
+    }
+}
+
+/// A status change kept in the history of a record
+#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StatusChange {
+    /// Check the status belongs to
+    pub check: IdentityCheck,
+    /// Status before the change
+    pub previous_status: IdentityStatus,
+    /// Status after the change
+    pub status: IdentityStatus,
+    /// Verifier who made the change
+    pub verifier: Pubkey,
+    /// Unix timestamp of the change
+    pub timestamp: i64,
+}
+
+impl StatusChange {
+    pub const LEN: usize = 1 + // check
+        1 + // previous status
+        1 + // status
+        32 + // verifier pubkey
+        8; // timestamp
+}
+
+/// Investor class flags stored in `IdentityRecord::investor_class`
+pub mod investor_class {
+    /// Accredited investor (Reg D)
+    pub const ACCREDITED: u8 = 1 << 0;
+    /// Qualified purchaser
+    pub const QUALIFIED_PURCHASER: u8 = 1 << 1;
+    /// Non-US person (Reg S)
+    pub const NON_US_PERSON: u8 = 1 << 2;
+    /// Every known flag
+    pub const ALL: u8 = ACCREDITED | QUALIFIED_PURCHASER | NON_US_PERSON;
+}
+
 #[derive(Default)]
 #[account]
 pub struct IdentityRecord {
     /// bump seed used in deriving the pda for the status account
     pub bump: u8,
-    /// Accreditation status of the user (0 == initial, 1 == started, 2 == approved, 3 == rejected).
-    pub ia_status: u8,
-    /// AML status of the user (0 == initial, 1 == started, 2 == approved, 3 == rejected).
-    pub aml_status: u8,
-    /// KYC status of the user (0 == initial, 1 == started, 2 == approved, 3 == rejected).
-    pub kyc_status: u8,
-    /// Account who has update authority over the account
+    /// Accreditation status of the user.
+    pub ia_status: IdentityStatus,
+    /// AML status of the user.
+    pub aml_status: IdentityStatus,
+    /// KYC status of the user.
+    pub kyc_status: IdentityStatus,
+    /// Account who manages the record, statuses are updated by the verifiers of the group
     pub authority: Pubkey,
+    /// Unix timestamp the accreditation status was approved at (0 when not approved)
+    pub ia_approved_at: i64,
+    /// Unix timestamp the accreditation approval lapses at (0 when not approved)
+    pub ia_expires_at: i64,
+    /// Unix timestamp the aml status was approved at (0 when not approved)
+    pub aml_approved_at: i64,
+    /// Unix timestamp the aml approval lapses at (0 when not approved)
+    pub aml_expires_at: i64,
+    /// Unix timestamp the kyc status was approved at (0 when not approved)
+    pub kyc_approved_at: i64,
+    /// Unix timestamp the kyc approval lapses at (0 when not approved)
+    pub kyc_expires_at: i64,
+    /// ISO 3166-1 alpha-2 country code of the subject ([0, 0] when unknown)
+    pub jurisdiction: [u8; 2],
+    /// Bitmask of `investor_class` flags the subject qualifies for
+    pub investor_class: u8,
+    /// Issue timestamp of the last imported attestation, older attestations are rejected
+    pub attested_at: i64,
+    /// Slot in history the next change is written to
+    pub history_index: u8,
+    /// Ring buffer of the last `HISTORY_LEN` status changes
+    pub history: [StatusChange; 8],
+    /// Account who paid the rent of the record, the rent is returned to it when the record is closed
+    pub payer: Pubkey,
+    /// Signers required to close the record with `delete_record`
+    pub close_policy: ClosePolicy,
 }
 
 impl IdentityRecord {
     pub const LEN: usize = 8 + // discriminator
+        1 + // bump
+        (1 * 3) + // statuses
+        32 + // authority pubkey
+        (8 * 6) + // approval timestamps
+        2 + // jurisdiction
+        1 + // investor class
+        8 + // attested at
+        1 + // history index
+        (StatusChange::LEN * IdentityRecord::HISTORY_LEN) + // history
+        32 + // payer pubkey
+        1; // close policy
+
+    /// Number of status changes kept on the record
+    pub const HISTORY_LEN: usize = 8;
+
+    /// Size of records created before approval timestamps were introduced
+    pub const LEGACY_LEN: usize = 8 + // discriminator
         1 + // bump
         (1 * 3) + // statuses
         32; // authority pubkey
+
+    /// Sets the accreditation status, stamping or clearing the approval window
+    pub fn set_ia_status(&mut self, status: IdentityStatus, expires_at: i64, now: i64) {
+        let (approved_at, expires_at) = approval_window(status, expires_at, now);
+        self.ia_status = status;
+        self.ia_approved_at = approved_at;
+        self.ia_expires_at = expires_at;
+    }
+
+    /// Sets the aml status, stamping or clearing the approval window
+    pub fn set_aml_status(&mut self, status: IdentityStatus, expires_at: i64, now: i64) {
+        let (approved_at, expires_at) = approval_window(status, expires_at, now);
+        self.aml_status = status;
+        self.aml_approved_at = approved_at;
+        self.aml_expires_at = expires_at;
+    }
+
+    /// Sets the kyc status, stamping or clearing the approval window
+    pub fn set_kyc_status(&mut self, status: IdentityStatus, expires_at: i64, now: i64) {
+        let (approved_at, expires_at) = approval_window(status, expires_at, now);
+        self.kyc_status = status;
+        self.kyc_approved_at = approved_at;
+        self.kyc_expires_at = expires_at;
+    }
+
+    /// Sets the status of a check and appends the change to the history
+    pub fn update_status(
+        &mut self,
+        check: IdentityCheck,
+        status: IdentityStatus,
+        expires_at: i64,
+        verifier: Pubkey,
+        now: i64,
+    ) -> StatusChange {
+        let previous_status = match check {
+            IdentityCheck::Accreditation => self.ia_status,
+            IdentityCheck::Aml => self.aml_status,
+            IdentityCheck::Kyc => self.kyc_status,
+        };
+
+        match check {
+            IdentityCheck::Accreditation => self.set_ia_status(status, expires_at, now),
+            IdentityCheck::Aml => self.set_aml_status(status, expires_at, now),
+            IdentityCheck::Kyc => self.set_kyc_status(status, expires_at, now),
+        }
+
+        let change = StatusChange {
+            check,
+            previous_status,
+            status,
+            verifier,
+            timestamp: now,
+        };
+
+        self.history[self.history_index as usize % IdentityRecord::HISTORY_LEN] = change;
+        self.history_index = ((self.history_index as usize + 1) % IdentityRecord::HISTORY_LEN) as u8;
+
+        change
+    }
+
+    /// Returns true when the given signers satisfy the close policy of the record
+    pub fn can_close(&self, authority_signed: bool, subject_signed: bool) -> bool {
+        match self.close_policy {
+            ClosePolicy::Authority => authority_signed,
+            ClosePolicy::Subject => subject_signed,
+            ClosePolicy::AuthorityAndSubject => authority_signed && subject_signed,
+        }
+    }
+
+    /// Returns true when every check is approved and none of the approvals has lapsed
+    pub fn is_verified(&self, now: i64) -> bool {
+        is_approved(self.ia_status, self.ia_expires_at, now)
+            && is_approved(self.aml_status, self.aml_expires_at, now)
+            && is_approved(self.kyc_status, self.kyc_expires_at, now)
+    }
+}
+
+/// Returns true when the code is unset or two upper case ascii letters
+pub fn is_valid_jurisdiction(jurisdiction: &[u8; 2]) -> bool {
+    *jurisdiction == [0, 0] || jurisdiction.iter().all(|c| c.is_ascii_uppercase())
+}
+
+/// Returns true when the status is approved and the approval has not lapsed
+pub fn is_approved(status: IdentityStatus, expires_at: i64, now: i64) -> bool {
+    status == IdentityStatus::Approved && now < expires_at
+}
+
+fn approval_window(status: IdentityStatus, expires_at: i64, now: i64) -> (i64, i64) {
+    if status == IdentityStatus::Approved {
+        (now, expires_at)
+    } else {
+        (0, 0)
+    }
+}
+
+/// Layout of records created before approval timestamps were introduced
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyIdentityRecord {
+    pub bump: u8,
+    pub ia_status: IdentityStatus,
+    pub aml_status: IdentityStatus,
+    pub kyc_status: IdentityStatus,
+    pub authority: Pubkey,
 }
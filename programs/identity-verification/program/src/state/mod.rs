@@ -1,3 +1,9 @@
+pub use attestation::*;
+pub use freeze::*;
+pub use group::*;
 pub use identity_record::*;
 
-pub mod identity_record;
\ No newline at end of file
+pub mod attestation;
+pub mod freeze;
+pub mod group;
+pub mod identity_record;
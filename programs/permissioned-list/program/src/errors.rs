@@ -4,4 +4,22 @@ use anchor_lang::prelude::*;
 pub enum ErrorCode {
     #[msg("Not Authorized")]
     NotAuthorized,
+    #[msg("List name is too long")]
+    NameTooLong,
+    #[msg("List still has entries")]
+    ListNotEmpty,
+    #[msg("Entry expiry must be in the future")]
+    InvalidExpiry,
+    #[msg("User is not an active member of the list")]
+    NotAMember,
+    #[msg("Unknown or empty manager permissions")]
+    InvalidPermissions,
+    #[msg("Policy expression is not valid for its lists")]
+    InvalidPolicy,
+    #[msg("Expected a list and an entry account for every list of the policy")]
+    InvalidPolicyAccounts,
+    #[msg("List entry count is out of range")]
+    InvalidEntryCount,
+    #[msg("Account is not a legacy list or entry")]
+    InvalidLegacyAccount,
 }
\ No newline at end of file
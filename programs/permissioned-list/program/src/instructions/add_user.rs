@@ -1,14 +1,29 @@
 use anchor_lang::prelude::*;
-use crate::state::{EntryMetadata, ListMetadata};
+use crate::state::{is_authorized, permission, EntryMetadata, ListMetadata};
 use crate::errors::ErrorCode;
 
 pub fn add_user(
-    ctx: Context<AddUser>
+    ctx: Context<AddUser>,
+    expires_at: Option<i64>,
+    tag: u64,
 ) -> Result<()> {
     let list = &mut ctx.accounts.list;
     let signer = &mut ctx.accounts.signer;
+    let entry = &mut ctx.accounts.entry;
+    let now = Clock::get()?.unix_timestamp;
 
-    require!(list.authority == signer.key.clone(), ErrorCode::NotAuthorized);
+    require!(is_authorized(list, signer.key, &ctx.accounts.manager, permission::ADD)?, ErrorCode::NotAuthorized);
+    require!(expires_at.map_or(true, |expires_at| expires_at > now), ErrorCode::InvalidExpiry);
+
+    list.entry_count = list.entry_count.checked_add(1).ok_or(error!(ErrorCode::InvalidEntryCount))?;
+
+    entry.bump = *ctx.bumps.get("entry").unwrap();
+    entry.list = list.key();
+    entry.user = ctx.accounts.user.key();
+    entry.added_at = now;
+    entry.added_by = signer.key();
+    entry.expires_at = expires_at;
+    entry.tag = tag;
 
     Ok(())
 }
@@ -17,9 +32,11 @@ pub fn add_user(
 pub struct AddUser<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
-    #[account(mut, seeds = [b"list", signer.key.as_ref()], bump)]
+    #[account(mut, seeds = [b"list", list.creator.as_ref(), &list.list_id.to_le_bytes()], bump = list.bump)]
     pub list: Account<'info, ListMetadata>,
-    #[account(init, seeds = [list.key().as_ref(), user.key.as_ref()], bump, payer = signer, space = 8)]
+    /// CHECK: manager account of the signer, only read when the signer is not the authority
+    pub manager: UncheckedAccount<'info>,
+    #[account(init, seeds = [list.key().as_ref(), user.key.as_ref()], bump, payer = signer, space = EntryMetadata::LEN)]
     pub entry: Account<'info, EntryMetadata>,
     #[account(mut)]
     pub user: SystemAccount<'info>,
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::{self, ListMetadata};
+use crate::errors::ErrorCode;
+
+/// Fails unless the user holds an active entry on the list, meant to be called through cpi
+pub fn check_membership(
+    ctx: Context<CheckMembership>
+) -> Result<()> {
+    let is_member = state::check_membership(
+        &ctx.accounts.list.key(),
+        ctx.accounts.user.key,
+        &ctx.accounts.entry,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    require!(is_member, ErrorCode::NotAMember);
+
+    Ok(())
+}
+
+/// Fails unless the proof shows the user is a member of the merkle root of the list, meant to be called through cpi
+pub fn verify_membership_proof(
+    ctx: Context<VerifyMembershipProof>,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    require!(ctx.accounts.list.is_member_by_proof(ctx.accounts.user.key, &proof), ErrorCode::NotAMember);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CheckMembership<'info> {
+    pub list: Account<'info, ListMetadata>,
+    /// CHECK: may not exist, validated by check_membership
+    pub entry: UncheckedAccount<'info>,
+    /// CHECK: any account may be a member
+    pub user: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyMembershipProof<'info> {
+    pub list: Account<'info, ListMetadata>,
+    /// CHECK: any account may be a member
+    pub user: UncheckedAccount<'info>,
+}
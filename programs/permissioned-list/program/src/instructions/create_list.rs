@@ -1,22 +1,37 @@
 use anchor_lang::prelude::*;
-use crate::state::ListMetadata;
+use crate::state::{ListMetadata, ListType};
+use crate::errors::ErrorCode;
 
 pub fn create_list(
-    ctx: Context<CreateList>
+    ctx: Context<CreateList>,
+    list_id: u64,
+    name: String,
+    list_type: ListType,
 ) -> Result<()> {
     let list = &mut ctx.accounts.list;
     let signer = &mut ctx.accounts.signer;
 
+    require!(name.len() <= ListMetadata::MAX_NAME_LEN, ErrorCode::NameTooLong);
+
     list.authority = signer.key.clone();
+    list.creator = signer.key.clone();
+    list.list_id = list_id;
+    list.bump = *ctx.bumps.get("list").unwrap();
+    list.name = name;
+    list.created_at = Clock::get()?.unix_timestamp;
+    list.entry_count = 0;
+    list.merkle_root = None;
+    list.list_type = list_type;
 
     Ok(())
 }
 
 #[derive(Accounts)]
+#[instruction(list_id: u64)]
 pub struct CreateList<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
-    #[account(init, seeds = [b"list", signer.key.as_ref()], bump, payer = signer, space = 8 + 32)]
+    #[account(init, seeds = [b"list", signer.key.as_ref(), &list_id.to_le_bytes()], bump, payer = signer, space = ListMetadata::LEN)]
     pub list: Account<'info, ListMetadata>,
     pub system_program: Program<'info, System>,
-}
\ No newline at end of file
+}
@@ -10,6 +10,7 @@ pub fn delete_list(
     let signer = &mut ctx.accounts.signer;
 
     require!(list.authority == signer.key.clone(), ErrorCode::NotAuthorized);
+    require!(list.entry_count == 0, ErrorCode::ListNotEmpty);
 
     Ok(())
 }
@@ -18,6 +19,6 @@ pub fn delete_list(
 pub struct DeleteList<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
-    #[account(mut, close = signer, seeds = [b"list", signer.key.as_ref()], bump)]
+    #[account(mut, close = signer, seeds = [b"list", list.creator.as_ref(), &list.list_id.to_le_bytes()], bump = list.bump)]
     pub list: Account<'info, ListMetadata>,
 }
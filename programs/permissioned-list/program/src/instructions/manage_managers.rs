@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::state::{is_authorized, permission, ListMetadata, Manager};
+use crate::errors::ErrorCode;
+
+pub fn grant_manager(
+    ctx: Context<GrantManager>,
+    permissions: u8,
+) -> Result<()> {
+    let list = &ctx.accounts.list;
+    let signer = &ctx.accounts.signer;
+
+    require!(permissions != 0 && permissions & !permission::ALL == 0, ErrorCode::InvalidPermissions);
+    require!(can_manage(list, signer.key, &ctx.accounts.signer_manager, permissions)?, ErrorCode::NotAuthorized);
+
+    let manager = &mut ctx.accounts.manager;
+
+    manager.bump = *ctx.bumps.get("manager").unwrap();
+    manager.list = list.key();
+    manager.manager = ctx.accounts.manager_account.key();
+    manager.permissions = permissions;
+    manager.granted_by = signer.key();
+
+    Ok(())
+}
+
+pub fn revoke_manager(
+    ctx: Context<RevokeManager>
+) -> Result<()> {
+    let list = &ctx.accounts.list;
+    let signer = &ctx.accounts.signer;
+
+    require!(can_manage(list, signer.key, &ctx.accounts.signer_manager, ctx.accounts.manager.permissions)?, ErrorCode::NotAuthorized);
+
+    Ok(())
+}
+
+/// admin permission is granted and revoked by the authority only, admins handle the other permissions
+fn can_manage(list: &Account<ListMetadata>, signer: &Pubkey, signer_manager: &AccountInfo, permissions: u8) -> Result<bool> {
+    if permissions & permission::ADMIN != 0 {
+        return Ok(list.authority == *signer);
+    }
+
+    is_authorized(list, signer, signer_manager, permission::ADMIN)
+}
+
+#[derive(Accounts)]
+pub struct GrantManager<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    /// CHECK: manager account of the signer, only read when the signer is not the authority
+    pub signer_manager: UncheckedAccount<'info>,
+    #[account(seeds = [b"list", list.creator.as_ref(), &list.list_id.to_le_bytes()], bump = list.bump)]
+    pub list: Account<'info, ListMetadata>,
+    #[account(init, seeds = [b"manager", list.key().as_ref(), manager_account.key.as_ref()], bump, payer = signer, space = Manager::LEN)]
+    pub manager: Account<'info, Manager>,
+    pub manager_account: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeManager<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    /// CHECK: manager account of the signer, only read when the signer is not the authority
+    pub signer_manager: UncheckedAccount<'info>,
+    #[account(seeds = [b"list", list.creator.as_ref(), &list.list_id.to_le_bytes()], bump = list.bump)]
+    pub list: Account<'info, ListMetadata>,
+    #[account(mut, close = granted_by, seeds = [b"manager", list.key().as_ref(), manager_account.key.as_ref()], bump = manager.bump)]
+    pub manager: Account<'info, Manager>,
+    pub manager_account: SystemAccount<'info>,
+    /// rent of the manager account is returned to the account that paid for it
+    #[account(mut, address = manager.granted_by)]
+    pub granted_by: SystemAccount<'info>,
+}
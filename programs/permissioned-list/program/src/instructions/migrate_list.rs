@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::state::{EntryMetadata, ListMetadata, ListType};
+use crate::errors::ErrorCode;
+
+/// Move a legacy list, seeded by its authority only, to a list seeded by its creator and id.
+/// Entries of the legacy list are moved one by one with migrate_entry and the legacy list is closed to the signer.
+pub fn migrate_list(
+    ctx: Context<MigrateList>,
+    list_id: u64,
+    name: String,
+    list_type: ListType,
+) -> Result<()> {
+    let legacy_list = &ctx.accounts.legacy_list;
+    let list = &mut ctx.accounts.list;
+    let signer = &ctx.accounts.signer;
+
+    let legacy_authority = {
+        let data = legacy_list.try_borrow_data()?;
+
+        require!(data.len() == ListMetadata::LEGACY_LEN, ErrorCode::InvalidLegacyAccount);
+        require!(data[..8] == ListMetadata::discriminator(), ErrorCode::InvalidLegacyAccount);
+
+        Pubkey::deserialize(&mut &data[8..])?
+    };
+
+    require!(legacy_authority == signer.key(), ErrorCode::NotAuthorized);
+    require!(name.len() <= ListMetadata::MAX_NAME_LEN, ErrorCode::NameTooLong);
+
+    list.authority = signer.key();
+    list.creator = signer.key();
+    list.list_id = list_id;
+    list.bump = *ctx.bumps.get("list").unwrap();
+    list.name = name;
+    list.created_at = Clock::get()?.unix_timestamp;
+    list.entry_count = 0;
+    list.merkle_root = None;
+    list.list_type = list_type;
+
+    close_legacy_account(&legacy_list.to_account_info(), &signer.to_account_info())
+}
+
+/// Move an entry of the legacy list of the signer to the migrated list, the legacy entry is closed to the signer
+/// who paid for it when it was added
+pub fn migrate_entry(
+    ctx: Context<MigrateEntry>
+) -> Result<()> {
+    let legacy_entry = &ctx.accounts.legacy_entry;
+    let list = &mut ctx.accounts.list;
+    let signer = &ctx.accounts.signer;
+    let entry = &mut ctx.accounts.entry;
+
+    {
+        let data = legacy_entry.try_borrow_data()?;
+
+        require!(data.len() == EntryMetadata::LEGACY_LEN, ErrorCode::InvalidLegacyAccount);
+        require!(data[..8] == EntryMetadata::discriminator(), ErrorCode::InvalidLegacyAccount);
+    }
+
+    require!(list.creator == signer.key() && list.authority == signer.key(), ErrorCode::NotAuthorized);
+
+    list.entry_count = list.entry_count.checked_add(1).ok_or(error!(ErrorCode::InvalidEntryCount))?;
+
+    entry.bump = *ctx.bumps.get("entry").unwrap();
+    entry.list = list.key();
+    entry.user = ctx.accounts.user.key();
+    // legacy entries didn't record when they were added
+    entry.added_at = Clock::get()?.unix_timestamp;
+    entry.added_by = signer.key();
+    entry.expires_at = None;
+    entry.tag = 0;
+
+    close_legacy_account(&legacy_entry.to_account_info(), &signer.to_account_info())
+}
+
+/// Moves the lamports of a legacy account to the destination and wipes its data
+fn close_legacy_account<'info>(account: &AccountInfo<'info>, destination: &AccountInfo<'info>) -> Result<()> {
+    let lamports = destination.lamports().saturating_add(account.lamports());
+
+    **destination.try_borrow_mut_lamports()? = lamports;
+    **account.try_borrow_mut_lamports()? = 0;
+
+    account.try_borrow_mut_data()?.fill(0);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(list_id: u64)]
+pub struct MigrateList<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    /// CHECK: legacy lists no longer deserialize as a ListMetadata, the layout is validated in the handler
+    #[account(mut, owner = crate::ID, seeds = [b"list", signer.key.as_ref()], bump)]
+    pub legacy_list: UncheckedAccount<'info>,
+    #[account(init, seeds = [b"list", signer.key.as_ref(), &list_id.to_le_bytes()], bump, payer = signer, space = ListMetadata::LEN)]
+    pub list: Account<'info, ListMetadata>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateEntry<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    /// CHECK: only the address of the legacy list is used to derive the legacy entry, the list may already be migrated
+    #[account(seeds = [b"list", signer.key.as_ref()], bump)]
+    pub legacy_list: UncheckedAccount<'info>,
+    #[account(mut, seeds = [b"list", list.creator.as_ref(), &list.list_id.to_le_bytes()], bump = list.bump)]
+    pub list: Account<'info, ListMetadata>,
+    /// CHECK: legacy entries no longer deserialize as an EntryMetadata, the layout is validated in the handler
+    #[account(mut, owner = crate::ID, seeds = [legacy_list.key().as_ref(), user.key.as_ref()], bump)]
+    pub legacy_entry: UncheckedAccount<'info>,
+    #[account(init, seeds = [list.key().as_ref(), user.key.as_ref()], bump, payer = signer, space = EntryMetadata::LEN)]
+    pub entry: Account<'info, EntryMetadata>,
+    pub user: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
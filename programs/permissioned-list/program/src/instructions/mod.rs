@@ -1,9 +1,23 @@
 pub use add_user::*;
+pub use check_membership::*;
 pub use create_list::*;
 pub use delete_list::*;
+pub use manage_managers::*;
+pub use migrate_list::*;
+pub use policy::*;
 pub use remove_user::*;
+pub use set_merkle_root::*;
+pub use transfer_list_authority::*;
+pub use update_entry::*;
 
 pub mod add_user;
+pub mod check_membership;
 pub mod create_list;
 pub mod delete_list;
-pub mod remove_user;
\ No newline at end of file
+pub mod manage_managers;
+pub mod migrate_list;
+pub mod policy;
+pub mod remove_user;
+pub mod set_merkle_root;
+pub mod transfer_list_authority;
+pub mod update_entry;
\ No newline at end of file
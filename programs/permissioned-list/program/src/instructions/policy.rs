@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
+use crate::state::{check_membership, ListMetadata, Policy, PolicyOp};
+use crate::errors::ErrorCode;
+
+pub fn create_policy(
+    ctx: Context<CreatePolicy>,
+    policy_id: u64,
+    lists: Vec<Pubkey>,
+    ops: Vec<PolicyOp>,
+) -> Result<()> {
+    let policy = &mut ctx.accounts.policy;
+
+    require!(Policy::is_valid(&lists, &ops), ErrorCode::InvalidPolicy);
+
+    policy.bump = *ctx.bumps.get("policy").unwrap();
+    policy.authority = ctx.accounts.signer.key();
+    policy.policy_id = policy_id;
+    policy.lists = lists;
+    policy.ops = ops;
+
+    Ok(())
+}
+
+pub fn update_policy(
+    ctx: Context<UpdatePolicy>,
+    lists: Vec<Pubkey>,
+    ops: Vec<PolicyOp>,
+) -> Result<()> {
+    let policy = &mut ctx.accounts.policy;
+
+    require!(Policy::is_valid(&lists, &ops), ErrorCode::InvalidPolicy);
+
+    policy.lists = lists;
+    policy.ops = ops;
+
+    Ok(())
+}
+
+pub fn delete_policy(
+    _ctx: Context<DeletePolicy>
+) -> Result<()> {
+    Ok(())
+}
+
+/// Sets a single byte of return data, 1 when the policy admits the user and 0 otherwise.
+/// The remaining accounts are a list account followed by the entry pda of the user for every list of the policy.
+pub fn evaluate_policy(
+    ctx: Context<EvaluatePolicy>
+) -> Result<()> {
+    let policy = &ctx.accounts.policy;
+    let user = ctx.accounts.user.key;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(ctx.remaining_accounts.len() == policy.lists.len() * 2, ErrorCode::InvalidPolicyAccounts);
+
+    let mut admitted = Vec::with_capacity(policy.lists.len());
+
+    for (expected_list, accounts) in policy.lists.iter().zip(ctx.remaining_accounts.chunks(2)) {
+        let (list_info, entry_info) = (&accounts[0], &accounts[1]);
+
+        require!(list_info.key == expected_list && list_info.owner == &crate::ID, ErrorCode::InvalidPolicyAccounts);
+
+        let list = ListMetadata::try_deserialize(&mut &list_info.try_borrow_data()?[..])?;
+        let is_member = check_membership(list_info.key, user, entry_info, now)?;
+
+        admitted.push(list.admits(is_member));
+    }
+
+    set_return_data(&[policy.evaluate(&admitted) as u8]);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(policy_id: u64)]
+pub struct CreatePolicy<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(init, seeds = [b"policy", signer.key.as_ref(), &policy_id.to_le_bytes()], bump, payer = signer, space = Policy::LEN)]
+    pub policy: Account<'info, Policy>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePolicy<'info> {
+    pub signer: Signer<'info>,
+    #[account(mut, constraint = policy.authority == signer.key() @ ErrorCode::NotAuthorized, seeds = [b"policy", policy.authority.as_ref(), &policy.policy_id.to_le_bytes()], bump = policy.bump)]
+    pub policy: Account<'info, Policy>,
+}
+
+#[derive(Accounts)]
+pub struct DeletePolicy<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(mut, close = signer, constraint = policy.authority == signer.key() @ ErrorCode::NotAuthorized, seeds = [b"policy", policy.authority.as_ref(), &policy.policy_id.to_le_bytes()], bump = policy.bump)]
+    pub policy: Account<'info, Policy>,
+}
+
+#[derive(Accounts)]
+pub struct EvaluatePolicy<'info> {
+    pub policy: Account<'info, Policy>,
+    /// CHECK: any account may be evaluated
+    pub user: UncheckedAccount<'info>,
+}
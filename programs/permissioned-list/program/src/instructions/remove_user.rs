@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::state::{EntryMetadata, ListMetadata};
+use crate::state::{is_authorized, permission, EntryMetadata, ListMetadata};
 use crate::errors::ErrorCode;
 
 pub fn remove_user(
@@ -8,7 +8,9 @@ pub fn remove_user(
     let list = &mut ctx.accounts.list;
     let signer = &mut ctx.accounts.signer;
 
-    require!(list.authority == signer.key.clone(), ErrorCode::NotAuthorized);
+    require!(is_authorized(list, signer.key, &ctx.accounts.manager, permission::REMOVE)?, ErrorCode::NotAuthorized);
+
+    list.entry_count = list.entry_count.checked_sub(1).ok_or(error!(ErrorCode::InvalidEntryCount))?;
 
     Ok(())
 }
@@ -17,10 +19,15 @@ pub fn remove_user(
 pub struct RemoveUser<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
-    #[account(mut, seeds = [b"list", signer.key.as_ref()], bump)]
+    #[account(mut, seeds = [b"list", list.creator.as_ref(), &list.list_id.to_le_bytes()], bump = list.bump)]
     pub list: Account<'info, ListMetadata>,
-    #[account(mut, close = signer, seeds = [list.key().as_ref(), user.key.as_ref()], bump)]
+    /// CHECK: manager account of the signer, only read when the signer is not the authority
+    pub manager: UncheckedAccount<'info>,
+    #[account(mut, close = added_by, seeds = [list.key().as_ref(), user.key.as_ref()], bump)]
     pub entry: Account<'info, EntryMetadata>,
     #[account(mut)]
     pub user: SystemAccount<'info>,
+    /// rent of the entry is returned to the account that paid for it
+    #[account(mut, address = entry.added_by)]
+    pub added_by: SystemAccount<'info>,
 }
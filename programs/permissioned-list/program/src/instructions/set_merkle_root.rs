@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+use crate::state::{is_authorized, permission, ListMetadata};
+use crate::errors::ErrorCode;
+
+/// Set or rotate the merkle root of the list, None stops accepting proofs
+pub fn set_merkle_root(
+    ctx: Context<SetMerkleRoot>,
+    merkle_root: Option<[u8; 32]>,
+) -> Result<()> {
+    let list = &mut ctx.accounts.list;
+    let signer = &ctx.accounts.signer;
+
+    require!(is_authorized(list, signer.key, &ctx.accounts.manager, permission::ADMIN)?, ErrorCode::NotAuthorized);
+
+    list.merkle_root = merkle_root;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetMerkleRoot<'info> {
+    pub signer: Signer<'info>,
+    #[account(mut, seeds = [b"list", list.creator.as_ref(), &list.list_id.to_le_bytes()], bump = list.bump)]
+    pub list: Account<'info, ListMetadata>,
+    /// CHECK: manager account of the signer, only read when the signer is not the authority
+    pub manager: UncheckedAccount<'info>,
+}
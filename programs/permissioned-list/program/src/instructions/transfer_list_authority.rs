@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::state::ListMetadata;
+use crate::errors::ErrorCode;
+
+pub fn transfer_list_authority(
+    ctx: Context<TransferListAuthority>
+) -> Result<()> {
+    let list = &mut ctx.accounts.list;
+    let signer = &mut ctx.accounts.signer;
+
+    require!(list.authority == signer.key.clone(), ErrorCode::NotAuthorized);
+
+    list.authority = ctx.accounts.transfer_to.key.clone();
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct TransferListAuthority<'info> {
+    pub signer: Signer<'info>,
+    #[account(mut, seeds = [b"list", list.creator.as_ref(), &list.list_id.to_le_bytes()], bump = list.bump)]
+    pub list: Account<'info, ListMetadata>,
+    pub transfer_to: SystemAccount<'info>,
+}
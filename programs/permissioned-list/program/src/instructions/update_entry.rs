@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::state::{is_authorized, permission, EntryMetadata, ListMetadata};
+use crate::errors::ErrorCode;
+
+pub fn update_entry(
+    ctx: Context<UpdateEntry>,
+    expires_at: Option<i64>,
+    tag: u64,
+) -> Result<()> {
+    let list = &ctx.accounts.list;
+    let signer = &ctx.accounts.signer;
+    let entry = &mut ctx.accounts.entry;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(is_authorized(list, signer.key, &ctx.accounts.manager, permission::ADMIN)?, ErrorCode::NotAuthorized);
+    require!(expires_at.map_or(true, |expires_at| expires_at > now), ErrorCode::InvalidExpiry);
+
+    entry.expires_at = expires_at;
+    entry.tag = tag;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateEntry<'info> {
+    pub signer: Signer<'info>,
+    #[account(seeds = [b"list", list.creator.as_ref(), &list.list_id.to_le_bytes()], bump = list.bump)]
+    pub list: Account<'info, ListMetadata>,
+    /// CHECK: manager account of the signer, only read when the signer is not the authority
+    pub manager: UncheckedAccount<'info>,
+    #[account(mut, seeds = [list.key().as_ref(), user.key.as_ref()], bump = entry.bump)]
+    pub entry: Account<'info, EntryMetadata>,
+    pub user: SystemAccount<'info>,
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use instructions::*;
+use state::{ListType, PolicyOp};
 
 pub mod instructions;
 pub mod state;
@@ -14,11 +15,14 @@ declare_id!("9rMCpctTpKwaM1Wd6ppAgRLhbb9X3kJnZvzCrzy5kiDC"); // localnet
 mod permissioned_list {
     use super::*;
 
-    /// Create a list
+    /// Create an allow or deny list, a signer may create many lists with distinct ids
     pub fn create_list(
-        ctx: Context<CreateList>
+        ctx: Context<CreateList>,
+        list_id: u64,
+        name: String,
+        list_type: ListType,
     ) -> Result<()> {
-        instructions::create_list::create_list(ctx)
+        instructions::create_list::create_list(ctx, list_id, name, list_type)
     }
 
     /// delete a list
@@ -28,11 +32,22 @@ mod permissioned_list {
         instructions::delete_list::delete_list(ctx)
     }
 
-    /// add a user to a list
+    /// add a user to a list, optionally until the given unix timestamp
     pub fn add_user(
-        ctx: Context<AddUser>
+        ctx: Context<AddUser>,
+        expires_at: Option<i64>,
+        tag: u64,
     ) -> Result<()> {
-        instructions::add_user::add_user(ctx)
+        instructions::add_user::add_user(ctx, expires_at, tag)
+    }
+
+    /// update the expiry and tag of an entry
+    pub fn update_entry(
+        ctx: Context<UpdateEntry>,
+        expires_at: Option<i64>,
+        tag: u64,
+    ) -> Result<()> {
+        instructions::update_entry::update_entry(ctx, expires_at, tag)
     }
 
     /// remove a user from a list
@@ -41,6 +56,101 @@ mod permissioned_list {
     ) -> Result<()> {
         instructions::remove_user::remove_user(ctx)
     }
+
+    /// transfer the authority of a list to another account
+    pub fn transfer_list_authority(
+        ctx: Context<TransferListAuthority>
+    ) -> Result<()> {
+        instructions::transfer_list_authority::transfer_list_authority(ctx)
+    }
+
+    /// set or rotate the merkle root of members of a list
+    pub fn set_merkle_root(
+        ctx: Context<SetMerkleRoot>,
+        merkle_root: Option<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::set_merkle_root::set_merkle_root(ctx, merkle_root)
+    }
+
+    /// grant add/remove/admin permissions on a list to a manager
+    pub fn grant_manager(
+        ctx: Context<GrantManager>,
+        permissions: u8,
+    ) -> Result<()> {
+        instructions::manage_managers::grant_manager(ctx, permissions)
+    }
+
+    /// revoke every permission of a manager
+    pub fn revoke_manager(
+        ctx: Context<RevokeManager>
+    ) -> Result<()> {
+        instructions::manage_managers::revoke_manager(ctx)
+    }
+
+    /// move a list created before lists had ids to a list with the given id, name and type
+    pub fn migrate_list(
+        ctx: Context<MigrateList>,
+        list_id: u64,
+        name: String,
+        list_type: ListType,
+    ) -> Result<()> {
+        instructions::migrate_list::migrate_list(ctx, list_id, name, list_type)
+    }
+
+    /// move an entry of a legacy list to the migrated list
+    pub fn migrate_entry(
+        ctx: Context<MigrateEntry>
+    ) -> Result<()> {
+        instructions::migrate_list::migrate_entry(ctx)
+    }
+
+    /// create a policy combining lists
+    pub fn create_policy(
+        ctx: Context<CreatePolicy>,
+        policy_id: u64,
+        lists: Vec<Pubkey>,
+        ops: Vec<PolicyOp>,
+    ) -> Result<()> {
+        instructions::policy::create_policy(ctx, policy_id, lists, ops)
+    }
+
+    /// replace the lists and the expression of a policy
+    pub fn update_policy(
+        ctx: Context<UpdatePolicy>,
+        lists: Vec<Pubkey>,
+        ops: Vec<PolicyOp>,
+    ) -> Result<()> {
+        instructions::policy::update_policy(ctx, lists, ops)
+    }
+
+    /// delete a policy
+    pub fn delete_policy(
+        ctx: Context<DeletePolicy>
+    ) -> Result<()> {
+        instructions::policy::delete_policy(ctx)
+    }
+
+    /// evaluate a policy for a user, the result is set as return data
+    pub fn evaluate_policy(
+        ctx: Context<EvaluatePolicy>
+    ) -> Result<()> {
+        instructions::policy::evaluate_policy(ctx)
+    }
+
+    /// fail unless the user holds an active entry on the list
+    pub fn check_membership(
+        ctx: Context<CheckMembership>
+    ) -> Result<()> {
+        instructions::check_membership::check_membership(ctx)
+    }
+
+    /// fail unless the proof shows the user is a member of the merkle root of a list
+    pub fn verify_membership_proof(
+        ctx: Context<VerifyMembershipProof>,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::check_membership::verify_membership_proof(ctx, proof)
+    }
 }
 
 
@@ -2,4 +2,55 @@ use anchor_lang::prelude::*;
 
 #[derive(Default)]
 #[account]
-pub struct EntryMetadata {}
+pub struct EntryMetadata {
+    /// bump seed used in deriving the pda of the entry
+    pub bump: u8,
+    /// list the entry belongs to
+    pub list: Pubkey,
+    /// user the entry was added for
+    pub user: Pubkey,
+    /// unix timestamp the entry was added at
+    pub added_at: i64,
+    /// account that added the entry
+    pub added_by: Pubkey,
+    /// unix timestamp the entry lapses at, never when none
+    pub expires_at: Option<i64>,
+    /// free form value set by the list authority, e.g. a tier or a max allocation
+    pub tag: u64,
+}
+
+impl EntryMetadata {
+    /// size of entries created before entries had metadata, they only held the discriminator
+    pub const LEGACY_LEN: usize = 8; // discriminator
+
+    pub const LEN: usize = 8 + // discriminator
+        1 + // bump
+        32 + // list pubkey
+        32 + // user pubkey
+        8 + // added at
+        32 + // added by pubkey
+        (1 + 8) + // expires at
+        8; // tag
+
+    /// Returns true when the entry has not lapsed
+    pub fn is_active(&self, now: i64) -> bool {
+        match self.expires_at {
+            Some(expires_at) => now < expires_at,
+            None => true,
+        }
+    }
+}
+
+/// Returns true when the entry account is an active entry of the user on the list.
+/// Missing, foreign and expired entries are all treated as absent.
+pub fn check_membership(list: &Pubkey, user: &Pubkey, entry: &AccountInfo, now: i64) -> Result<bool> {
+    let (entry_address, _) = Pubkey::find_program_address(&[list.as_ref(), user.as_ref()], &crate::ID);
+
+    if entry.key() != entry_address || entry.owner != &crate::ID || entry.data_is_empty() {
+        return Ok(false);
+    }
+
+    let entry = EntryMetadata::try_deserialize(&mut &entry.try_borrow_data()?[..])?;
+
+    Ok(entry.list == *list && entry.user == *user && entry.is_active(now))
+}
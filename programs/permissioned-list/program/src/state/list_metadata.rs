@@ -1,7 +1,77 @@
 use anchor_lang::prelude::*;
 
+use crate::state::merkle::{leaf_hash, verify_proof};
+
+/// How membership of a list is read
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ListType {
+    /// members are admitted, everyone else is rejected
+    Allow,
+    /// members are rejected, everyone else is admitted
+    Deny,
+}
+
+impl Default for ListType {
+    fn default() -> Self {
+        ListType::Allow
+    }
+}
+
 #[derive(Default)]
 #[account]
 pub struct ListMetadata {
+    /// account allowed to manage the list
     pub authority: Pubkey,
+    /// account that created the list, part of the list seeds
+    pub creator: Pubkey,
+    /// identifier of the list among the lists of the creator, part of the list seeds
+    pub list_id: u64,
+    /// bump seed used in deriving the pda of the list
+    pub bump: u8,
+    /// human readable name of the list
+    pub name: String,
+    /// unix timestamp the list was created at
+    pub created_at: i64,
+    /// number of entries currently on the list
+    pub entry_count: u64,
+    /// root of a merkle tree of members, users can prove membership without an entry account
+    pub merkle_root: Option<[u8; 32]>,
+    /// whether members of the list are admitted or rejected
+    pub list_type: ListType,
+}
+
+impl ListMetadata {
+    /// max length of the name in bytes
+    pub const MAX_NAME_LEN: usize = 32;
+
+    /// size of lists created before lists had ids, they only held the authority and were seeded by it
+    pub const LEGACY_LEN: usize = 8 + // discriminator
+        32; // authority pubkey
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // authority pubkey
+        32 + // creator pubkey
+        8 + // list id
+        1 + // bump
+        (4 + ListMetadata::MAX_NAME_LEN) + // name
+        8 + // created at
+        8 + // entry count
+        (1 + 32) + // merkle root
+        1; // list type
+
+    /// Returns true when a user with the given membership is admitted by the list
+    pub fn admits(&self, is_member: bool) -> bool {
+        match self.list_type {
+            ListType::Allow => is_member,
+            ListType::Deny => !is_member,
+        }
+    }
+
+    /// Returns true when the proof shows the user is a leaf of the merkle root of the list
+    pub fn is_member_by_proof(&self, user: &Pubkey, proof: &[[u8; 32]]) -> bool {
+        match self.merkle_root {
+            Some(root) => verify_proof(&root, leaf_hash(user), proof),
+            None => false,
+        }
+    }
 }
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::state::ListMetadata;
+
+/// Permission flags stored in `Manager::permissions`
+pub mod permission {
+    /// May add users to the list
+    pub const ADD: u8 = 1 << 0;
+    /// May remove users from the list
+    pub const REMOVE: u8 = 1 << 1;
+    /// May update entries and grant or revoke add/remove managers
+    pub const ADMIN: u8 = 1 << 2;
+    /// Every known flag
+    pub const ALL: u8 = ADD | REMOVE | ADMIN;
+}
+
+#[derive(Default)]
+#[account]
+pub struct Manager {
+    /// bump seed used in deriving the pda of the manager
+    pub bump: u8,
+    /// list the manager acts on
+    pub list: Pubkey,
+    /// account granted the permissions
+    pub manager: Pubkey,
+    /// bitmask of `permission` flags
+    pub permissions: u8,
+    /// account that granted the permissions and paid for the manager account
+    pub granted_by: Pubkey,
+}
+
+impl Manager {
+    pub const LEN: usize = 8 + // discriminator
+        1 + // bump
+        32 + // list pubkey
+        32 + // manager pubkey
+        1 + // permissions
+        32; // granted by pubkey
+}
+
+/// Returns true when the signer is the list authority or a manager of the list holding the permission.
+/// The manager account is only read when the signer is not the authority and may not exist.
+pub fn is_authorized(list: &Account<ListMetadata>, signer: &Pubkey, manager: &AccountInfo, permission: u8) -> Result<bool> {
+    if list.authority == *signer {
+        return Ok(true);
+    }
+
+    let (manager_address, _) = Pubkey::find_program_address(
+        &[b"manager", list.key().as_ref(), signer.as_ref()],
+        &crate::ID,
+    );
+
+    if manager.key() != manager_address || manager.owner != &crate::ID || manager.data_is_empty() {
+        return Ok(false);
+    }
+
+    let manager = Manager::try_deserialize(&mut &manager.try_borrow_data()?[..])?;
+
+    Ok(manager.permissions & permission == permission)
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak::hashv;
+
+/// prefix of leaf hashes, keeps leaves and inner nodes from being confused with each other
+pub const LEAF_PREFIX: &[u8] = &[0];
+
+/// prefix of inner node hashes
+pub const NODE_PREFIX: &[u8] = &[1];
+
+/// Returns the leaf of a user in a list merkle tree
+pub fn leaf_hash(user: &Pubkey) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, user.as_ref()]).to_bytes()
+}
+
+/// Returns the parent of two nodes, the pair is sorted so proofs don't need to carry the side of each node
+pub fn node_hash(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+
+    hashv(&[NODE_PREFIX, left, right]).to_bytes()
+}
+
+/// Returns true when folding the leaf with the proof yields the root
+pub fn verify_proof(root: &[u8; 32], leaf: [u8; 32], proof: &[[u8; 32]]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| node_hash(&node, sibling));
+
+    computed == *root
+}
@@ -1,5 +1,11 @@
 pub use entry_metadata::*;
 pub use list_metadata::*;
+pub use manager::*;
+pub use merkle::*;
+pub use policy::*;
 
 pub mod entry_metadata;
-pub mod list_metadata;
\ No newline at end of file
+pub mod list_metadata;
+pub mod manager;
+pub mod merkle;
+pub mod policy;
\ No newline at end of file
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+/// Step of a policy expression, evaluated in postfix order on a stack of results
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PolicyOp {
+    /// push whether the list at the index of the policy admits the user
+    List(u8),
+    /// pop two results and push true when both are true
+    And,
+    /// pop two results and push true when either is true
+    Or,
+    /// pop a result and push its negation
+    Not,
+}
+
+#[derive(Default)]
+#[account]
+pub struct Policy {
+    /// bump seed used in deriving the pda of the policy
+    pub bump: u8,
+    /// account allowed to manage the policy
+    pub authority: Pubkey,
+    /// identifier of the policy among the policies of the authority, part of the policy seeds
+    pub policy_id: u64,
+    /// lists referenced by the expression
+    pub lists: Vec<Pubkey>,
+    /// postfix expression combining the lists, e.g. [List(0), List(1), Not, And] reads "on list 0 and not on list 1"
+    pub ops: Vec<PolicyOp>,
+}
+
+impl Policy {
+    /// max number of lists a policy can reference
+    pub const MAX_LISTS: usize = 8;
+
+    /// max number of steps of a policy expression
+    pub const MAX_OPS: usize = 16;
+
+    pub const LEN: usize = 8 + // discriminator
+        1 + // bump
+        32 + // authority pubkey
+        8 + // policy id
+        (4 + 32 * Policy::MAX_LISTS) + // lists
+        (4 + 2 * Policy::MAX_OPS); // ops
+
+    /// Returns true when the expression only references known lists and leaves exactly one result
+    pub fn is_valid(lists: &[Pubkey], ops: &[PolicyOp]) -> bool {
+        if lists.is_empty() || lists.len() > Policy::MAX_LISTS || ops.len() > Policy::MAX_OPS {
+            return false;
+        }
+
+        let mut depth: usize = 0;
+
+        for op in ops {
+            depth = match op {
+                PolicyOp::List(index) if (*index as usize) < lists.len() => depth + 1,
+                PolicyOp::List(_) => return false,
+                PolicyOp::And | PolicyOp::Or if depth >= 2 => depth - 1,
+                PolicyOp::Not if depth >= 1 => depth,
+                _ => return false,
+            };
+        }
+
+        depth == 1
+    }
+
+    /// Evaluates the expression given whether each list of the policy admits the user
+    pub fn evaluate(&self, admitted: &[bool]) -> bool {
+        let mut stack: Vec<bool> = Vec::with_capacity(self.ops.len());
+
+        for op in &self.ops {
+            match op {
+                PolicyOp::List(index) => stack.push(admitted[*index as usize]),
+                PolicyOp::And => {
+                    let (b, a) = (stack.pop().unwrap(), stack.pop().unwrap());
+                    stack.push(a && b);
+                }
+                PolicyOp::Or => {
+                    let (b, a) = (stack.pop().unwrap(), stack.pop().unwrap());
+                    stack.push(a || b);
+                }
+                PolicyOp::Not => {
+                    let a = stack.pop().unwrap();
+                    stack.push(!a);
+                }
+            }
+        }
+
+        stack.pop().unwrap()
+    }
+}
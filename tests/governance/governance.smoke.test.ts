@@ -374,6 +374,7 @@ describe.skip("governance smoke test", () => {
                 tokenOwnerRecordPk,
                 governanceAuthorityPk,
                 transactionPk,
+                governancePk,
             );
 
             await withExecuteTransaction(
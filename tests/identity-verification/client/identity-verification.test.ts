@@ -1,4 +1,5 @@
 import {Connection, Keypair, PublicKey, sendAndConfirmTransaction, Transaction} from "@solana/web3.js";
+import {BN} from "@project-serum/anchor";
 import {createAccount} from "../../utils/create-account";
 import {
     IdentityVerificationService
@@ -9,6 +10,8 @@ import {
 } from "../../../programs/identity-verification/client/src";
 import {IdentityRecord} from "../../../programs/identity-verification/client/src/models/identity-record";
 import {IdentityStatus} from "../../../programs/identity-verification/client/src/models/identity-status";
+import {VerifierRole} from "../../../programs/identity-verification/client/src/models/verifier-role";
+import {ClosePolicy} from "../../../programs/identity-verification/client/src/models/close-policy";
 
 describe("identity verification tests", () => {
 
@@ -20,7 +23,9 @@ describe("identity verification tests", () => {
     let authorized: Keypair
     let alsoAuthorized: Keypair
     let unauthorized: Keypair
+    let groupKeypair: Keypair;
     let groupId: PublicKey;
+    let expiresAt: number;
 
     beforeAll(async () => {
 
@@ -30,11 +35,42 @@ describe("identity verification tests", () => {
         authorized = await createAccount(connection);
         alsoAuthorized = await createAccount(connection);
         unauthorized = await createAccount(connection);
-        groupId = Keypair.generate().publicKey
+        groupKeypair = Keypair.generate()
+        groupId = groupKeypair.publicKey
+        expiresAt = Math.floor(Date.now() / 1000) + 60 * 60 * 24 * 365
         service = new IdentityVerificationService(connection, programId)
 
     });
 
+    test("that group can be created and verifiers registered", async () => {
+
+        expect.assertions(1);
+
+        const sig = await service.createGroup(groupKeypair, authorized);
+        await connection.confirmTransaction(sig);
+
+        for (const role of [VerifierRole.kycProvider, VerifierRole.amlProvider, VerifierRole.accreditationReviewer]) {
+            const sig = await service.addVerifier(groupId, authorized, authorized.publicKey, role);
+            await connection.confirmTransaction(sig);
+        }
+
+        expect(sig).toBeDefined()
+
+    });
+
+    test("that verifiers cannot be registered by someone other than the group authority", async () => {
+
+        expect.assertions(1);
+
+        try {
+            const sig = await service.addVerifier(groupId, unauthorized, unauthorized.publicKey, VerifierRole.amlProvider);
+            await connection.confirmTransaction(sig);
+        } catch (error) {
+            expect(error).toBeDefined()
+        }
+
+    });
+
     test("that record can be created", async () => {
 
         expect.assertions(4);
@@ -64,10 +100,10 @@ describe("identity verification tests", () => {
 
     test("that record can be updated", async () => {
 
-        expect.assertions(1);
+        expect.assertions(2);
 
         try {
-            const sig = await service.updateAmlStatus(ownerKeypair.publicKey, groupId, authorized, IdentityStatus.approved);
+            const sig = await service.updateAmlStatus(ownerKeypair.publicKey, groupId, authorized, IdentityStatus.approved, expiresAt);
             await connection.confirmTransaction(sig);
         } catch {
             // ...
@@ -81,6 +117,7 @@ describe("identity verification tests", () => {
         )
 
         expect(record.amlStatus).toEqual(IdentityStatus.approved);
+        expect(record.amlExpiresAt).toEqual(expiresAt);
 
     });
 
@@ -111,7 +148,7 @@ describe("identity verification tests", () => {
         expect.assertions(1);
 
         try {
-            const sig = await service.approve(ownerKeypair.publicKey, groupId, authorized);
+            const sig = await service.approve(ownerKeypair.publicKey, groupId, authorized, expiresAt);
             await connection.confirmTransaction(sig);
         } catch {
             // ...
@@ -128,6 +165,21 @@ describe("identity verification tests", () => {
 
     });
 
+    test("that record cannot be approved with an expiry in the past", async () => {
+
+        expect.assertions(2);
+
+        try {
+            const sig = await service.updateKycStatus(ownerKeypair.publicKey, groupId, authorized, IdentityStatus.approved, 1);
+            await connection.confirmTransaction(sig);
+        } catch (error) {
+            expect(error).toBeDefined()
+            const record = await service.getRecord(ownerKeypair.publicKey, groupId)
+            expect(record.kycExpiresAt).toEqual(expiresAt);
+        }
+
+    });
+
     test("that record cannot be updated by unrecognized authority", async () => {
 
         expect.assertions(2);
@@ -196,17 +248,66 @@ describe("identity verification tests", () => {
 
     });
 
+    test("that subject can withdraw consent for a record paid by someone else", async () => {
+
+        expect.assertions(3);
+
+        const otherGroupId = Keypair.generate().publicKey
+
+        const tix = await createIdentityRecordInstruction(
+            connection,
+            programId,
+            ownerKeypair.publicKey,
+            otherGroupId,
+            alsoAuthorized.publicKey,
+            ClosePolicy.subject,
+            authorized.publicKey
+        );
+
+        const tx = new Transaction();
+        tx.add(tix);
+        tx.feePayer = ownerKeypair.publicKey
+
+        const sig = await sendAndConfirmTransaction(connection, tx, [ownerKeypair, authorized]);
+        await connection.confirmTransaction(sig);
+
+        try {
+            await service.deleteRecord(ownerKeypair.publicKey, otherGroupId, alsoAuthorized);
+        } catch (error) {
+            expect(error).toBeDefined()
+        }
+
+        const payerLamportsBefore = await connection.getBalance(authorized.publicKey);
+
+        const withdrawSig = await service.withdrawConsent(ownerKeypair, otherGroupId);
+        await connection.confirmTransaction(withdrawSig);
+
+        const payerLamportsAfter = await connection.getBalance(authorized.publicKey);
+
+        expect(payerLamportsAfter).toBeGreaterThan(payerLamportsBefore);
+
+        try {
+            await service.getRecord(ownerKeypair.publicKey, otherGroupId)
+        } catch (error) {
+            expect(error).toBeDefined();
+        }
+
+    });
+
     describe("combined status returns as expected", () => {
 
         test("when no statuses are initial", () => {
 
             const record = IdentityRecord.with(
                 {
-                    amlStatus: 0,
-                    iaStatus: 0,
-                    kycStatus: 0,
+                    amlStatus: {initial: {}},
+                    iaStatus: {initial: {}},
+                    kycStatus: {initial: {}},
                     bump: 0,
-                    authority: Keypair.generate().publicKey
+                    authority: Keypair.generate().publicKey,
+                    amlExpiresAt: new BN(0),
+                    iaExpiresAt: new BN(0),
+                    kycExpiresAt: new BN(0)
                 },
                 Keypair.generate().publicKey
             );
@@ -219,11 +320,14 @@ describe("identity verification tests", () => {
 
             const record = IdentityRecord.with(
                 {
-                    amlStatus: 1,
-                    iaStatus: 1,
-                    kycStatus: 0,
+                    amlStatus: {started: {}},
+                    iaStatus: {started: {}},
+                    kycStatus: {initial: {}},
                     bump: 0,
-                    authority: Keypair.generate().publicKey
+                    authority: Keypair.generate().publicKey,
+                    amlExpiresAt: new BN(0),
+                    iaExpiresAt: new BN(0),
+                    kycExpiresAt: new BN(0)
                 },
                 Keypair.generate().publicKey
             );
@@ -236,11 +340,14 @@ describe("identity verification tests", () => {
 
             const record = IdentityRecord.with(
                 {
-                    amlStatus: 1,
-                    iaStatus: 3,
-                    kycStatus: 0,
+                    amlStatus: {started: {}},
+                    iaStatus: {denied: {}},
+                    kycStatus: {initial: {}},
                     bump: 0,
-                    authority: Keypair.generate().publicKey
+                    authority: Keypair.generate().publicKey,
+                    amlExpiresAt: new BN(0),
+                    iaExpiresAt: new BN(0),
+                    kycExpiresAt: new BN(0)
                 },
                 Keypair.generate().publicKey
             );
@@ -253,11 +360,14 @@ describe("identity verification tests", () => {
 
             const record = IdentityRecord.with(
                 {
-                    amlStatus: 2,
-                    iaStatus: 2,
-                    kycStatus: 1,
+                    amlStatus: {approved: {}},
+                    iaStatus: {approved: {}},
+                    kycStatus: {started: {}},
                     bump: 0,
-                    authority: Keypair.generate().publicKey
+                    authority: Keypair.generate().publicKey,
+                    amlExpiresAt: new BN(expiresAt),
+                    iaExpiresAt: new BN(expiresAt),
+                    kycExpiresAt: new BN(0)
                 },
                 Keypair.generate().publicKey
             );
@@ -270,16 +380,41 @@ describe("identity verification tests", () => {
 
             const record = IdentityRecord.with(
                 {
-                    amlStatus: 2,
-                    iaStatus: 2,
-                    kycStatus: 2,
+                    amlStatus: {approved: {}},
+                    iaStatus: {approved: {}},
+                    kycStatus: {approved: {}},
+                    bump: 0,
+                    authority: Keypair.generate().publicKey,
+                    amlExpiresAt: new BN(expiresAt),
+                    iaExpiresAt: new BN(expiresAt),
+                    kycExpiresAt: new BN(expiresAt)
+                },
+                Keypair.generate().publicKey
+            );
+
+            expect(record.status).toEqual(IdentityStatus.approved)
+            expect(record.isVerified).toBeTruthy()
+
+        })
+
+        test("when approvals have lapsed", () => {
+
+            const record = IdentityRecord.with(
+                {
+                    amlStatus: {approved: {}},
+                    iaStatus: {approved: {}},
+                    kycStatus: {approved: {}},
                     bump: 0,
-                    authority: Keypair.generate().publicKey
+                    authority: Keypair.generate().publicKey,
+                    amlExpiresAt: new BN(1),
+                    iaExpiresAt: new BN(1),
+                    kycExpiresAt: new BN(1)
                 },
                 Keypair.generate().publicKey
             );
 
             expect(record.status).toEqual(IdentityStatus.approved)
+            expect(record.isVerified).toBeFalsy()
 
         })
 
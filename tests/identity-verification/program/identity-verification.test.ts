@@ -1,7 +1,7 @@
 import * as anchor from "@project-serum/anchor";
-import {AnchorProvider, Program} from "@project-serum/anchor";
+import {AnchorProvider, BN, Program} from "@project-serum/anchor";
 import {IdentityVerification} from "../../../target/types/identity_verification";
-import {Keypair, PublicKey, Transaction} from "@solana/web3.js";
+import {Ed25519Program, Keypair, PublicKey, SYSVAR_INSTRUCTIONS_PUBKEY, Transaction} from "@solana/web3.js";
 import {createAccount} from "../../utils/create-account";
 
 describe("test that the identity-verification program", () => {
@@ -12,9 +12,14 @@ describe("test that the identity-verification program", () => {
     let authority: Keypair;
     let newAuthority: Keypair;
     let nonAuthority: Keypair;
+    let kycProvider: Keypair;
     let groupKeypair: Keypair;
     let pdaPubkey: PublicKey;
     let pdaBump: number
+    let groupAccount: PublicKey;
+    let groupBump: number
+    let frozenSubject: PublicKey;
+    let expiresAt: BN;
 
     beforeAll(async () => {
 
@@ -25,7 +30,9 @@ describe("test that the identity-verification program", () => {
         authority = await createAccount(provider.connection);
         newAuthority = await createAccount(provider.connection);
         nonAuthority = await createAccount(provider.connection);
+        kycProvider = await createAccount(provider.connection);
         groupKeypair = anchor.web3.Keypair.generate();
+        expiresAt = new BN(Math.floor(Date.now() / 1000) + 60 * 60 * 24 * 365);
 
         const [account, bump] = await anchor.web3.PublicKey.findProgramAddress([
             Buffer.from("identity"),
@@ -36,15 +43,100 @@ describe("test that the identity-verification program", () => {
         pdaPubkey = account;
         pdaBump = bump;
 
+        const [group, groupAccountBump] = await anchor.web3.PublicKey.findProgramAddress([
+            Buffer.from("group"),
+            groupKeypair.publicKey.toBytes()
+        ], program.programId);
+
+        groupAccount = group;
+        groupBump = groupAccountBump;
+
+        const [freeze] = await anchor.web3.PublicKey.findProgramAddress([
+            Buffer.from("freeze"),
+            keypair.publicKey.toBytes()
+        ], program.programId);
+
+        frozenSubject = freeze;
+
+    })
+
+    test("fails to create a group without the signature of the group key", async () => {
+
+        expect.assertions(2);
+
+        try {
+
+            await program.methods.createGroup(groupBump, groupKeypair.publicKey)
+                .accounts({
+                    authority: nonAuthority.publicKey,
+                    groupKey: groupKeypair.publicKey,
+                    groupAccount: groupAccount,
+                    systemProgram: anchor.web3.SystemProgram.programId
+                })
+                .signers([nonAuthority])
+                .rpc();
+
+        } catch (error) {
+
+            expect(error).toBeDefined()
+
+        }
+
+        const accountInfo = await provider.connection.getAccountInfo(groupAccount);
+
+        expect(accountInfo).toBeNull();
+
+    })
+
+    test("succeeds in creating a group signed by the group key", async () => {
+
+        expect.assertions(2);
+
+        await program.methods.createGroup(groupBump, groupKeypair.publicKey)
+            .accounts({
+                authority: authority.publicKey,
+                groupKey: groupKeypair.publicKey,
+                groupAccount: groupAccount,
+                systemProgram: anchor.web3.SystemProgram.programId
+            })
+            .signers([authority, groupKeypair])
+            .rpc();
+
+        const accountMeta = await program.account.group.fetch(groupAccount);
+
+        expect(accountMeta.group.toBase58()).toEqual(groupKeypair.publicKey.toBase58());
+        expect(accountMeta.authority.toBase58()).toEqual(authority.publicKey.toBase58());
+
+    })
+
+    test("is able to register a verifier for every role of the group", async () => {
+
+        expect.assertions(1);
+
+        for (const role of [{kycProvider: {}}, {amlProvider: {}}, {accreditationReviewer: {}}]) {
+            await program.methods.addVerifier(groupBump, groupKeypair.publicKey, authority.publicKey, role)
+                .accounts({
+                    groupAccount: groupAccount,
+                    authority: authority.publicKey
+                })
+                .signers([authority])
+                .rpc();
+        }
+
+        const accountMeta = await program.account.group.fetch(groupAccount);
+
+        expect(accountMeta.verifiers.length).toEqual(3);
+
     })
 
     test("succeeds in creating a identity-verification record for a user", async () => {
 
-        expect.assertions(6);
+        expect.assertions(7);
 
-        const txi = await program.methods.createRecord(pdaBump, groupKeypair.publicKey)
+        const txi = await program.methods.createRecord(pdaBump, groupKeypair.publicKey, {authority: {}})
             .accounts({
                 signer: keypair.publicKey,
+                payer: keypair.publicKey,
                 record: pdaPubkey,
                 systemProgram: anchor.web3.SystemProgram.programId,
                 authority: authority.publicKey
@@ -62,10 +154,11 @@ describe("test that the identity-verification program", () => {
         const accountMeta = await program.account.identityRecord.fetch(pdaPubkey);
 
         expect(accountInfo!.owner.toBase58()).toEqual(program.programId.toBase58());
-        expect(accountMeta.iaStatus).toEqual(0);
-        expect(accountMeta.amlStatus).toEqual(0);
-        expect(accountMeta.kycStatus).toEqual(0);
+        expect(accountMeta.iaStatus).toEqual({initial: {}});
+        expect(accountMeta.amlStatus).toEqual({initial: {}});
+        expect(accountMeta.kycStatus).toEqual({initial: {}});
         expect(accountMeta.bump).toEqual(pdaBump);
+        expect(accountMeta.payer.toBase58()).toEqual(keypair.publicKey.toBase58());
         expect(tx).toBeDefined();
 
     })
@@ -74,10 +167,12 @@ describe("test that the identity-verification program", () => {
 
         expect.assertions(1);
 
-        await program.methods.updateIaStatus(pdaBump, groupKeypair.publicKey, 3)
+        await program.methods.updateIaStatus(pdaBump, groupKeypair.publicKey, {denied: {}}, new BN(0))
             .accounts({
                 record: pdaPubkey,
+                groupAccount: groupAccount,
                 subject: keypair.publicKey,
+                frozenSubject: frozenSubject,
                 authority: authority.publicKey
             })
             .signers([authority])
@@ -86,18 +181,20 @@ describe("test that the identity-verification program", () => {
 
         const accountMeta = await program.account.identityRecord.fetch(pdaPubkey);
 
-        expect(accountMeta.iaStatus).toEqual(3);
+        expect(accountMeta.iaStatus).toEqual({denied: {}});
 
     });
 
     test("is able to update an account's kyc status", async () => {
 
-        expect.assertions(1);
+        expect.assertions(2);
 
-        await program.methods.updateKycStatus(pdaBump, groupKeypair.publicKey, 2)
+        await program.methods.updateKycStatus(pdaBump, groupKeypair.publicKey, {approved: {}}, expiresAt)
             .accounts({
                 record: pdaPubkey,
+                groupAccount: groupAccount,
                 subject: keypair.publicKey,
+                frozenSubject: frozenSubject,
                 authority: authority.publicKey
             })
             .signers([authority])
@@ -106,7 +203,8 @@ describe("test that the identity-verification program", () => {
 
         const accountMeta = await program.account.identityRecord.fetch(pdaPubkey);
 
-        expect(accountMeta.kycStatus).toEqual(2);
+        expect(accountMeta.kycStatus).toEqual({approved: {}});
+        expect(accountMeta.kycExpiresAt.toNumber()).toEqual(expiresAt.toNumber());
 
 
     });
@@ -115,10 +213,12 @@ describe("test that the identity-verification program", () => {
 
         expect.assertions(1);
 
-        await program.methods.updateAmlStatus(pdaBump, groupKeypair.publicKey, 1)
+        await program.methods.updateAmlStatus(pdaBump, groupKeypair.publicKey, {started: {}}, new BN(0))
             .accounts({
                 record: pdaPubkey,
+                groupAccount: groupAccount,
                 subject: keypair.publicKey,
+                frozenSubject: frozenSubject,
                 authority: authority.publicKey
             })
             .signers([authority])
@@ -126,12 +226,12 @@ describe("test that the identity-verification program", () => {
 
         const accountMeta = await program.account.identityRecord.fetch(pdaPubkey);
 
-        expect(accountMeta.amlStatus).toEqual(1);
+        expect(accountMeta.amlStatus).toEqual({started: {}});
 
 
     });
 
-    test("is not able to update an account's status to unknown status", async () => {
+    test("is not able to approve an account's status with an expiry in the past", async () => {
 
         expect.assertions(2);
 
@@ -141,11 +241,13 @@ describe("test that the identity-verification program", () => {
 
         try {
 
-            await program.methods.updateIaStatus(pdaBump, groupKeypair.publicKey, 4)
+            await program.methods.updateIaStatus(pdaBump, groupKeypair.publicKey, {approved: {}}, new BN(1))
 
                 .accounts({
                     record: pdaPubkey,
+                    groupAccount: groupAccount,
                     subject: keypair.publicKey,
+                    frozenSubject: frozenSubject,
                     authority: authority.publicKey
                 })
                 .signers([authority])
@@ -168,10 +270,12 @@ describe("test that the identity-verification program", () => {
 
         expect.assertions(3);
 
-        await program.methods.approve(pdaBump, groupKeypair.publicKey)
+        await program.methods.approve(pdaBump, groupKeypair.publicKey, expiresAt)
             .accounts({
                 record: pdaPubkey,
+                groupAccount: groupAccount,
                 subject: keypair.publicKey,
+                frozenSubject: frozenSubject,
                 authority: authority.publicKey
             })
             .signers([authority])
@@ -180,9 +284,46 @@ describe("test that the identity-verification program", () => {
 
         const accountMeta = await program.account.identityRecord.fetch(pdaPubkey);
 
-        expect(accountMeta.amlStatus).toEqual(2);
-        expect(accountMeta.kycStatus).toEqual(2);
-        expect(accountMeta.iaStatus).toEqual(2);
+        expect(accountMeta.amlStatus).toEqual({approved: {}});
+        expect(accountMeta.kycStatus).toEqual({approved: {}});
+        expect(accountMeta.iaStatus).toEqual({approved: {}});
+
+    });
+
+    test("fails to deny record without every verifier role", async () => {
+
+        expect.assertions(2);
+
+        await program.methods.addVerifier(groupBump, groupKeypair.publicKey, kycProvider.publicKey, {kycProvider: {}})
+            .accounts({
+                groupAccount: groupAccount,
+                authority: authority.publicKey
+            })
+            .signers([authority])
+            .rpc();
+
+        try {
+
+            await program.methods.deny(pdaBump, groupKeypair.publicKey)
+                .accounts({
+                    record: pdaPubkey,
+                    groupAccount: groupAccount,
+                    subject: keypair.publicKey,
+                    frozenSubject: frozenSubject,
+                    authority: kycProvider.publicKey
+                })
+                .signers([kycProvider])
+                .rpc()
+
+        } catch (error) {
+
+            expect(error).toBeDefined()
+
+        }
+
+        const accountMeta = await program.account.identityRecord.fetch(pdaPubkey);
+
+        expect(accountMeta.iaStatus).toEqual({approved: {}});
 
     });
 
@@ -193,7 +334,9 @@ describe("test that the identity-verification program", () => {
         await program.methods.deny(pdaBump, groupKeypair.publicKey)
             .accounts({
                 record: pdaPubkey,
+                groupAccount: groupAccount,
                 subject: keypair.publicKey,
+                frozenSubject: frozenSubject,
                 authority: authority.publicKey
             })
             .signers([authority])
@@ -201,9 +344,94 @@ describe("test that the identity-verification program", () => {
 
         const accountMeta = await program.account.identityRecord.fetch(pdaPubkey);
 
-        expect(accountMeta.amlStatus).toEqual(3);
-        expect(accountMeta.kycStatus).toEqual(3);
-        expect(accountMeta.iaStatus).toEqual(3);
+        expect(accountMeta.amlStatus).toEqual({denied: {}});
+        expect(accountMeta.kycStatus).toEqual({denied: {}});
+        expect(accountMeta.iaStatus).toEqual({denied: {}});
+
+    });
+
+    const importAttestation = async (signer: Keypair, attestation: any) => {
+
+        const message = Buffer.concat([
+            Buffer.from("tokr-identity-attestation"),
+            program.coder.types.encode("Attestation", attestation)
+        ]);
+
+        const verifySignatureIx = Ed25519Program.createInstructionWithPrivateKey({
+            privateKey: signer.secretKey,
+            message: message
+        });
+
+        await program.methods.importAttestation(pdaBump, groupKeypair.publicKey, attestation)
+            .accounts({
+                record: pdaPubkey,
+                groupAccount: groupAccount,
+                subject: keypair.publicKey,
+                frozenSubject: frozenSubject,
+                instructions: SYSVAR_INSTRUCTIONS_PUBKEY
+            })
+            .preInstructions([verifySignatureIx])
+            .rpc();
+
+    }
+
+    test("fails to import an attestation without statuses signed by a key that is not a verifier", async () => {
+
+        expect.assertions(2);
+
+        const originalAccountMeta = await program.account.identityRecord.fetch(pdaPubkey);
+
+        try {
+
+            await importAttestation(nonAuthority, {
+                subject: keypair.publicKey,
+                group: groupKeypair.publicKey,
+                iaStatus: null,
+                amlStatus: null,
+                kycStatus: null,
+                expiresAt: new BN(0),
+                issuedAt: new BN(Math.floor(Date.now() / 1000) - 10)
+            });
+
+        } catch (error) {
+
+            expect(error).toBeDefined()
+
+        }
+
+        const accountMeta = await program.account.identityRecord.fetch(pdaPubkey);
+
+        expect(accountMeta.attestedAt.toNumber()).toEqual(originalAccountMeta.attestedAt.toNumber());
+
+    });
+
+    test("fails to import an attestation without statuses signed by a verifier", async () => {
+
+        expect.assertions(2);
+
+        const originalAccountMeta = await program.account.identityRecord.fetch(pdaPubkey);
+
+        try {
+
+            await importAttestation(authority, {
+                subject: keypair.publicKey,
+                group: groupKeypair.publicKey,
+                iaStatus: null,
+                amlStatus: null,
+                kycStatus: null,
+                expiresAt: new BN(0),
+                issuedAt: new BN(Math.floor(Date.now() / 1000) - 10)
+            });
+
+        } catch (error) {
+
+            expect(error).toBeDefined()
+
+        }
+
+        const accountMeta = await program.account.identityRecord.fetch(pdaPubkey);
+
+        expect(accountMeta.attestedAt.toNumber()).toEqual(originalAccountMeta.attestedAt.toNumber());
 
     });
 
@@ -213,10 +441,12 @@ describe("test that the identity-verification program", () => {
 
         try {
 
-            await program.methods.updateIaStatus(pdaBump, groupKeypair.publicKey, 1)
+            await program.methods.updateIaStatus(pdaBump, groupKeypair.publicKey, {started: {}}, new BN(0))
                 .accounts({
                     record: pdaPubkey,
+                    groupAccount: groupAccount,
                     subject: keypair.publicKey,
+                    frozenSubject: frozenSubject,
                     authority: nonAuthority.publicKey
                 })
                 .signers([nonAuthority])
@@ -250,6 +480,34 @@ describe("test that the identity-verification program", () => {
 
     });
 
+    test("fails to delete account without the signature of the authority", async () => {
+
+        expect.assertions(2);
+
+        try {
+
+            await program.methods.deleteRecord(pdaBump, groupKeypair.publicKey)
+                .accounts({
+                    record: pdaPubkey,
+                    authority: newAuthority.publicKey,
+                    subject: keypair.publicKey,
+                    payer: keypair.publicKey,
+                    systemProgram: anchor.web3.SystemProgram.programId
+                })
+                .rpc();
+
+        } catch (error) {
+
+            expect(error).toBeDefined()
+
+        }
+
+        const accountInfo = await provider.connection.getAccountInfo(pdaPubkey);
+
+        expect(accountInfo).not.toBeNull();
+
+    });
+
     test("can delete account", async () => {
 
         expect.assertions(2);
@@ -260,8 +518,10 @@ describe("test that the identity-verification program", () => {
         const txsig = await program.methods.deleteRecord(pdaBump, groupKeypair.publicKey)
             .accounts({
                 record: pdaPubkey,
+                authority: newAuthority.publicKey,
                 subject: keypair.publicKey,
-                signer: newAuthority.publicKey
+                payer: keypair.publicKey,
+                systemProgram: anchor.web3.SystemProgram.programId
             })
             .signers([newAuthority])
             .rpc();